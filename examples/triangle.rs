@@ -0,0 +1,117 @@
+//! Draws a single coloured triangle using only `rgl` calls for the OpenGL side
+//!
+//! Run with `cargo run --example triangle`, or `cargo run --example triangle --features gles2` for an
+//! ES 2 context. Add `--features trace-calls` to print every GL call made
+
+extern crate gl;
+extern crate glutin;
+extern crate rgl;
+
+use glutin::event::{Event, WindowEvent};
+use glutin::event_loop::{ControlFlow, EventLoop};
+use glutin::window::WindowBuilder;
+use glutin::{Api, ContextBuilder, GlRequest};
+
+#[cfg(not(feature = "gles2"))]
+const VERTEX_SHADER: &str = "#version 330 core
+layout (location = 0) in vec2 position;
+
+void main() {
+    gl_Position = vec4(position, 0.0, 1.0);
+}";
+
+#[cfg(not(feature = "gles2"))]
+const FRAGMENT_SHADER: &str = "#version 330 core
+out vec4 colour;
+
+void main() {
+    colour = vec4(1.0, 0.5, 0.2, 1.0);
+}";
+
+#[cfg(feature = "gles2")]
+const VERTEX_SHADER: &str = "#version 100
+attribute vec2 position;
+
+void main() {
+    gl_Position = vec4(position, 0.0, 1.0);
+}";
+
+#[cfg(feature = "gles2")]
+const FRAGMENT_SHADER: &str = "#version 100
+precision mediump float;
+
+void main() {
+    gl_FragColor = vec4(1.0, 0.5, 0.2, 1.0);
+}";
+
+#[cfg(not(feature = "gles2"))]
+const CONTEXT: GlRequest = GlRequest::Specific(Api::OpenGl, (3, 3));
+#[cfg(feature = "gles2")]
+const CONTEXT: GlRequest = GlRequest::Specific(Api::OpenGlEs, (2, 0));
+
+const VERTEX_DATA: [f32; 6] = [0.0, 0.5, 0.5, -0.5, -0.5, -0.5];
+
+//Panics with the info log rather than the Debug form of the error
+fn or_panic<T>(result: Result<T, rgl::Error>) -> T {
+    result.unwrap_or_else(|error| panic!("{}", error))
+}
+
+fn main() {
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new().with_title("rgl triangle");
+    let builder = ContextBuilder::new().with_gl(CONTEXT);
+    #[cfg(not(feature = "gles2"))]
+    let builder = builder.with_gl_profile(glutin::GlProfile::Core);
+    let context = builder.build_windowed(window, &event_loop).unwrap();
+    let context = unsafe { context.make_current().unwrap() };
+
+    let info = rgl::load_with(|symbol| context.get_proc_address(symbol) as *const _).unwrap();
+    println!("{} on {}", info.version_string, info.renderer);
+    #[cfg(feature = "gles2")]
+    assert!(gl::GenVertexArrays::is_loaded(), "OES_vertex_array_object is not supported");
+
+    //Upload the vertex data and describe its layout
+    let vbo = rgl::gen_buffer();
+    rgl::bind_buffer(rgl::Target::ArrayBuffer, vbo);
+    rgl::buffer_data(rgl::Target::ArrayBuffer, &VERTEX_DATA, rgl::Usage::StaticDraw);
+    let vao = rgl::VaoBuilder::new().vertex_buffer(vbo).attribute(0, 2, rgl::Type::Float, false).build();
+
+    //Build the shader program
+    let vertex = or_panic(rgl::shader_from_source(rgl::ShaderType::Vertex, VERTEX_SHADER));
+    let fragment = or_panic(rgl::shader_from_source(rgl::ShaderType::Fragment, FRAGMENT_SHADER));
+    let program = rgl::create_program();
+    rgl::attach_shader(program, vertex);
+    rgl::attach_shader(program, fragment);
+    //The ES 2 shader has no layout qualifiers, so give `position` the location the VAO uses
+    or_panic(rgl::bind_attrib_location(program, 0, "position").map_err(rgl::Error::from));
+    or_panic(rgl::link_program_checked(program).map_err(rgl::Error::from));
+    rgl::detach_shader(program, vertex);
+    rgl::detach_shader(program, fragment);
+    rgl::delete_shader(vertex);
+    rgl::delete_shader(fragment);
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::Wait;
+
+        match event {
+            Event::WindowEvent { event: WindowEvent::CloseRequested, .. } => {
+                rgl::delete_program(program);
+                rgl::delete_buffer(vbo);
+                rgl::delete_vertex_array(vao);
+                *control_flow = ControlFlow::Exit;
+            }
+            Event::WindowEvent { event: WindowEvent::Resized(size), .. } => context.resize(size),
+            Event::RedrawRequested(_) => {
+                rgl::clear_color(0.1, 0.1, 0.1, 1.0);
+                rgl::clear(rgl::ClearMask::COLOR);
+
+                rgl::use_program(program);
+                rgl::bind_vertex_array(vao);
+                rgl::draw_arrays(rgl::Primitive::Triangles, 0, 3);
+
+                context.swap_buffers().unwrap();
+            }
+            _ => (),
+        }
+    });
+}
@@ -1,87 +1,354 @@
 use gl;
 use gl::types::*;
+use std::error;
+use std::fmt;
+use std::marker::PhantomData;
 use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_void;
 use std::ptr;
+use std::slice;
 
+#[cfg(not(feature = "gles2"))]
+use super::dsa;
 use super::enums;
 
-#[derive(Clone, Copy)]
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
 pub struct VAO(pub GLuint);
 
-#[derive(Clone, Copy)]
+impl VAO {
+    /// The name 0, which binds no vertex array
+    pub const NONE: VAO = VAO(0);
+}
+
+/// Types that can be uploaded to GL memory as plain bytes
+///
+/// The upload functions hand GL the raw bytes of their slices, so `T` must not contain pointers,
+/// references or padding: uploading a `&[String]` sends heap pointers to the GPU, and the padding
+/// of a `#[repr(C)]` struct is uninitialised memory, which is undefined behaviour to read. With the
+/// `bytemuck` feature this is enforced by requiring `bytemuck::Pod`. **Without it every type
+/// implements `Plain`, and upholding this is up to the caller**; add explicit padding fields to
+/// structs, or enable the feature to have it checked. Readbacks require the stricter `Readback`
+#[cfg(feature = "bytemuck")]
+pub trait Plain: bytemuck::Pod {}
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod> Plain for T {}
+
+/// Types that can be uploaded to GL memory as plain bytes
+///
+/// The upload functions hand GL the raw bytes of their slices, so `T` must not contain pointers,
+/// references or padding: uploading a `&[String]` sends heap pointers to the GPU, and the padding
+/// of a `#[repr(C)]` struct is uninitialised memory, which is undefined behaviour to read. With the
+/// `bytemuck` feature this is enforced by requiring `bytemuck::Pod`. **Without it every type
+/// implements `Plain`, and upholding this is up to the caller**; add explicit padding fields to
+/// structs, or enable the feature to have it checked. Readbacks require the stricter `Readback`
+#[cfg(not(feature = "bytemuck"))]
+pub trait Plain {}
+
+#[cfg(not(feature = "bytemuck"))]
+impl<T> Plain for T {}
+
+/// Types that GL can write any bytes into, required by the readback functions
+///
+/// Reading back is stricter than uploading, as GL overwrites every byte of the elements: reading
+/// into a `bool`, a reference or an enum would create invalid values. With the `bytemuck` feature
+/// this is implemented for every `bytemuck::AnyBitPattern` type, without it for the integer and
+/// float types and arrays of them, and can be implemented by hand for `#[repr(C)]` structs of those
+///
+/// # Examples
+/// ```rust,compile_fail
+/// fn readable<T: rgl::Readback>() {}
+///
+/// //Only 0 and 1 are valid bools
+/// readable::<bool>();
+/// ```
+///
+/// # Safety
+/// Every bit pattern of `size_of::<Self>()` bytes must be a valid value of the type
+pub unsafe trait Readback: Copy {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::AnyBitPattern> Readback for T {}
+
+#[cfg(not(feature = "bytemuck"))]
+macro_rules! readback {
+    ($($type_:ty),*) => {
+        $(unsafe impl Readback for $type_ {})*
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+readback!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64);
+
+#[cfg(not(feature = "bytemuck"))]
+unsafe impl<T: Readback, const N: usize> Readback for [T; N] {}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
 pub struct Buffer(pub GLuint);
 
-/// Generates vertex array objects
+impl Buffer {
+    /// The name 0, which binds no buffer
+    ///
+    /// # Examples
+    /// ```rust
+    /// let mut bound = rgl::Buffer::NONE;
+    /// assert_eq!(bound.0, 0);
+    ///
+    /// bound = rgl::Buffer(3);
+    /// assert_ne!(bound.0, rgl::Buffer::NONE.0);
+    /// ```
+    pub const NONE: Buffer = Buffer(0);
+}
+
+/// Generates vertex array objects, one for every element of the slice
 ///
 /// # Examples
-/// ```rust,no_run
-/// let mut vao = rgl::VAO(0);
-/// rgl::gen_vertex_arrays(1, &mut vao);
+/// ```rust
+/// extern crate gl;
+/// extern crate rgl;
+///
+/// use gl::types::*;
+/// use std::os::raw::c_void;
+/// use std::ptr;
+///
+/// //Hands out names counting up from 1, like a driver would
+/// extern "system" fn gen_vertex_arrays(n: GLsizei, arrays: *mut GLuint) {
+///     for i in 0..n as usize {
+///         unsafe { *arrays.add(i) = i as GLuint + 1 };
+///     }
+/// }
+///
+/// extern "system" fn get_error() -> GLenum {
+///     gl::NO_ERROR
+/// }
+///
+/// # fn main() {
+/// gl::load_with(|symbol| match symbol {
+///     "glGenVertexArrays" => gen_vertex_arrays as *const c_void,
+///     "glGetError" => get_error as *const c_void,
+///     _ => ptr::null(),
+/// });
+///
+/// let mut vaos = [rgl::VAO(0); 4];
+/// rgl::gen_vertex_arrays(&mut vaos);
+///
+/// for (i, vao) in vaos.iter().enumerate() {
+///     assert!(vao.0 != 0);
+///     assert!(vaos[i + 1..].iter().all(|other| other.0 != vao.0));
+/// }
+/// # }
 /// ```
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenVertexArrays.xhtml
-pub fn gen_vertex_arrays(count: GLsizei, arrays: *mut VAO) {
-    unsafe {
-        gl::GenVertexArrays(count, &mut (*arrays).0);
-    }
+pub fn gen_vertex_arrays(arrays: &mut [VAO]) {
+    gl_call!(gen_vertex_arrays, gl::GenVertexArrays(arrays.len() as GLsizei, arrays.as_mut_ptr() as *mut GLuint));
 }
 
+/// Loads the `OES_vertex_array_object` functions in place of the core VAO functions
+///
+/// ES 2 only has VAOs through this extension, after loading it the VAO wrappers work as on
+/// desktop. `loader` is the same function given to `gl::load_with`. Returns whether the
+/// extension functions were found. Only available with the `gles2` feature
+///
+/// # Examples
+/// ```rust,ignore
+/// gl::load_with(|symbol| context.get_proc_address(symbol) as *const _);
+/// if !rgl::load_oes_vertex_array_object(|symbol| context.get_proc_address(symbol) as *const _) {
+///     panic!("OES_vertex_array_object is not supported");
+/// }
+/// ```
+#[cfg(feature = "gles2")]
+pub fn load_oes_vertex_array_object<F>(mut loader: F) -> bool
+where
+    F: FnMut(&'static str) -> *const c_void,
+{
+    gl::GenVertexArrays::load_with(|_| loader("glGenVertexArraysOES"));
+    gl::BindVertexArray::load_with(|_| loader("glBindVertexArrayOES"));
+    gl::DeleteVertexArrays::load_with(|_| loader("glDeleteVertexArraysOES"));
+    gl::IsVertexArray::load_with(|_| loader("glIsVertexArrayOES"));
+
+    gl::GenVertexArrays::is_loaded() && gl::BindVertexArray::is_loaded() && gl::DeleteVertexArrays::is_loaded()
+}
 
 /// Generates a single VAO
 /// No need to create the VAO seperatly!
-/// 
+///
 /// # Examples
 /// ```rust,no_run
 /// let mut vao = rgl::gen_vertex_array();
 /// ```
 pub fn gen_vertex_array() -> VAO {
-    let mut vao = VAO(0);
-    gen_vertex_arrays(1, &mut vao);
-    vao
+    let mut vao = [VAO(0)];
+    gen_vertex_arrays(&mut vao);
+    vao[0]
+}
+
+/// Generates `count` vertex array objects with a single call, see `gen_vertex_arrays`
+///
+/// A `count` of 0 returns an empty Vec without calling GL
+pub fn gen_vertex_arrays_vec(count: usize) -> Vec<VAO> {
+    let mut arrays = vec![VAO(0); count];
+    if count > 0 {
+        gen_vertex_arrays(&mut arrays);
+    }
+    arrays
+}
+
+/// Creates vertex array objects, one for every element of the slice
+///
+/// Unlike `gen_vertex_arrays`, the objects exist straight away, so they can be used with the
+/// direct state access functions without binding them first. Requires GL 4.5 or `ARB_direct_state_access`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateVertexArrays.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn create_vertex_arrays(arrays: &mut [VAO]) {
+    gl_call!(create_vertex_arrays, gl::CreateVertexArrays(arrays.len() as GLsizei, arrays.as_mut_ptr() as *mut GLuint));
+}
+
+/// Creates a single VAO, see `create_vertex_arrays`
+#[cfg(not(feature = "gles2"))]
+pub fn create_vertex_array() -> VAO {
+    let mut vao = [VAO(0)];
+    create_vertex_arrays(&mut vao);
+    vao[0]
 }
 
-/// Generates some buffer objects
+/// Generates buffer objects, one for every element of the slice
 ///
 /// # Examples
 /// ```rust,no_run
-/// let mut vbo = rgl::VBO(0);
-/// rgl::gen_buffers(1, &mut vbo);
+/// let mut vbos = [rgl::Buffer(0); 4];
+/// rgl::gen_buffers(&mut vbos);
+///
+/// for (i, vbo) in vbos.iter().enumerate() {
+///     assert!(vbo.0 != 0);
+///     assert!(vbos[i + 1..].iter().all(|other| other.0 != vbo.0));
+/// }
 /// ```
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/es2.0/xhtml/glGenBuffers.xml
-pub fn gen_buffers(count: GLsizei, buffers: *mut Buffer) {
-    unsafe {
-        gl::GenBuffers(count, &mut (*buffers).0);
-    }
+pub fn gen_buffers(buffers: &mut [Buffer]) {
+    gl_call!(gen_buffers, gl::GenBuffers(buffers.len() as GLsizei, buffers.as_mut_ptr() as *mut GLuint));
 }
 
 /// Generates a single VBO
 /// No need to create the VBO seperatly!
-/// 
+///
 /// # Examples
 /// ```rust,no_run
 /// let mut vbo = rgl::gen_buffer();
 /// ```
 pub fn gen_buffer() -> Buffer {
-    let mut vbo = Buffer(0);
-    gen_buffers(1, &mut vbo);
-    vbo
+    let mut vbo = [Buffer(0)];
+    gen_buffers(&mut vbo);
+    vbo[0]
+}
+
+/// Generates `count` buffer objects with a single call, see `gen_buffers`
+///
+/// A `count` of 0 returns an empty Vec without calling GL
+///
+/// # Examples
+/// ```rust
+/// extern crate gl;
+/// extern crate rgl;
+///
+/// use gl::types::*;
+/// use std::os::raw::c_void;
+/// use std::ptr;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static CALLS: AtomicUsize = AtomicUsize::new(0);
+///
+/// //Hands out names counting up from 1, like a driver would
+/// extern "system" fn gen_buffers(n: GLsizei, buffers: *mut GLuint) {
+///     CALLS.fetch_add(1, Ordering::SeqCst);
+///     for i in 0..n as usize {
+///         unsafe { *buffers.add(i) = i as GLuint + 1 };
+///     }
+/// }
+///
+/// extern "system" fn get_error() -> GLenum {
+///     gl::NO_ERROR
+/// }
+///
+/// # fn main() {
+/// gl::load_with(|symbol| match symbol {
+///     "glGenBuffers" => gen_buffers as *const c_void,
+///     "glGetError" => get_error as *const c_void,
+///     _ => ptr::null(),
+/// });
+///
+/// //One buffer per glTF buffer view
+/// let buffers = rgl::gen_buffers_vec(8);
+/// assert_eq!(buffers.len(), 8);
+/// for (i, buffer) in buffers.iter().enumerate() {
+///     assert!(buffer.0 != 0);
+///     assert!(buffers[i + 1..].iter().all(|other| other.0 != buffer.0));
+/// }
+///
+/// assert!(rgl::gen_buffers_vec(0).is_empty());
+/// assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+/// # }
+/// ```
+pub fn gen_buffers_vec(count: usize) -> Vec<Buffer> {
+    let mut buffers = vec![Buffer(0); count];
+    if count > 0 {
+        gen_buffers(&mut buffers);
+    }
+    buffers
+}
+
+/// Creates buffer objects, one for every element of the slice
+///
+/// Unlike `gen_buffers`, the objects exist straight away, so they can be used with the direct
+/// state access functions without binding them first. Requires GL 4.5 or `ARB_direct_state_access`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateBuffers.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn create_buffers(buffers: &mut [Buffer]) {
+    gl_call!(create_buffers, gl::CreateBuffers(buffers.len() as GLsizei, buffers.as_mut_ptr() as *mut GLuint));
+}
+
+/// Creates a single buffer, see `create_buffers`
+///
+/// # Examples
+/// ```rust,no_run
+/// let vertex_data: [f32; 6] = [0.0, 0.5, 0.5, -0.5, -0.5, -0.5];
+///
+/// let vbo = rgl::create_buffer();
+/// rgl::named_buffer_data(vbo, &vertex_data, rgl::Usage::StaticDraw);
+/// ```
+#[cfg(not(feature = "gles2"))]
+pub fn create_buffer() -> Buffer {
+    let mut vbo = [Buffer(0)];
+    create_buffers(&mut vbo);
+    vbo[0]
 }
 
 /// Bind a vertex array object
 ///
 /// # Examples
 /// ```rust,no_run
-/// let mut vao = rgl::VAO(0);
-/// rgl::gen_vertex_arrays(1, &mut vao);
+/// let vao = rgl::gen_vertex_array();
 /// rgl::bind_vertex_array(vao);
 /// ```
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindVertexArray.xhtml
-pub fn bind_vertex_array(array: VAO) {
-    unsafe {
-        gl::BindVertexArray(array.0);
-    }
+pub fn bind_vertex_array<H: Into<VAO>>(array: H) {
+    let array: VAO = array.into();
+    gl_call!(bind_vertex_array, gl::BindVertexArray(array.0));
+}
+
+/// Unbinds the current vertex array object
+///
+/// Core profiles have no default vertex array, so attribute setup and draws are errors until
+/// another is bound. Compatibility profiles fall back to a default one
+pub fn unbind_vertex_array() {
+    gl_call!(unbind_vertex_array, gl::BindVertexArray(0));
 }
 
 /// Bind a vertex buffer
@@ -93,16 +360,65 @@ pub fn bind_vertex_array(array: VAO) {
 /// ```
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/es2.0/xhtml/glGenBuffers.xml
-pub fn bind_buffer(target: enums::Target, buffer: Buffer) {
-    unsafe {
-        gl::BindBuffer(target as u32, buffer.0);
-    }
+pub fn bind_buffer<H: Into<Buffer>>(target: enums::Target, buffer: H) {
+    let buffer: Buffer = buffer.into();
+    gl_call!(bind_buffer, gl::BindBuffer(target as u32, buffer.0));
 }
 
+/// Unbinds the buffer bound to `target`
+///
+/// With no `ElementArrayBuffer` this also changes the bound vertex array, and with no `ArrayBuffer`
+/// `vertex_attrib_pointer` offsets are read as client memory addresses, which core profiles reject
 pub fn unbind_buffer(target: enums::Target) {
-    unsafe {
-        gl::BindBuffer(target as u32, 0);
-    }
+    gl_call!(unbind_buffer, gl::BindBuffer(target as u32, 0));
+}
+
+/// Binds a whole buffer to the binding point `index` of an indexed target
+///
+/// The buffer is also bound to the matching non-indexed `Target`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindBufferBase.xhtml
+pub fn bind_buffer_base<H: Into<Buffer>>(target: enums::IndexedTarget, index: GLuint, buffer: H) {
+    let buffer: Buffer = buffer.into();
+    gl_call!(bind_buffer_base, gl::BindBufferBase(target as GLenum, index, buffer.0));
+}
+
+/// Binds `size` bytes of a buffer, starting at `offset`, to the binding point `index` of an indexed target
+///
+/// For `UniformBuffer`, `offset` must be a multiple of `uniform_buffer_offset_alignment()`, and
+/// for `ShaderStorageBuffer` of `GL_SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT`
+///
+/// # Examples
+/// ```rust,no_run
+/// //One block of per-object uniforms per draw, out of a single buffer
+/// let block_size: usize = 64;
+/// let alignment = rgl::uniform_buffer_offset_alignment();
+/// let stride = block_size.div_ceil(alignment) * alignment;
+///
+/// let ubo = rgl::gen_buffer();
+/// for object in 0..16 {
+///     rgl::bind_buffer_range(rgl::IndexedTarget::UniformBuffer, 1, ubo, object * stride, block_size);
+///     //...draw the object
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindBufferRange.xhtml
+pub fn bind_buffer_range<H: Into<Buffer>>(target: enums::IndexedTarget, index: GLuint, buffer: H, offset: usize, size: usize) {
+    let buffer: Buffer = buffer.into();
+    gl_call!(bind_buffer_range, gl::BindBufferRange(
+        target as GLenum,
+        index,
+        buffer.0,
+        offset as GLintptr,
+        size as GLsizeiptr,
+    ));
+}
+
+/// The alignment in bytes required of `offset` in `bind_buffer_range` on `UniformBuffer`
+pub fn uniform_buffer_offset_alignment() -> usize {
+    let mut alignment = 0;
+    gl_call!(uniform_buffer_offset_alignment, gl::GetIntegerv(gl::UNIFORM_BUFFER_OFFSET_ALIGNMENT, &mut alignment));
+    alignment as usize
 }
 
 /// Enable a generic vertex attribute array
@@ -114,90 +430,1175 @@ pub fn unbind_buffer(target: enums::Target) {
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glEnableVertexAttribArray.xhtml
 pub fn enable_vertex_attrib_array(index: GLuint) {
-    unsafe {
-        gl::EnableVertexAttribArray(index);
-    }
+    gl_call!(enable_vertex_attrib_array, gl::EnableVertexAttribArray(index));
+}
+
+/// Disable the generic vertex attribute array at `index`, the shader input then reads the value set
+/// with `vertex_attrib4f` and friends
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glEnableVertexAttribArray.xhtml
+pub fn disable_vertex_attrib_array(index: GLuint) {
+    gl_call!(disable_vertex_attrib_array, gl::DisableVertexAttribArray(index));
 }
 
 /// Define an array of generic vertex attribute data
 ///
-/// # Examles
+/// `stride` is the byte distance between consecutive vertices (0 meaning tightly packed) and
+/// `offset` is the byte offset of the attribute's first component into the bound array buffer
+///
+/// # Examples
 /// ```rust,no_run
-/// rgl::vertex_attrib_pointer(0, 2, rgl::Type::Float, false, 0);
+/// use std::mem;
+///
+/// #[repr(C)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// let stride = mem::size_of::<Vertex>() as i32;
+///
+/// rgl::enable_vertex_attrib_array(0);
+/// rgl::vertex_attrib_pointer(0, 3, rgl::Type::Float, false, stride, 0);
+///
+/// rgl::enable_vertex_attrib_array(1);
+/// rgl::vertex_attrib_pointer(1, 2, rgl::Type::Float, false, stride, mem::size_of::<[f32; 3]>());
 /// ```
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribPointer.xhtml
-/// TODO that last param in a rusty way (null default for now)
 pub fn vertex_attrib_pointer(
     index: GLuint,
     size: GLint,
     type_: enums::Type,
     normalised: bool,
     stride: GLsizei,
+    offset: usize,
 ) {
-    unsafe {
-        gl::VertexAttribPointer(
-            index,
-            size,
-            type_ as GLenum,
-            normalised as GLboolean,
-            stride,
-            ptr::null(),
-        );
+    gl_call!(vertex_attrib_pointer, gl::VertexAttribPointer(
+        index,
+        size,
+        type_ as GLenum,
+        normalised as GLboolean,
+        stride,
+        offset as *const c_void,
+    ));
+}
+
+/// Define an array of generic vertex attribute data that reaches the shader as integers
+///
+/// Unlike `vertex_attrib_pointer`, the values are not converted to floats, so they can be read by
+/// `int`, `uint`, `ivec` and `uvec` shader inputs
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::mem;
+///
+/// #[repr(C)]
+/// struct SkinnedVertex {
+///     position: [f32; 3],
+///     bones: [u16; 4],
+///     weights: [f32; 4],
+/// }
+///
+/// let stride = mem::size_of::<SkinnedVertex>() as i32;
+///
+/// //layout(location = 1) in uvec4 bones;
+/// rgl::enable_vertex_attrib_array(1);
+/// rgl::vertex_attrib_i_pointer(1, 4, rgl::IntType::UnsignedShort, stride, mem::size_of::<[f32; 3]>());
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribPointer.xhtml
+pub fn vertex_attrib_i_pointer(index: GLuint, size: GLint, type_: enums::IntType, stride: GLsizei, offset: usize) {
+    gl_call!(vertex_attrib_i_pointer, gl::VertexAttribIPointer(
+        index,
+        size,
+        type_ as GLenum,
+        stride,
+        offset as *const c_void,
+    ));
+}
+
+/// Define an array of generic vertex attribute data of doubles, read by `double` and `dvec` shader inputs
+///
+/// Requires GL 4.1 or `ARB_vertex_attrib_64bit`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribPointer.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn vertex_attrib_l_pointer(index: GLuint, size: GLint, stride: GLsizei, offset: usize) {
+    gl_call!(vertex_attrib_l_pointer, gl::VertexAttribLPointer(
+        index,
+        size,
+        gl::DOUBLE,
+        stride,
+        offset as *const c_void,
+    ));
+}
+
+/// Sets the value the shader input at `index` reads while its attribute array is disabled
+///
+/// The value is only used after `disable_vertex_attrib_array`, an enabled array always takes
+/// precedence. Missing components default to 0 for y and z, and 1 for w
+///
+/// # Examples
+/// ```rust,no_run
+/// //A sprite batch without a colour buffer, the colour at index 2 is set between draws
+/// rgl::disable_vertex_attrib_array(2);
+/// rgl::vertex_attrib4f(2, 1.0, 0.0, 0.0, 1.0);
+/// rgl::draw_arrays(rgl::Primitive::TriangleStrip, 0, 4);
+/// rgl::vertex_attrib4f(2, 0.0, 0.0, 1.0, 0.5);
+/// rgl::draw_arrays(rgl::Primitive::TriangleStrip, 4, 4);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttrib.xhtml
+pub fn vertex_attrib4f(index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat) {
+    gl_call!(vertex_attrib4f, gl::VertexAttrib4f(index, x, y, z, w));
+}
+
+pub fn vertex_attrib1f(index: GLuint, x: GLfloat) {
+    gl_call!(vertex_attrib1f, gl::VertexAttrib1f(index, x));
+}
+
+pub fn vertex_attrib2f(index: GLuint, x: GLfloat, y: GLfloat) {
+    gl_call!(vertex_attrib2f, gl::VertexAttrib2f(index, x, y));
+}
+
+pub fn vertex_attrib3f(index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat) {
+    gl_call!(vertex_attrib3f, gl::VertexAttrib3f(index, x, y, z));
+}
+
+/// Like `vertex_attrib4f`, from an array
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttrib.xhtml
+pub fn vertex_attrib4fv(index: GLuint, value: &[GLfloat; 4]) {
+    gl_call!(vertex_attrib4fv, gl::VertexAttrib4fv(index, value.as_ptr()));
+}
+
+/// Sets the value an `ivec4` shader input reads while the attribute array at `index` is disabled
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttrib.xhtml
+pub fn vertex_attrib_i4i(index: GLuint, x: GLint, y: GLint, z: GLint, w: GLint) {
+    gl_call!(vertex_attrib_i4i, gl::VertexAttribI4i(index, x, y, z, w));
+}
+
+/// Sets the value a `uvec4` shader input reads while the attribute array at `index` is disabled
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttrib.xhtml
+pub fn vertex_attrib_i4ui(index: GLuint, x: GLuint, y: GLuint, z: GLuint, w: GLuint) {
+    gl_call!(vertex_attrib_i4ui, gl::VertexAttribI4ui(index, x, y, z, w));
+}
+
+/// Sets the value a `dvec4` shader input reads while the attribute array at `index` is disabled
+///
+/// Requires GL 4.1 or `ARB_vertex_attrib_64bit`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttrib.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn vertex_attrib_l4d(index: GLuint, value: [GLdouble; 4]) {
+    gl_call!(vertex_attrib_l4d, gl::VertexAttribL4d(index, value[0], value[1], value[2], value[3]));
+}
+
+/// Sets how many instances are drawn before the attribute at `index` advances
+///
+/// A divisor of 0 restores the default per-vertex behaviour
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribDivisor.xhtml
+pub fn vertex_attrib_divisor(index: GLuint, divisor: GLuint) {
+    gl_call!(vertex_attrib_divisor, gl::VertexAttribDivisor(index, divisor));
+}
+
+/// Describes the layout of an attribute of the bound VAO, without tying it to a buffer
+///
+/// `relative_offset` is the byte offset of the attribute within a vertex. The attribute reads
+/// from the vertex buffer binding chosen with `vertex_attrib_binding`. Requires GL 4.3 or
+/// `ARB_vertex_attrib_binding`
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::mem;
+///
+/// #[repr(C)]
+/// struct Vertex {
+///     position: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// //The format is specified once
+/// let vao = rgl::gen_vertex_array();
+/// rgl::bind_vertex_array(vao);
+/// rgl::enable_vertex_attrib_array(0);
+/// rgl::vertex_attrib_format(0, 3, rgl::Type::Float, false, 0);
+/// rgl::vertex_attrib_binding(0, 0);
+/// rgl::enable_vertex_attrib_array(1);
+/// rgl::vertex_attrib_format(1, 2, rgl::Type::Float, false, mem::size_of::<[f32; 3]>() as u32);
+/// rgl::vertex_attrib_binding(1, 0);
+///
+/// //Then every mesh only swaps the buffer
+/// let meshes = [rgl::gen_buffer(), rgl::gen_buffer()];
+/// for mesh in meshes.iter() {
+///     rgl::bind_vertex_buffer(0, *mesh, 0, mem::size_of::<Vertex>() as i32);
+///     rgl::draw_arrays(rgl::Primitive::Triangles, 0, 36);
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribFormat.xhtml
+pub fn vertex_attrib_format(
+    attrib_index: GLuint,
+    size: GLint,
+    type_: enums::Type,
+    normalised: bool,
+    relative_offset: GLuint,
+) {
+    gl_call!(vertex_attrib_format, gl::VertexAttribFormat(
+        attrib_index,
+        size,
+        type_ as GLenum,
+        normalised as GLboolean,
+        relative_offset
+    ));
+}
+
+/// Like `vertex_attrib_format`, for attributes that reach the shader as integers
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribFormat.xhtml
+pub fn vertex_attrib_i_format(attrib_index: GLuint, size: GLint, type_: enums::IntType, relative_offset: GLuint) {
+    gl_call!(vertex_attrib_i_format, gl::VertexAttribIFormat(attrib_index, size, type_ as GLenum, relative_offset));
+}
+
+/// Binds a buffer to a vertex buffer binding point of the bound VAO
+///
+/// `offset` is the byte offset of the first vertex in the buffer, and `stride` the byte distance
+/// between consecutive vertices. Requires GL 4.3 or `ARB_vertex_attrib_binding`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindVertexBuffer.xhtml
+pub fn bind_vertex_buffer<H: Into<Buffer>>(binding_index: GLuint, buffer: H, offset: usize, stride: GLsizei) {
+    let buffer: Buffer = buffer.into();
+    gl_call!(bind_vertex_buffer, gl::BindVertexBuffer(binding_index, buffer.0, offset as GLintptr, stride));
+}
+
+/// Makes an attribute of the bound VAO read from a vertex buffer binding point
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribBinding.xhtml
+pub fn vertex_attrib_binding(attrib_index: GLuint, binding_index: GLuint) {
+    gl_call!(vertex_attrib_binding, gl::VertexAttribBinding(attrib_index, binding_index));
+}
+
+/// Sets how many instances are drawn before the attributes reading from a binding point advance
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexBindingDivisor.xhtml
+pub fn vertex_binding_divisor(binding_index: GLuint, divisor: GLuint) {
+    gl_call!(vertex_binding_divisor, gl::VertexBindingDivisor(binding_index, divisor));
+}
+
+/// Enables and defines a per-instance attribute in the bound array buffer, advancing once per
+/// instance
+///
+/// The attribute is read as floats without normalisation, like `vertex_attrib_pointer` with
+/// `normalised` off. For a matrix per instance, use `setup_instance_matrix_attributes`
+///
+/// # Examples
+/// ```rust,no_run
+/// //A vec2 offset and a float scale per instance, interleaved
+/// rgl::setup_instanced_attribute(2, 2, rgl::Type::Float, 12, 0);
+/// rgl::setup_instanced_attribute(3, 1, rgl::Type::Float, 12, 8);
+/// ```
+pub fn setup_instanced_attribute(index: GLuint, size: GLint, type_: enums::Type, stride: GLsizei, offset: usize) {
+    enable_vertex_attrib_array(index);
+    vertex_attrib_pointer(index, size, type_, false, stride, offset);
+    vertex_attrib_divisor(index, 1);
+}
+
+/// Enables and defines a per-instance `mat4` attribute in the bound array buffer, of tightly packed
+/// column major `f32` matrices from the start of the buffer
+///
+/// A `mat4` input takes up four `vec4` attribute slots, one per column, so this sets up
+/// `base_index` to `base_index + 3`
+///
+/// # Examples
+/// ```rust
+/// extern crate gl;
+/// extern crate rgl;
+///
+/// use gl::types::*;
+/// use std::os::raw::c_void;
+/// use std::ptr;
+/// use std::sync::Mutex;
+///
+/// static POINTERS: Mutex<Vec<(GLuint, GLint, GLsizei, usize)>> = Mutex::new(Vec::new());
+///
+/// extern "system" fn vertex_attrib_pointer(index: GLuint, size: GLint, _type: GLenum, _normalised: GLboolean, stride: GLsizei, offset: *const c_void) {
+///     POINTERS.lock().unwrap().push((index, size, stride, offset as usize));
+/// }
+///
+/// extern "system" fn enable_vertex_attrib_array(_index: GLuint) {}
+///
+/// extern "system" fn vertex_attrib_divisor(_index: GLuint, divisor: GLuint) {
+///     assert_eq!(divisor, 1);
+/// }
+///
+/// extern "system" fn get_error() -> GLenum {
+///     gl::NO_ERROR
+/// }
+///
+/// # fn main() {
+/// //Record the layout with stand ins for the driver's functions
+/// gl::load_with(|symbol| match symbol {
+///     "glVertexAttribPointer" => vertex_attrib_pointer as *const c_void,
+///     "glEnableVertexAttribArray" => enable_vertex_attrib_array as *const c_void,
+///     "glVertexAttribDivisor" => vertex_attrib_divisor as *const c_void,
+///     "glGetError" => get_error as *const c_void,
+///     _ => ptr::null(),
+/// });
+///
+/// //layout(location = 3) in mat4 model;
+/// rgl::setup_instance_matrix_attributes(3);
+/// assert_eq!(*POINTERS.lock().unwrap(), [(3, 4, 64, 0), (4, 4, 64, 16), (5, 4, 64, 32), (6, 4, 64, 48)]);
+/// # }
+/// ```
+pub fn setup_instance_matrix_attributes(base_index: GLuint) {
+    let column = mem::size_of::<[GLfloat; 4]>();
+    for i in 0..4 {
+        setup_instanced_attribute(base_index + i, 4, enums::Type::Float, (column * 4) as GLsizei, i as usize * column);
     }
 }
 
 /// Creates and initalizes a buffer object data store
 ///
+/// Passing an empty slice allocates an empty data store. `T` must be plain data, see `Plain`
+///
 /// # Examples
 /// ```rust,no_run
 /// extern crate gl;
-/// 
+///
 /// use rgl;
 /// use gl::types::*;
-/// 
-/// 
+///
 /// let vertex_data: [GLfloat; 6] = [0.0, 0.5, 0.5, -0.5, -0.5, -0.5];
 /// //...
 /// rgl::buffer_data(rgl::Target::ArrayBuffer, &vertex_data, rgl::Usage::StaticDraw);
 /// ```
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferData.xhtml
-pub fn buffer_data<T>(target: enums::Target, data: &[T], usage: enums::Usage) {
-    unsafe {
-        gl::BufferData(
-            target as GLenum,
-            (data.len() * mem::size_of::<T>()) as GLsizeiptr,
-            mem::transmute(&data[0]),
-            usage as GLenum,
+pub fn buffer_data<T: Plain>(target: enums::Target, data: &[T], usage: enums::Usage) {
+    gl_call!(buffer_data, gl::BufferData(
+        target as GLenum,
+        mem::size_of_val(data) as GLsizeiptr,
+        data_ptr(data),
+        usage as GLenum,
+    ));
+}
+
+/// Creates a buffer object data store of `size` bytes, without any initial data
+///
+/// The contents of the data store are undefined until written, for example with `buffer_sub_data`
+///
+/// # Examples
+/// ```rust,no_run
+/// let vbo = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::ArrayBuffer, vbo);
+/// rgl::buffer_reserve(rgl::Target::ArrayBuffer, 1024 * 1024, rgl::Usage::StaticDraw);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferData.xhtml
+pub fn buffer_reserve(target: enums::Target, size: usize, usage: enums::Usage) {
+    gl_call!(buffer_reserve, gl::BufferData(target as GLenum, size as GLsizeiptr, ptr::null(), usage as GLenum));
+}
+
+/// Creates an immutable data store for the buffer bound to `target`, initialized with `data`
+///
+/// Requires GL 4.4 or `ARB_buffer_storage`, see `supports_buffer_storage`. Passing an empty slice
+/// is an error, as immutable data stores cannot be empty
+///
+/// # Examples
+/// ```rust,no_run
+/// let vertex_data: [f32; 6] = [0.0, 0.5, 0.5, -0.5, -0.5, -0.5];
+///
+/// if rgl::supports_buffer_storage() {
+///     rgl::buffer_storage(rgl::Target::ArrayBuffer, &vertex_data, rgl::StorageFlags::empty());
+/// } else {
+///     rgl::buffer_data(rgl::Target::ArrayBuffer, &vertex_data, rgl::Usage::StaticDraw);
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferStorage.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn buffer_storage<T: Plain>(target: enums::Target, data: &[T], flags: enums::StorageFlags) {
+    gl_call!(buffer_storage, gl::BufferStorage(
+        target as GLenum,
+        mem::size_of_val(data) as GLsizeiptr,
+        data_ptr(data),
+        flags.bits(),
+    ));
+}
+
+/// Creates an immutable data store of `size` bytes, without any initial data
+///
+/// Requires GL 4.4 or `ARB_buffer_storage`, see `supports_buffer_storage`
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn persistent() -> Result<(), rgl::MapError> {
+/// //Three frames worth of vertex data, written by the CPU while the GPU reads the other two
+/// let size = 3 * 64 * 1024;
+/// let flags = rgl::StorageFlags::MAP_WRITE | rgl::StorageFlags::MAP_PERSISTENT | rgl::StorageFlags::MAP_COHERENT;
+/// rgl::buffer_storage_reserve(rgl::Target::ArrayBuffer, size, flags);
+///
+/// let access = rgl::MapAccess::WRITE | rgl::MapAccess::PERSISTENT | rgl::MapAccess::COHERENT;
+/// let mapped = rgl::map_buffer_range(rgl::Target::ArrayBuffer, 0, size, access)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferStorage.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn buffer_storage_reserve(target: enums::Target, size: usize, flags: enums::StorageFlags) {
+    gl_call!(buffer_storage_reserve, gl::BufferStorage(target as GLenum, size as GLsizeiptr, ptr::null(), flags.bits()));
+}
+
+/// Whether `buffer_storage` is available, otherwise fall back to `buffer_data`
+#[cfg(not(feature = "gles2"))]
+pub fn supports_buffer_storage() -> bool {
+    gl::BufferStorage::is_loaded()
+}
+
+/// Replaces part of the data store of the buffer bound to `target` with `data`, starting at byte
+/// `offset`
+///
+/// The size is that of the whole slice, and an empty slice uploads nothing. `T` must be plain data,
+/// see `Plain`
+///
+/// # Examples
+#[cfg_attr(not(feature = "gles2"), doc = "```rust")]
+#[cfg_attr(feature = "gles2", doc = "```rust,ignore")]
+/// extern crate gl;
+/// extern crate rgl;
+///
+/// use gl::types::*;
+/// use std::os::raw::c_void;
+/// use std::ptr;
+/// use std::sync::Mutex;
+///
+/// //The data store of the one buffer the stand ins for the driver know about
+/// static STORE: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+///
+/// extern "system" fn gen_buffers(_n: GLsizei, buffers: *mut GLuint) {
+///     unsafe { *buffers = 1 };
+/// }
+///
+/// extern "system" fn bind_buffer(_target: GLenum, _buffer: GLuint) {}
+///
+/// extern "system" fn buffer_data(_target: GLenum, size: GLsizeiptr, data: *const c_void, _usage: GLenum) {
+///     let data = unsafe { std::slice::from_raw_parts(data as *const u8, size as usize) };
+///     *STORE.lock().unwrap() = data.to_vec();
+/// }
+///
+/// extern "system" fn buffer_sub_data(_target: GLenum, offset: GLintptr, size: GLsizeiptr, data: *const c_void) {
+///     let data = unsafe { std::slice::from_raw_parts(data as *const u8, size as usize) };
+///     STORE.lock().unwrap()[offset as usize..][..size as usize].copy_from_slice(data);
+/// }
+///
+/// extern "system" fn get_buffer_sub_data(_target: GLenum, offset: GLintptr, size: GLsizeiptr, data: *mut c_void) {
+///     let data = unsafe { std::slice::from_raw_parts_mut(data as *mut u8, size as usize) };
+///     data.copy_from_slice(&STORE.lock().unwrap()[offset as usize..][..size as usize]);
+/// }
+///
+/// extern "system" fn get_error() -> GLenum {
+///     gl::NO_ERROR
+/// }
+///
+/// # fn main() {
+/// gl::load_with(|symbol| match symbol {
+///     "glGenBuffers" => gen_buffers as *const c_void,
+///     "glBindBuffer" => bind_buffer as *const c_void,
+///     "glBufferData" => buffer_data as *const c_void,
+///     "glBufferSubData" => buffer_sub_data as *const c_void,
+///     "glGetBufferSubData" => get_buffer_sub_data as *const c_void,
+///     "glGetError" => get_error as *const c_void,
+///     _ => ptr::null(),
+/// });
+///
+/// let buffer = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::ArrayBuffer, buffer);
+/// rgl::buffer_data(rgl::Target::ArrayBuffer, &[0u32; 8], rgl::Usage::DynamicDraw);
+///
+/// //Replace elements 2 to 4, the offset is in bytes
+/// rgl::buffer_sub_data(rgl::Target::ArrayBuffer, 2 * 4, &[7u32, 8, 9]);
+///
+/// let mut contents = [0u32; 8];
+/// rgl::get_buffer_sub_data(rgl::Target::ArrayBuffer, 0, &mut contents);
+/// assert_eq!(contents, [0, 0, 7, 8, 9, 0, 0, 0]);
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferSubData.xhtml
+pub fn buffer_sub_data<T: Plain>(target: enums::Target, offset: usize, data: &[T]) {
+    gl_call!(buffer_sub_data, gl::BufferSubData(
+        target as GLenum,
+        offset as GLintptr,
+        mem::size_of_val(data) as GLsizeiptr,
+        data_ptr(data),
+    ));
+}
+
+/// Replaces `size` bytes of the data store of the buffer bound to `target` with the bytes at `data`,
+/// starting at byte `offset`
+///
+/// Prefer `buffer_sub_data`, this is for data that is not in a slice of plain data
+///
+/// # Safety
+/// `data` must be valid for reads of `size` bytes
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferSubData.xhtml
+pub unsafe fn buffer_sub_data_raw(target: enums::Target, offset: usize, size: usize, data: *const c_void) {
+    gl_call!(buffer_sub_data_raw, gl::BufferSubData(target as GLenum, offset as GLintptr, size as GLsizeiptr, data));
+}
+
+/// Fills the whole buffer bound to `target` with a single repeated value
+///
+/// `data` is one element laid out as described by `format` and `type_`, which is converted to
+/// `internal_format` and repeated over the buffer. `None` fills the buffer with zeros
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearBufferData.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn clear_buffer_data(
+    target: enums::Target,
+    internal_format: enums::InternalFormat,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+    data: Option<&[u8]>,
+) {
+    gl_call!(clear_buffer_data, gl::ClearBufferData(
+        target as GLenum,
+        internal_format as GLenum,
+        format as GLenum,
+        type_ as GLenum,
+        data_ptr(data.unwrap_or(&[])),
+    ));
+}
+
+/// Fills `size` bytes of the buffer bound to `target`, starting at `offset`, with a single repeated value
+///
+/// See `clear_buffer_data`, `offset` and `size` must be multiples of the size of `internal_format`
+///
+/// # Examples
+/// ```rust,no_run
+/// //Set the second 256 bytes to 1.0
+/// rgl::clear_buffer_sub_data(
+///     rgl::Target::ShaderStorageBuffer,
+///     rgl::InternalFormat::R32F,
+///     256,
+///     256,
+///     rgl::PixelFormat::Red,
+///     rgl::PixelType::Float,
+///     Some(&1.0f32.to_ne_bytes()),
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearBufferSubData.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn clear_buffer_sub_data(
+    target: enums::Target,
+    internal_format: enums::InternalFormat,
+    offset: usize,
+    size: usize,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+    data: Option<&[u8]>,
+) {
+    gl_call!(clear_buffer_sub_data, gl::ClearBufferSubData(
+        target as GLenum,
+        internal_format as GLenum,
+        offset as GLintptr,
+        size as GLsizeiptr,
+        format as GLenum,
+        type_ as GLenum,
+        data_ptr(data.unwrap_or(&[])),
+    ));
+}
+
+/// Sets every byte of the buffer bound to `target` to 0
+///
+/// # Examples
+/// ```rust,no_run
+/// rgl::bind_buffer(rgl::Target::ShaderStorageBuffer, rgl::gen_buffer());
+/// rgl::buffer_reserve(rgl::Target::ShaderStorageBuffer, 64 * 1024 * 1024, rgl::Usage::DynamicCopy);
+/// rgl::zero_buffer(rgl::Target::ShaderStorageBuffer);
+/// ```
+#[cfg(not(feature = "gles2"))]
+pub fn zero_buffer(target: enums::Target) {
+    clear_buffer_data(target, enums::InternalFormat::R8, enums::PixelFormat::Red, enums::PixelType::UnsignedByte, None);
+}
+
+/// Reads the contents of the buffer bound to `target`, starting at byte `offset`, into `out`
+///
+/// Reading past the end of the buffer is an error, which the `error-check` feature reports as
+/// `InvalidValue`
+///
+/// # Examples
+/// ```rust,no_run
+/// let mut results = [0.0f32; 64];
+/// rgl::bind_buffer(rgl::Target::ShaderStorageBuffer, rgl::gen_buffer());
+/// //...dispatch a compute shader writing into the buffer
+/// rgl::get_buffer_sub_data(rgl::Target::ShaderStorageBuffer, 0, &mut results);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetBufferSubData.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn get_buffer_sub_data<T: Readback>(target: enums::Target, offset: usize, out: &mut [T]) {
+    gl_call!(get_buffer_sub_data, gl::GetBufferSubData(
+        target as GLenum,
+        offset as GLintptr,
+        mem::size_of_val(out) as GLsizeiptr,
+        out.as_mut_ptr() as *mut c_void,
+    ));
+}
+
+/// Reads `count` elements of the buffer bound to `target`, starting at byte `offset`, into a `Vec`
+///
+/// The `Vec` starts out zeroed, which is what it holds if GL reports an error instead of reading
+/// anything. Like `get_buffer_sub_data`, GL may write any bytes into the elements, see `Readback`
+///
+/// # Examples
+/// ```rust,no_run
+/// let captured: Vec<[f32; 4]> = rgl::get_buffer_sub_data_vec(rgl::Target::TransformFeedbackBuffer, 0, 128);
+/// ```
+///
+/// # Panics
+/// If `count` elements would not fit in memory
+#[cfg(not(feature = "gles2"))]
+pub fn get_buffer_sub_data_vec<T: Readback>(target: enums::Target, offset: usize, count: usize) -> Vec<T> {
+    assert!(
+        count.checked_mul(mem::size_of::<T>()).is_some_and(|size| size <= isize::MAX as usize),
+        "get_buffer_sub_data_vec: {} elements would not fit in memory",
+        count
+    );
+
+    //Zero is one of the bit patterns `Readback` allows
+    let mut data = vec![unsafe { mem::zeroed::<T>() }; count];
+    get_buffer_sub_data(target, offset, &mut data);
+    data
+}
+
+/// Copies `size` bytes between the buffers bound to `read_target` and `write_target`, on the GPU
+///
+/// Copying between overlapping ranges of the same buffer is an error, which the `error-check`
+/// feature reports as `InvalidValue`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCopyBufferSubData.xhtml
+pub fn copy_buffer_sub_data(
+    read_target: enums::Target,
+    write_target: enums::Target,
+    read_offset: usize,
+    write_offset: usize,
+    size: usize,
+) {
+    debug_assert!(
+        read_target as GLenum != write_target as GLenum
+            || read_offset.saturating_add(size) <= write_offset
+            || write_offset.saturating_add(size) <= read_offset,
+        "copy_buffer_sub_data: overlapping ranges of the same buffer"
+    );
+    gl_call!(copy_buffer_sub_data, gl::CopyBufferSubData(
+        read_target as GLenum,
+        write_target as GLenum,
+        read_offset as GLintptr,
+        write_offset as GLintptr,
+        size as GLsizeiptr,
+    ));
+}
+
+/// Copies the first `size` bytes of `src` to the start of `dst`
+///
+/// Binds `src` to `CopyReadBuffer` and `dst` to `CopyWriteBuffer`, leaving them bound
+///
+/// # Examples
+/// ```rust,no_run
+/// //Grow a vertex buffer, keeping its contents
+/// let old_size = 1024;
+/// let old = rgl::gen_buffer();
+/// //...
+/// let new = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::CopyWriteBuffer, new);
+/// rgl::buffer_reserve(rgl::Target::CopyWriteBuffer, old_size * 2, rgl::Usage::DynamicDraw);
+///
+/// rgl::copy_buffer(old, new, old_size);
+/// rgl::delete_buffer(old);
+/// ```
+pub fn copy_buffer(src: Buffer, dst: Buffer, size: usize) {
+    bind_buffer(enums::Target::CopyReadBuffer, src);
+    bind_buffer(enums::Target::CopyWriteBuffer, dst);
+    copy_buffer_sub_data(enums::Target::CopyReadBuffer, enums::Target::CopyWriteBuffer, 0, 0, size);
+}
+
+/// Reasons `map_buffer_range` can fail
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MapError {
+    /// Zero length ranges cannot be mapped
+    EmptyRange,
+    /// GL refused to map the range, for example because no buffer is bound to the target, the
+    /// range is out of bounds or the buffer is already mapped
+    Failed,
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MapError::EmptyRange => write!(f, "cannot map an empty buffer range"),
+            MapError::Failed => write!(f, "failed to map buffer range"),
+        }
+    }
+}
+
+impl error::Error for MapError {}
+
+/// The data store of a buffer was corrupted while it was mapped, its contents are undefined
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MapLost;
+
+impl fmt::Display for MapLost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "buffer data store was lost while mapped")
+    }
+}
+
+impl error::Error for MapLost {}
+
+/// A mapped range of a buffer's data store, unmapped when dropped
+///
+/// When mapped with `map_buffer_range`, the buffer must stay bound to the same target for as long
+/// as it is mapped
+pub struct MappedBuffer {
+    mapping: Mapping,
+    data: *mut u8,
+    length: usize,
+    flush_explicit: bool,
+}
+
+/// How a `MappedBuffer` finds its buffer again to unmap it
+#[derive(Clone, Copy)]
+enum Mapping {
+    Bound(enums::Target),
+    #[cfg(not(feature = "gles2"))]
+    Named(Buffer),
+}
+
+impl MappedBuffer {
+    /// Wraps a range mapped with `glMapNamedBufferRange`
+    #[cfg(not(feature = "gles2"))]
+    pub(crate) fn named(buffer: Buffer, data: *mut c_void, length: usize, access: enums::MapAccess) -> MappedBuffer {
+        MappedBuffer {
+            mapping: Mapping::Named(buffer),
+            data: data as *mut u8,
+            length,
+            flush_explicit: access.contains(enums::MapAccess::FLUSH_EXPLICIT),
+        }
+    }
+
+    /// Makes writes to `length` bytes of the mapping, starting at `offset`, visible to GL
+    ///
+    /// Only for mappings made with `MapAccess::FLUSH_EXPLICIT`, where unflushed writes are lost
+    /// when unmapping, or never seen by the GPU for persistent mappings that are not `COHERENT`.
+    /// `offset` is relative to the start of the mapping
+    ///
+    /// # Panics
+    /// If the mapping was made without `FLUSH_EXPLICIT`, or the range is not inside it
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # fn write() -> Result<(), rgl::MapError> {
+    /// //Write 4 scattered bytes of a large mapping, and only flush those
+    /// let access = rgl::MapAccess::WRITE | rgl::MapAccess::FLUSH_EXPLICIT;
+    /// let mut mapped = rgl::map_buffer_range(rgl::Target::ArrayBuffer, 0, 1024 * 1024, access)?;
+    /// mapped[4096..4100].copy_from_slice(&1.0f32.to_ne_bytes());
+    /// mapped.flush_range(4096, 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flush_range(&mut self, offset: usize, length: usize) {
+        assert!(self.flush_explicit, "flush_range on a buffer mapped without MapAccess::FLUSH_EXPLICIT");
+        assert!(
+            offset + length <= self.length,
+            "flush range {}..{} is outside the {} mapped bytes",
+            offset,
+            offset + length,
+            self.length
         );
+
+        match self.mapping {
+            Mapping::Bound(target) => flush_mapped_buffer_range(target, offset, length),
+            #[cfg(not(feature = "gles2"))]
+            Mapping::Named(buffer) => dsa::flush_mapped_named_buffer_range(buffer, offset, length),
+        }
+    }
+
+    /// Unmaps the buffer, reporting whether its data store was lost while mapped
+    ///
+    /// Dropping the `MappedBuffer` also unmaps it, but only logs a lost data store in debug builds
+    pub fn unmap(self) -> Result<(), MapLost> {
+        let mapping = self.mapping;
+        mem::forget(self);
+
+        if unmap_buffer(mapping) {
+            Ok(())
+        } else {
+            Err(MapLost)
+        }
     }
 }
 
-pub fn buffer_sub_data<T>(target: enums::Target, offset: isize, size: isize, data: &[T]) {
-    unsafe {
-        gl::BufferSubData(target as GLenum, offset, size, mem::transmute(&data[0]));
+impl Deref for MappedBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.data, self.length) }
     }
 }
 
-pub fn delete_buffers(count: GLsizei, buffers: *mut Buffer) {
-    unsafe {
-        gl::DeleteBuffers(count, &mut (*buffers).0);
+impl DerefMut for MappedBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.data, self.length) }
     }
 }
 
+impl Drop for MappedBuffer {
+    fn drop(&mut self) {
+        if !unmap_buffer(self.mapping) && cfg!(debug_assertions) {
+            eprintln!("rgl: {}", MapLost);
+        }
+    }
+}
+
+/// Maps `length` bytes of the buffer bound to `target`, starting at `offset`, into client memory
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn stream() -> Result<(), Box<dyn std::error::Error>> {
+/// let vertices = [0u8; 256];
+///
+/// let mut mapped = rgl::map_buffer_range(
+///     rgl::Target::ArrayBuffer,
+///     0,
+///     vertices.len(),
+///     rgl::MapAccess::WRITE | rgl::MapAccess::INVALIDATE_BUFFER,
+/// )?;
+/// mapped.copy_from_slice(&vertices);
+/// mapped.unmap()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glMapBufferRange.xhtml
+pub fn map_buffer_range(
+    target: enums::Target,
+    offset: usize,
+    length: usize,
+    access: enums::MapAccess,
+) -> Result<MappedBuffer, MapError> {
+    if length == 0 {
+        return Err(MapError::EmptyRange);
+    }
+
+    let data = gl_call!(map_buffer_range, gl::MapBufferRange(
+        target as GLenum,
+        offset as GLintptr,
+        length as GLsizeiptr,
+        access.bits(),
+    ));
+    if data.is_null() {
+        return Err(MapError::Failed);
+    }
+
+    Ok(MappedBuffer {
+        mapping: Mapping::Bound(target),
+        data: data as *mut u8,
+        length,
+        flush_explicit: access.contains(enums::MapAccess::FLUSH_EXPLICIT),
+    })
+}
+
+/// Makes writes to `length` bytes of the range mapped from the buffer bound to `target`, starting
+/// at `offset` into the mapping, visible to GL
+///
+/// The range must have been mapped with `MapAccess::FLUSH_EXPLICIT`, otherwise this is an error,
+/// which the `error-check` feature reports as `InvalidOperation`. Prefer `MappedBuffer::flush_range`,
+/// which checks that
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFlushMappedBufferRange.xhtml
+pub fn flush_mapped_buffer_range(target: enums::Target, offset: usize, length: usize) {
+    gl_call!(flush_mapped_buffer_range, gl::FlushMappedBufferRange(target as GLenum, offset as GLintptr, length as GLsizeiptr));
+}
+
+fn unmap_buffer(mapping: Mapping) -> bool {
+    let unmapped = match mapping {
+        Mapping::Bound(target) => gl_call!(unmap_buffer, gl::UnmapBuffer(target as GLenum)),
+        #[cfg(not(feature = "gles2"))]
+        Mapping::Named(buffer) => gl_call!(unmap_named_buffer, gl::UnmapNamedBuffer(buffer.0)),
+    };
+    unmapped == gl::TRUE
+}
+
+/// Deletes every buffer object in the slice
+///
+/// # Examples
+/// ```rust
+/// extern crate gl;
+/// extern crate rgl;
+///
+/// use gl::types::*;
+/// use std::os::raw::c_void;
+/// use std::ptr;
+/// use std::sync::Mutex;
+///
+/// //The names the stand ins for the driver have handed out, and those deleted since
+/// static GENERATED: Mutex<Vec<GLuint>> = Mutex::new(Vec::new());
+/// static DELETED: Mutex<Vec<GLuint>> = Mutex::new(Vec::new());
+///
+/// extern "system" fn gen_buffers(n: GLsizei, buffers: *mut GLuint) {
+///     let mut generated = GENERATED.lock().unwrap();
+///     for i in 0..n as usize {
+///         let name = generated.len() as GLuint + 1;
+///         generated.push(name);
+///         unsafe { *buffers.add(i) = name };
+///     }
+/// }
+///
+/// extern "system" fn bind_buffer(_target: GLenum, _buffer: GLuint) {}
+///
+/// extern "system" fn delete_buffers(n: GLsizei, buffers: *const GLuint) {
+///     let buffers = unsafe { std::slice::from_raw_parts(buffers, n as usize) };
+///     DELETED.lock().unwrap().extend_from_slice(buffers);
+/// }
+///
+/// extern "system" fn is_buffer(buffer: GLuint) -> GLboolean {
+///     let exists = GENERATED.lock().unwrap().contains(&buffer) && !DELETED.lock().unwrap().contains(&buffer);
+///     exists as GLboolean
+/// }
+///
+/// extern "system" fn get_error() -> GLenum {
+///     gl::NO_ERROR
+/// }
+///
+/// # fn main() {
+/// gl::load_with(|symbol| match symbol {
+///     "glGenBuffers" => gen_buffers as *const c_void,
+///     "glBindBuffer" => bind_buffer as *const c_void,
+///     "glDeleteBuffers" => delete_buffers as *const c_void,
+///     "glIsBuffer" => is_buffer as *const c_void,
+///     "glGetError" => get_error as *const c_void,
+///     _ => ptr::null(),
+/// });
+///
+/// let mut buffers = [rgl::Buffer(0); 3];
+/// rgl::gen_buffers(&mut buffers);
+/// for buffer in buffers.iter() {
+///     rgl::bind_buffer(rgl::Target::ArrayBuffer, *buffer);
+/// }
+/// assert!(buffers.iter().all(|buffer| rgl::is_buffer(*buffer)));
+///
+/// rgl::delete_buffers(&buffers);
+/// assert!(buffers.iter().all(|buffer| !rgl::is_buffer(*buffer)));
+/// assert_eq!(DELETED.lock().unwrap().len(), 3);
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteBuffers.xhtml
+pub fn delete_buffers(buffers: &[Buffer]) {
+    gl_call!(delete_buffers, gl::DeleteBuffers(buffers.len() as GLsizei, buffers.as_ptr() as *const GLuint));
+}
+
+/// Like `delete_buffers`, then sets every handle to 0 so that using them afterwards is obvious
+pub fn delete_buffers_zeroed(buffers: &mut [Buffer]) {
+    delete_buffers(buffers);
+    buffers.fill(Buffer(0));
+}
+
 pub fn delete_buffer(buffer: Buffer) {
-    unsafe {
-        gl::DeleteBuffers(1, &buffer.0);
+    delete_buffers(&[buffer]);
+}
+
+/// Whether `buffer` names a buffer object, false once it has been deleted
+///
+/// Names from `gen_buffers` only become objects when they are first bound, until then this returns
+/// false for them. Names from `create_buffers` are objects straight away. The other `is_*` queries
+/// all work the same way
+///
+/// # Examples
+/// ```rust,no_run
+/// let buffer = rgl::gen_buffer();
+/// assert!(!rgl::is_buffer(buffer));
+/// rgl::bind_buffer(rgl::Target::ArrayBuffer, buffer);
+/// assert!(rgl::is_buffer(buffer));
+///
+/// rgl::delete_buffer(buffer);
+/// assert!(!rgl::is_buffer(buffer));
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsBuffer.xhtml
+pub fn is_buffer(buffer: Buffer) -> bool {
+    gl_call!(is_buffer, gl::IsBuffer(buffer.0)) == gl::TRUE
+}
+
+/// Deletes every vertex array object in the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteVertexArrays.xhtml
+pub fn delete_vertex_arrays(arrays: &[VAO]) {
+    gl_call!(delete_vertex_arrays, gl::DeleteVertexArrays(arrays.len() as GLsizei, arrays.as_ptr() as *const GLuint));
+}
+
+/// Like `delete_vertex_arrays`, then sets every handle to 0 so that using them afterwards is obvious
+pub fn delete_vertex_arrays_zeroed(arrays: &mut [VAO]) {
+    delete_vertex_arrays(arrays);
+    arrays.fill(VAO(0));
+}
+
+pub fn delete_vertex_array(array: VAO) {
+    delete_vertex_arrays(&[array]);
+}
+
+/// Whether `array` names a vertex array object, see `is_buffer`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsVertexArray.xhtml
+pub fn is_vertex_array(array: VAO) -> bool {
+    gl_call!(is_vertex_array, gl::IsVertexArray(array.0)) == gl::TRUE
+}
+
+/// A buffer of elements of type `T`, which keeps track of how many it holds
+///
+/// Offsets and lengths are counted in elements rather than bytes. `T` must be plain data, see
+/// `Plain`. This does not own the buffer, call `delete` to delete it
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn particles() -> Result<(), rgl::BufferRangeError> {
+/// let mut positions: rgl::TypedBuffer<[f32; 3]> = rgl::TypedBuffer::new(rgl::Target::ArrayBuffer, rgl::Usage::DynamicDraw);
+/// positions.upload(&[[0.0; 3]; 100]);
+/// assert_eq!(positions.byte_len(), 100 * 12);
+///
+/// //Move particle 10, the offset is an element index
+/// positions.update(10, &[[1.0, 2.0, 3.0]])?;
+///
+/// //Writing past the end is an error rather than an INVALID_VALUE from GL
+/// assert!(positions.update(99, &[[0.0; 3]; 2]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub struct TypedBuffer<T> {
+    buffer: Buffer,
+    target: enums::Target,
+    usage: enums::Usage,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: Plain> TypedBuffer<T> {
+    /// Generates an empty buffer that `bind` binds to `target`, `usage` is used for every `upload`
+    pub fn new(target: enums::Target, usage: enums::Usage) -> TypedBuffer<T> {
+        TypedBuffer {
+            buffer: gen_buffer(),
+            target,
+            usage,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// The underlying buffer handle
+    pub fn buffer(&self) -> Buffer {
+        self.buffer
+    }
+
+    /// The number of elements uploaded with the last `upload`
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The size of the data store in bytes
+    pub fn byte_len(&self) -> usize {
+        self.len * mem::size_of::<T>()
+    }
+
+    /// Binds the buffer to its target
+    pub fn bind(&self) {
+        bind_buffer(self.target, self.buffer);
+    }
+
+    /// Unbinds whatever buffer is bound to this buffer's target
+    pub fn unbind(&self) {
+        unbind_buffer(self.target);
+    }
+
+    /// Binds the buffer and replaces its data store with `data`, see `buffer_data`
+    pub fn upload(&mut self, data: &[T]) {
+        self.bind();
+        buffer_data(self.target, data, self.usage);
+        self.len = data.len();
+    }
+
+    /// Binds the buffer and replaces the elements starting at element `offset` with `data`, see
+    /// `buffer_sub_data`
+    ///
+    /// Returns an error without calling GL if the range does not fit in the data store
+    pub fn update(&mut self, offset: usize, data: &[T]) -> Result<(), BufferRangeError> {
+        match offset.checked_add(data.len()) {
+            Some(end) if end <= self.len => (),
+            _ => {
+                return Err(BufferRangeError {
+                    offset,
+                    len: data.len(),
+                    buffer_len: self.len,
+                })
+            }
+        }
+
+        self.bind();
+        buffer_sub_data(self.target, offset * mem::size_of::<T>(), data);
+        Ok(())
+    }
+
+    /// Deletes the buffer
+    pub fn delete(self) {
+        delete_buffer(self.buffer);
+    }
+}
+
+/// Error returned by `TypedBuffer::update` for a range past the end of the buffer, in elements
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferRangeError {
+    pub offset: usize,
+    pub len: usize,
+    pub buffer_len: usize,
+}
+
+impl fmt::Display for BufferRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cannot write {} elements at offset {} of a buffer of {} elements",
+            self.len, self.offset, self.buffer_len
+        )
     }
 }
 
-pub fn delete_vertex_arrays(count: GLsizei, arrays: *mut VAO) {
-    unsafe {
-        gl::DeleteVertexArrays(count, &mut (*arrays).0);
+impl error::Error for BufferRangeError {}
+
+/// Pointer to the start of the slice, or null for an empty slice
+pub(crate) fn data_ptr<T: Plain>(data: &[T]) -> *const c_void {
+    if data.is_empty() {
+        ptr::null()
+    } else {
+        as_bytes(data).as_ptr() as *const c_void
     }
 }
 
-pub fn delete_vertex_array(array: *mut VAO) {
-    delete_vertex_arrays(1, array);
+#[cfg(feature = "bytemuck")]
+pub(crate) fn as_bytes<T: Plain>(data: &[T]) -> &[u8] {
+    bytemuck::cast_slice(data)
+}
+
+#[cfg(not(feature = "bytemuck"))]
+pub(crate) fn as_bytes<T: Plain>(data: &[T]) -> &[u8] {
+    unsafe { slice::from_raw_parts(data.as_ptr() as *const u8, mem::size_of_val(data)) }
 }
@@ -0,0 +1,166 @@
+//! Skipping binds of objects that are already bound
+//!
+//! Rebinding the same texture or program is not free, the driver still has to validate the call.
+//! A `StateCache` remembers what it last bound and skips binds that would change nothing. It only
+//! knows about binds made through it, so call `invalidate` whenever other code may have touched
+//! the bindings, or after deleting a bound object as its name can be reused.
+//!
+//! The free functions in the rest of the crate are not cached, and the two can be mixed as long as
+//! the cache is invalidated afterwards.
+//!
+//! # Examples
+//! ```rust
+//! extern crate gl;
+//! extern crate rgl;
+//!
+//! use gl::types::*;
+//! use std::os::raw::c_void;
+//! use std::ptr;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! static BINDS: AtomicUsize = AtomicUsize::new(0);
+//!
+//! extern "system" fn bind_buffer(_target: GLenum, _buffer: GLuint) {
+//!     BINDS.fetch_add(1, Ordering::SeqCst);
+//! }
+//!
+//! extern "system" fn get_error() -> GLenum {
+//!     gl::NO_ERROR
+//! }
+//!
+//! # fn main() {
+//! //Count the binds that reach GL, with stand ins for the driver's functions
+//! gl::load_with(|symbol| match symbol {
+//!     "glBindBuffer" => bind_buffer as *const c_void,
+//!     "glGetError" => get_error as *const c_void,
+//!     _ => ptr::null(),
+//! });
+//!
+//! let mut cache = rgl::cache::StateCache::new();
+//! cache.bind_buffer(rgl::Target::ArrayBuffer, rgl::Buffer(1));
+//! cache.bind_buffer(rgl::Target::ArrayBuffer, rgl::Buffer(1));
+//! assert_eq!(BINDS.load(Ordering::SeqCst), 1);
+//!
+//! cache.bind_buffer(rgl::Target::ArrayBuffer, rgl::Buffer(2));
+//! cache.bind_buffer_force(rgl::Target::ArrayBuffer, rgl::Buffer(2));
+//! assert_eq!(BINDS.load(Ordering::SeqCst), 3);
+//!
+//! cache.invalidate();
+//! cache.bind_buffer(rgl::Target::ArrayBuffer, rgl::Buffer(2));
+//! assert_eq!(BINDS.load(Ordering::SeqCst), 4);
+//! # }
+//! ```
+
+use super::buffers::{self, Buffer, VAO};
+use super::enums;
+use super::shaders::{self, Program};
+use super::textures::{self, Texture};
+
+use gl;
+use gl::types::*;
+use std::collections::HashMap;
+
+/// The bindings last made through this cache, `None` where they are unknown
+#[derive(Default)]
+pub struct StateCache {
+    program: Option<GLuint>,
+    vertex_array: Option<GLuint>,
+    active_texture: Option<GLuint>,
+    buffers: HashMap<GLenum, GLuint>,
+    /// Keyed by texture unit and target
+    textures: HashMap<(GLuint, GLenum), GLuint>,
+}
+
+impl StateCache {
+    /// Creates a cache that knows nothing about the current bindings
+    pub fn new() -> StateCache {
+        StateCache::default()
+    }
+
+    /// Forgets every binding, so the next bind of each kind always reaches GL
+    pub fn invalidate(&mut self) {
+        *self = StateCache::default();
+    }
+
+    /// Like `bind_buffer`, skipped if `buffer` is already bound to `target`
+    pub fn bind_buffer(&mut self, target: enums::Target, buffer: Buffer) {
+        if self.buffers.get(&(target as GLenum)) != Some(&buffer.0) {
+            self.bind_buffer_force(target, buffer);
+        }
+    }
+
+    /// Binds `buffer` to `target` even if the cache thinks it already is
+    pub fn bind_buffer_force(&mut self, target: enums::Target, buffer: Buffer) {
+        buffers::bind_buffer(target, buffer);
+        self.buffers.insert(target as GLenum, buffer.0);
+    }
+
+    /// Like `bind_vertex_array`, skipped if `array` is already bound
+    pub fn bind_vertex_array(&mut self, array: VAO) {
+        if self.vertex_array != Some(array.0) {
+            self.bind_vertex_array_force(array);
+        }
+    }
+
+    /// Binds `array` even if the cache thinks it already is
+    ///
+    /// The element array buffer binding belongs to the VAO, so it becomes unknown
+    pub fn bind_vertex_array_force(&mut self, array: VAO) {
+        buffers::bind_vertex_array(array);
+        self.vertex_array = Some(array.0);
+        self.buffers.remove(&gl::ELEMENT_ARRAY_BUFFER);
+    }
+
+    /// Like `use_program`, skipped if `program` is already in use
+    pub fn use_program(&mut self, program: Program) {
+        if self.program != Some(program.0) {
+            self.use_program_force(program);
+        }
+    }
+
+    /// Uses `program` even if the cache thinks it already is
+    pub fn use_program_force(&mut self, program: Program) {
+        shaders::use_program(program);
+        self.program = Some(program.0);
+    }
+
+    /// Like `active_texture`, skipped if `unit` is already active
+    pub fn active_texture(&mut self, unit: GLuint) {
+        if self.active_texture != Some(unit) {
+            self.active_texture_force(unit);
+        }
+    }
+
+    /// Activates `unit` even if the cache thinks it already is
+    pub fn active_texture_force(&mut self, unit: GLuint) {
+        textures::active_texture(unit);
+        self.active_texture = Some(unit);
+    }
+
+    /// Like `bind_texture`, skipped if `texture` is already bound to `target` of the active unit
+    ///
+    /// Binds are never skipped while the active unit is unknown, use `active_texture` or
+    /// `bind_texture_unit` on the cache first
+    pub fn bind_texture(&mut self, target: enums::TextureTarget, texture: Texture) {
+        let bound = self
+            .active_texture
+            .and_then(|unit| self.textures.get(&(unit, target as GLenum)));
+        if bound != Some(&texture.0) {
+            self.bind_texture_force(target, texture);
+        }
+    }
+
+    /// Binds `texture` to `target` of the active unit even if the cache thinks it already is
+    pub fn bind_texture_force(&mut self, target: enums::TextureTarget, texture: Texture) {
+        textures::bind_texture(target, texture);
+        if let Some(unit) = self.active_texture {
+            self.textures.insert((unit, target as GLenum), texture.0);
+        }
+    }
+
+    /// Like `bind_texture_unit`, skipping whichever of the two calls would change nothing
+    pub fn bind_texture_unit(&mut self, unit: GLuint, target: enums::TextureTarget, texture: Texture) {
+        self.active_texture(unit);
+        self.bind_texture(target, texture);
+    }
+}
@@ -0,0 +1,45 @@
+use super::enums;
+
+use gl;
+use gl::types::*;
+
+/// Launches `x * y * z` work groups of the active compute program
+///
+/// Dispatching with any dimension of 0 is legal, but does nothing
+///
+/// # Examples
+/// ```rust,no_run
+/// //Update 10000 particles in groups of 64 (layout(local_size_x = 64) in the shader), then draw them
+/// let particle_count: u32 = 10000;
+/// let update = rgl::create_program();
+/// let render = rgl::create_program();
+/// //...
+/// rgl::use_program(update);
+/// rgl::dispatch_compute(particle_count.div_ceil(64), 1, 1);
+///
+/// //The draw reads the particle buffer as vertex data
+/// rgl::memory_barrier(rgl::BarrierMask::VERTEX_ATTRIB_ARRAY);
+///
+/// rgl::use_program(render);
+/// rgl::draw_arrays_instanced(rgl::Primitive::TriangleStrip, 0, 4, particle_count as i32);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDispatchCompute.xhtml
+pub fn dispatch_compute(x: GLuint, y: GLuint, z: GLuint) {
+    gl_call!(dispatch_compute, gl::DispatchCompute(x, y, z));
+}
+
+/// Launches work groups of the active compute program, with the counts read from the buffer bound to
+/// `DispatchIndirectBuffer` at byte `offset`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDispatchComputeIndirect.xhtml
+pub fn dispatch_compute_indirect(offset: usize) {
+    gl_call!(dispatch_compute_indirect, gl::DispatchComputeIndirect(offset as GLintptr));
+}
+
+/// Orders memory writes by shaders before the given kinds of later accesses
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glMemoryBarrier.xhtml
+pub fn memory_barrier(barriers: enums::BarrierMask) {
+    gl_call!(memory_barrier, gl::MemoryBarrier(barriers.bits()));
+}
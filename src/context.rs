@@ -0,0 +1,167 @@
+//! Confining GL calls to the thread the context is current on
+//!
+//! A context is only current on one thread, and calling GL from any other is undefined behaviour
+//! that usually shows up as a crash in the driver. A `GlContext` is a token standing for the
+//! current context. It is neither `Send` nor `Sync`, so code that only calls GL through its
+//! methods cannot call it from another thread by accident, such as from a closure handed to a
+//! thread pool. The methods forward to the free functions of the same name, which stay available
+//! for code that does not want the token.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use rgl::context::GlContext;
+//!
+//! fn draw(gl: &GlContext, vao: rgl::VAO, program: rgl::Program) {
+//!     gl.clear(rgl::ClearMask::COLOR);
+//!     gl.use_program(program);
+//!     gl.bind_vertex_array(vao);
+//!     gl.draw_arrays(rgl::Primitive::Triangles, 0, 3);
+//! }
+//! ```
+//!
+//! Moving the token to another thread does not compile
+//!
+//! ```rust,compile_fail
+//! let gl = rgl::context::GlContext::assume_current();
+//! std::thread::spawn(move || {
+//!     gl.clear(rgl::ClearMask::COLOR);
+//! });
+//! ```
+
+use super::buffers::{self, Buffer, Plain, VAO};
+use super::drawing;
+use super::enums;
+use super::errors::{self, GlError};
+use super::framebuffers::{self, Framebuffer, FramebufferStatus};
+use super::shaders::{self, Program, Shader, ShaderError, UniformLocation};
+use super::state;
+use super::textures::{self, Texture};
+
+use gl::types::*;
+use std::marker::PhantomData;
+
+/// A token for the context current on this thread, see the module documentation
+#[derive(Debug)]
+pub struct GlContext {
+    //Raw pointers are neither Send nor Sync
+    _not_send: PhantomData<*const ()>,
+}
+
+impl GlContext {
+    /// Creates a token for the context current on this thread
+    ///
+    /// It is up to the caller that a context is current, and that it stays current for as long as
+    /// the token is used. Create the token once the context is made current and the functions are
+    /// loaded, and keep it next to the context
+    pub fn assume_current() -> GlContext {
+        GlContext { _not_send: PhantomData }
+    }
+}
+
+//Forwards each method to the free function of the same name
+macro_rules! forward {
+    ($($module:ident::$name:ident($($arg:ident: $type_:ty),*) $(-> $ret:ty)?;)*) => {
+        impl GlContext {
+            $(
+                #[doc = concat!("See `", stringify!($name), "`")]
+                pub fn $name(&self, $($arg: $type_),*) $(-> $ret)? {
+                    $module::$name($($arg),*)
+                }
+            )*
+        }
+    };
+}
+
+forward! {
+    //Buffers and vertex arrays
+    buffers::gen_buffer() -> Buffer;
+    buffers::bind_buffer(target: enums::Target, buffer: Buffer);
+    buffers::delete_buffer(buffer: Buffer);
+    buffers::gen_vertex_array() -> VAO;
+    buffers::bind_vertex_array(array: VAO);
+    buffers::delete_vertex_array(array: VAO);
+    buffers::enable_vertex_attrib_array(index: GLuint);
+    buffers::disable_vertex_attrib_array(index: GLuint);
+    buffers::vertex_attrib_pointer(index: GLuint, size: GLint, type_: enums::Type, normalised: bool, stride: GLsizei, offset: usize);
+    buffers::vertex_attrib_divisor(index: GLuint, divisor: GLuint);
+
+    //Shaders
+    shaders::create_shader(type_: enums::ShaderType) -> Shader;
+    shaders::shader_source(shader: Shader, source: &str);
+    shaders::compile_shader_checked(shader: Shader) -> Result<(), ShaderError>;
+    shaders::delete_shader(shader: Shader);
+    shaders::create_program() -> Program;
+    shaders::attach_shader(program: Program, shader: Shader);
+    shaders::detach_shader(program: Program, shader: Shader);
+    shaders::link_program_checked(program: Program) -> Result<(), ShaderError>;
+    shaders::use_program(program: Program);
+    shaders::delete_program(program: Program);
+    shaders::get_uniform_location(program: Program, name: &str) -> Option<UniformLocation>;
+    shaders::uniform1i(location: UniformLocation, v0: GLint);
+    shaders::uniform1f(location: UniformLocation, v0: GLfloat);
+    shaders::uniform4f(location: UniformLocation, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat);
+    shaders::uniform_matrix4fv(location: UniformLocation, transpose: bool, value: &[GLfloat]);
+
+    //Textures
+    textures::gen_texture() -> Texture;
+    textures::active_texture(unit: GLuint);
+    textures::bind_texture(target: enums::TextureTarget, texture: Texture);
+    textures::delete_texture(texture: Texture);
+    textures::tex_image_2d(
+        target: enums::TextureTarget,
+        level: GLint,
+        internal_format: enums::InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        format: enums::PixelFormat,
+        pixel_type: enums::PixelType,
+        data: Option<&[u8]>
+    );
+    textures::tex_parameteri(target: enums::TextureTarget, param_name: enums::TextureParameter, param: GLint);
+    textures::generate_mipmap(target: enums::TextureTarget);
+
+    //Framebuffers
+    framebuffers::gen_framebuffer() -> Framebuffer;
+    framebuffers::bind_framebuffer(target: enums::FramebufferTarget, framebuffer: Framebuffer);
+    framebuffers::delete_framebuffer(framebuffer: Framebuffer);
+    framebuffers::check_framebuffer_status(target: enums::FramebufferTarget) -> Result<(), FramebufferStatus>;
+
+    //State
+    state::enable(cap: enums::Capability);
+    state::disable(cap: enums::Capability);
+    state::viewport(x: GLint, y: GLint, width: GLsizei, height: GLsizei);
+    state::blend_func(src: enums::BlendFactor, dst: enums::BlendFactor);
+    state::depth_func(func: enums::CompareFunc);
+    state::depth_mask(write: bool);
+    state::cull_face(face: enums::Face);
+
+    //Drawing
+    drawing::clear_color(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat);
+    drawing::clear(mask: enums::ClearMask);
+    drawing::draw_arrays(primitive: enums::Primitive, first: GLint, count: GLsizei);
+    drawing::draw_elements(primitive: enums::Primitive, count: GLsizei, index_type: enums::IndexType);
+    drawing::draw_arrays_instanced(primitive: enums::Primitive, first: GLint, count: GLsizei, instance_count: GLsizei);
+    drawing::draw_elements_instanced(
+        primitive: enums::Primitive,
+        count: GLsizei,
+        index_type: enums::IndexType,
+        offset: usize,
+        instance_count: GLsizei
+    );
+
+    //Errors
+    errors::get_error() -> Option<GlError>;
+}
+
+//Generic functions, which the macro does not handle
+impl GlContext {
+    /// See `buffer_data`
+    pub fn buffer_data<T: Plain>(&self, target: enums::Target, data: &[T], usage: enums::Usage) {
+        buffers::buffer_data(target, data, usage)
+    }
+
+    /// See `buffer_sub_data`
+    pub fn buffer_sub_data<T: Plain>(&self, target: enums::Target, offset: usize, data: &[T]) {
+        buffers::buffer_sub_data(target, offset, data)
+    }
+}
@@ -0,0 +1,435 @@
+use gl;
+use gl::types::*;
+use std::ffi::CStr;
+use std::os::raw::c_void;
+use std::panic;
+use std::ptr;
+use std::slice;
+#[cfg(feature = "trace-calls")]
+use std::fmt;
+#[cfg(feature = "trace-calls")]
+use std::sync::RwLock;
+
+use super::buffers::{self, Buffer, VAO};
+use super::enums::{DebugSeverity, DebugSource, DebugType, ObjectType};
+use super::framebuffers::{self, Framebuffer, Renderbuffer};
+use super::queries::{self, Query};
+use super::samplers::{self, Sampler};
+use super::shaders::{self, Program, ProgramPipeline, Shader};
+use super::textures::{self, Texture};
+use super::transform_feedback::TransformFeedback;
+
+type Callback = Box<dyn Fn(DebugSource, DebugType, GLuint, DebugSeverity, &str) + Send + Sync>;
+
+/// Sets the function the driver calls with debug messages, replacing any previous callback
+///
+/// Messages are only generated while `Capability::DebugOutput` is enabled, the context usually
+/// needs to be created with the debug flag for anything beyond errors to be reported.
+///
+/// Unless `Capability::DebugOutputSynchronous` is enabled the driver may call the callback from
+/// any thread at any time, which is why it must be `Send + Sync`. The driver may still be running
+/// the previous callback when it is replaced or cleared, so callbacks are never freed; install
+/// one for the lifetime of the context rather than replacing it every frame.
+/// Panics inside the callback are caught and discarded as they cannot unwind into the driver.
+///
+/// # Examples
+/// ```rust,no_run
+/// rgl::enable(rgl::Capability::DebugOutput);
+/// rgl::enable(rgl::Capability::DebugOutputSynchronous);
+/// rgl::debug_message_callback(|source, type_, id, severity, message| {
+///     eprintln!("[{:?}] {:?} {:?} {}: {}", severity, source, type_, id, message);
+/// });
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDebugMessageCallback.xhtml
+pub fn debug_message_callback<F>(callback: F)
+where
+    F: Fn(DebugSource, DebugType, GLuint, DebugSeverity, &str) + Send + Sync + 'static,
+{
+    //Boxed twice so GL gets a thin pointer as the user parameter, and leaked as the driver may
+    //call it on another thread after it is replaced
+    let callback: Box<Callback> = Box::new(Box::new(callback));
+    let callback: &'static Callback = Box::leak(callback);
+    let user_param = callback as *const Callback as *const c_void;
+
+    let proc: GLDEBUGPROC = Some(trampoline);
+    gl_call!(debug_message_callback, gl::DebugMessageCallback(proc, user_param));
+}
+
+/// Removes the debug message callback, which is not freed, see `debug_message_callback`
+pub fn clear_debug_message_callback() {
+    gl_call!(clear_debug_message_callback, gl::DebugMessageCallback(None, ptr::null()));
+}
+
+/// Enables or disables the debug messages matching the filter, `None` matches anything
+///
+/// # Examples
+/// ```rust,no_run
+/// //Ignore notifications, which some drivers generate for every buffer upload
+/// rgl::debug_message_control(None, None, Some(rgl::DebugSeverity::Notification), false);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDebugMessageControl.xhtml
+pub fn debug_message_control(
+    source: Option<DebugSource>,
+    type_: Option<DebugType>,
+    severity: Option<DebugSeverity>,
+    enabled: bool,
+) {
+    gl_call!(debug_message_control, gl::DebugMessageControl(
+        source.map_or(gl::DONT_CARE, |source| source as GLenum),
+        type_.map_or(gl::DONT_CARE, |type_| type_ as GLenum),
+        severity.map_or(gl::DONT_CARE, |severity| severity as GLenum),
+        0,
+        ptr::null(),
+        enabled as GLboolean
+    ));
+}
+
+extern "system" fn trampoline(
+    source: GLenum,
+    type_: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    message: *const GLchar,
+    user_param: *mut c_void,
+) {
+    if user_param.is_null() || message.is_null() {
+        return;
+    }
+
+    let bytes = unsafe {
+        if length < 0 {
+            CStr::from_ptr(message).to_bytes()
+        } else {
+            slice::from_raw_parts(message as *const u8, length as usize)
+        }
+    };
+    let message = String::from_utf8_lossy(bytes);
+    let callback = unsafe { &*(user_param as *const Callback) };
+
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        callback(to_source(source), to_type(type_), id, to_severity(severity), &message);
+    }));
+}
+
+fn to_source(source: GLenum) -> DebugSource {
+    match source {
+        gl::DEBUG_SOURCE_API => DebugSource::Api,
+        gl::DEBUG_SOURCE_WINDOW_SYSTEM => DebugSource::WindowSystem,
+        gl::DEBUG_SOURCE_SHADER_COMPILER => DebugSource::ShaderCompiler,
+        gl::DEBUG_SOURCE_THIRD_PARTY => DebugSource::ThirdParty,
+        gl::DEBUG_SOURCE_APPLICATION => DebugSource::Application,
+        _ => DebugSource::Other,
+    }
+}
+
+fn to_type(type_: GLenum) -> DebugType {
+    match type_ {
+        gl::DEBUG_TYPE_ERROR => DebugType::Error,
+        gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR => DebugType::DeprecatedBehavior,
+        gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR => DebugType::UndefinedBehavior,
+        gl::DEBUG_TYPE_PORTABILITY => DebugType::Portability,
+        gl::DEBUG_TYPE_PERFORMANCE => DebugType::Performance,
+        gl::DEBUG_TYPE_MARKER => DebugType::Marker,
+        gl::DEBUG_TYPE_PUSH_GROUP => DebugType::PushGroup,
+        gl::DEBUG_TYPE_POP_GROUP => DebugType::PopGroup,
+        _ => DebugType::Other,
+    }
+}
+
+fn to_severity(severity: GLenum) -> DebugSeverity {
+    match severity {
+        gl::DEBUG_SEVERITY_HIGH => DebugSeverity::High,
+        gl::DEBUG_SEVERITY_MEDIUM => DebugSeverity::Medium,
+        gl::DEBUG_SEVERITY_LOW => DebugSeverity::Low,
+        _ => DebugSeverity::Notification,
+    }
+}
+
+//Object labels and debug groups
+
+/// Names an object in debug messages and GPU debuggers such as RenderDoc
+///
+/// Labels longer than `GL_MAX_LABEL_LENGTH` are truncated. Does nothing without GL 4.3 or
+/// `KHR_debug`, so labelling can be left in release builds
+///
+/// # Examples
+/// ```rust,no_run
+/// let vbo = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::ArrayBuffer, vbo);
+/// rgl::object_label(rgl::ObjectType::Buffer, vbo.0, "terrain vertices");
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glObjectLabel.xhtml
+pub fn object_label(identifier: ObjectType, name: GLuint, label: &str) {
+    if !gl::ObjectLabel::is_loaded() {
+        return;
+    }
+
+    let label = truncate_label(label);
+    gl_call!(object_label, gl::ObjectLabel(
+        identifier as GLenum,
+        name,
+        label.len() as GLsizei,
+        label.as_ptr() as *const GLchar
+    ));
+}
+
+macro_rules! label_fns {
+    ($($(#[$doc:meta])* $fn_name:ident($type_:ty) => $identifier:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $fn_name(object: $type_, label: &str) {
+                object_label(ObjectType::$identifier, object.0, label);
+            }
+        )*
+    };
+}
+
+label_fns! {
+    /// Labels a buffer, see `object_label`
+    label_buffer(Buffer) => Buffer,
+    /// Labels a shader, see `object_label`
+    label_shader(Shader) => Shader,
+    /// Labels a program, see `object_label`
+    label_program(Program) => Program,
+    /// Labels a vertex array object, see `object_label`
+    label_vertex_array(VAO) => VertexArray,
+    /// Labels a query object, see `object_label`
+    label_query(Query) => Query,
+    /// Labels a program pipeline, see `object_label`
+    label_program_pipeline(ProgramPipeline) => ProgramPipeline,
+    /// Labels a transform feedback object, see `object_label`
+    label_transform_feedback(TransformFeedback) => TransformFeedback,
+    /// Labels a sampler, see `object_label`
+    label_sampler(Sampler) => Sampler,
+    /// Labels a texture, see `object_label`
+    label_texture(Texture) => Texture,
+    /// Labels a renderbuffer, see `object_label`
+    label_renderbuffer(Renderbuffer) => Renderbuffer,
+    /// Labels a framebuffer, see `object_label`
+    label_framebuffer(Framebuffer) => Framebuffer,
+}
+
+/// Cuts `label` down to fewer than `GL_MAX_LABEL_LENGTH` bytes, on a character boundary
+fn truncate_label(label: &str) -> &str {
+    let mut max = 0;
+    gl_call!(truncate_label, gl::GetIntegerv(gl::MAX_LABEL_LENGTH, &mut max));
+    let max = (max.max(1) - 1) as usize;
+
+    if label.len() <= max {
+        return label;
+    }
+    let end = (0..=max).rev().find(|&end| label.is_char_boundary(end)).unwrap_or(0);
+    &label[..end]
+}
+
+/// Starts a named group of commands, shown as a collapsible region in GPU debuggers, until the
+/// matching `pop_debug_group`
+///
+/// The message is truncated like a label. Does nothing without GL 4.3 or `KHR_debug`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPushDebugGroup.xhtml
+pub fn push_debug_group(message: &str) {
+    if !gl::PushDebugGroup::is_loaded() {
+        return;
+    }
+
+    let message = truncate_label(message);
+    gl_call!(push_debug_group, gl::PushDebugGroup(
+        gl::DEBUG_SOURCE_APPLICATION,
+        0,
+        message.len() as GLsizei,
+        message.as_ptr() as *const GLchar
+    ));
+}
+
+/// Ends the group started by the last `push_debug_group`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPopDebugGroup.xhtml
+pub fn pop_debug_group() {
+    if !gl::PopDebugGroup::is_loaded() {
+        return;
+    }
+
+    gl_call!(pop_debug_group, gl::PopDebugGroup());
+}
+
+/// Runs `f` inside a debug group named `message`, popping it afterwards even if `f` panics
+///
+/// # Examples
+/// ```rust,no_run
+/// rgl::debug_group("shadow pass", || {
+///     //...draw the shadow casters
+/// });
+///
+/// let visible = rgl::debug_group("main pass", || {
+///     //...draw the scene, returning how many objects were drawn
+///     42
+/// });
+/// ```
+pub fn debug_group<F, R>(message: &str, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct Pop;
+
+    impl Drop for Pop {
+        fn drop(&mut self) {
+            pop_debug_group();
+        }
+    }
+
+    push_debug_group(message);
+    let _pop = Pop;
+    f()
+}
+
+/// Handles that can be checked for naming a live GL object, used by `assert_valid!`
+pub trait GlObject {
+    /// Whether the handle names an object, with the matching `is_*` query
+    fn is_valid(&self) -> bool;
+}
+
+macro_rules! impl_gl_object {
+    ($($type_:ty => $module:ident::$is:ident),* $(,)?) => {
+        $(
+            impl GlObject for $type_ {
+                fn is_valid(&self) -> bool {
+                    $module::$is(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_gl_object! {
+    Buffer => buffers::is_buffer,
+    VAO => buffers::is_vertex_array,
+    Framebuffer => framebuffers::is_framebuffer,
+    Renderbuffer => framebuffers::is_renderbuffer,
+    Query => queries::is_query,
+    Sampler => samplers::is_sampler,
+    Program => shaders::is_program,
+    Shader => shaders::is_shader,
+    Texture => textures::is_texture,
+}
+
+/// Panics if a handle does not name a live GL object, to catch use after delete
+///
+/// Like the `is_*` queries, names from the `gen_*` functions are not objects until they are
+/// first bound
+///
+/// # Examples
+/// ```rust,no_run
+/// #[macro_use]
+/// extern crate rgl;
+///
+/// # fn main() {
+/// let texture = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, texture);
+/// assert_valid!(texture);
+///
+/// rgl::delete_texture(texture);
+/// //Panics with "texture is not a live GL object"
+/// assert_valid!(texture);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_valid {
+    ($handle:expr) => {
+        assert!(
+            $crate::GlObject::is_valid(&$handle),
+            "{} is not a live GL object",
+            stringify!($handle)
+        )
+    };
+}
+
+/// Called with every wrapped GL call and its arguments, see `set_call_hook`
+#[cfg(feature = "trace-calls")]
+pub type CallHook = fn(&str);
+
+#[cfg(feature = "trace-calls")]
+static CALL_HOOK: RwLock<Option<CallHook>> = RwLock::new(None);
+
+/// Sets the function called before every wrapped GL call, `None` restores the default
+///
+/// The hook gets the name of the wrapper and the arguments passed to GL, with enums, handles and
+/// bitfields shown as the wrapper was given them, for example `bind_buffer(ArrayBuffer, Buffer(3))`,
+/// so a trace of a frame can be diffed against a known good run. Pointer arguments change between
+/// runs. The default hook prints each call to stderr. Only available with the `trace-calls`
+/// feature
+///
+/// # Examples
+/// ```rust,ignore
+/// //Route the trace through the log crate instead
+/// fn log_call(call: &str) {
+///     log::trace!(target: "rgl", "{}", call);
+/// }
+///
+/// rgl::set_call_hook(Some(log_call));
+/// ```
+///
+/// ```rust
+/// extern crate gl;
+/// extern crate rgl;
+///
+/// use gl::types::*;
+/// use std::os::raw::c_void;
+/// use std::ptr;
+/// use std::sync::Mutex;
+///
+/// static CALLS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// fn record(call: &str) {
+///     CALLS.lock().unwrap().push(call.to_string());
+/// }
+///
+/// extern "system" fn bind_buffer(_target: GLenum, _buffer: GLuint) {}
+///
+/// extern "system" fn clear(_mask: GLbitfield) {}
+///
+/// extern "system" fn get_error() -> GLenum {
+///     gl::NO_ERROR
+/// }
+///
+/// # fn main() {
+/// gl::load_with(|symbol| match symbol {
+///     "glBindBuffer" => bind_buffer as *const c_void,
+///     "glClear" => clear as *const c_void,
+///     "glGetError" => get_error as *const c_void,
+///     _ => ptr::null(),
+/// });
+///
+/// rgl::set_call_hook(Some(record));
+/// rgl::bind_buffer(rgl::Target::ArrayBuffer, rgl::Buffer(3));
+/// rgl::clear(rgl::ClearMask::COLOR);
+/// assert_eq!(*CALLS.lock().unwrap(), ["bind_buffer(ArrayBuffer, Buffer(3))", "clear(ClearMask(16384))"]);
+/// # }
+/// ```
+#[cfg(feature = "trace-calls")]
+pub fn set_call_hook(hook: Option<CallHook>) {
+    *CALL_HOOK.write().unwrap_or_else(|error| error.into_inner()) = hook;
+}
+
+#[cfg(feature = "trace-calls")]
+#[doc(hidden)]
+pub fn trace_call(function: &'static str, args: &[&dyn fmt::Debug]) {
+    let mut call = format!("{}(", function);
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            call.push_str(", ");
+        }
+        call.push_str(&format!("{:?}", arg));
+    }
+    call.push(')');
+
+    let hook = *CALL_HOOK.read().unwrap_or_else(|error| error.into_inner());
+    match hook {
+        Some(hook) => hook(&call),
+        None => eprintln!("{}", call),
+    }
+}
@@ -1,7 +1,7 @@
 use gl;
 use gl::types::*;
 
-use std::ptr;
+use std::os::raw::c_void;
 
 use super::enums;
 
@@ -14,88 +14,642 @@ use super::enums;
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearColor.xhtml
 pub fn clear_color(red: GLfloat, green: GLfloat, blue: GLfloat, aplha: GLfloat) {
-    unsafe {
-        gl::ClearColor(red, green, blue, aplha);
-    }
+    gl_call!(clear_color, gl::ClearColor(red, green, blue, aplha));
 }
 
-/*
-pub fn clear(mask: GLClearMask) {
-    unsafe {
-        gl::Clear(mask as GLenum);
-    }
+/// Specify the clear value for the depth buffer
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearDepth.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn clear_depth(depth: GLdouble) {
+    gl_call!(clear_depth, gl::ClearDepth(depth));
+}
+
+/// Specify the clear value for the depth buffer, the single precision version available on ES
+///
+/// Prefer this in code that also targets ES. Requires GL 4.1 or `ARB_ES2_compatibility` on desktop
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearDepth.xhtml
+pub fn clear_depthf(depth: GLfloat) {
+    gl_call!(clear_depthf, gl::ClearDepthf(depth));
+}
+
+/// Specify the clear value for the stencil buffer
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearStencil.xhtml
+pub fn clear_stencil(stencil: GLint) {
+    gl_call!(clear_stencil, gl::ClearStencil(stencil));
+}
+
+/// Clear buffers to their clear values
+///
+/// # Examples
+/// ```rust,no_run
+/// rgl::clear_color(0.1, 0.1, 0.1, 1.0);
+/// rgl::clear(rgl::ClearMask::COLOR | rgl::ClearMask::DEPTH);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClear.xhtml
+pub fn clear(mask: enums::ClearMask) {
+    gl_call!(clear, gl::Clear(mask.bits()));
+}
+
+/// Clear colour attachment `draw_buffer` of the draw framebuffer to a float colour
+///
+/// Unlike `clear`, every draw buffer can be cleared to its own value. `draw_buffer` indexes the
+/// list given to `draw_buffers`, not the attachment
+///
+/// # Examples
+/// ```rust,no_run
+/// //G-buffer: albedo, normals and an R32UI object id that clears to "no object"
+/// rgl::clear_color_buffer_f(0, [0.0, 0.0, 0.0, 1.0]);
+/// rgl::clear_color_buffer_f(1, [0.5, 0.5, 1.0, 0.0]);
+/// rgl::clear_color_buffer_u(2, [u32::MAX, 0, 0, 0]);
+/// rgl::clear_depth_stencil_buffer(1.0, 0);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearBuffer.xhtml
+pub fn clear_color_buffer_f(draw_buffer: GLint, color: [GLfloat; 4]) {
+    gl_call!(clear_color_buffer_f, gl::ClearBufferfv(gl::COLOR, draw_buffer, color.as_ptr()));
+}
+
+/// Clear colour attachment `draw_buffer` of the draw framebuffer, which must have a signed
+/// integer format
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearBuffer.xhtml
+pub fn clear_color_buffer_i(draw_buffer: GLint, color: [GLint; 4]) {
+    gl_call!(clear_color_buffer_i, gl::ClearBufferiv(gl::COLOR, draw_buffer, color.as_ptr()));
+}
+
+/// Clear colour attachment `draw_buffer` of the draw framebuffer, which must have an unsigned
+/// integer format
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearBuffer.xhtml
+pub fn clear_color_buffer_u(draw_buffer: GLint, color: [GLuint; 4]) {
+    gl_call!(clear_color_buffer_u, gl::ClearBufferuiv(gl::COLOR, draw_buffer, color.as_ptr()));
+}
+
+/// Clear the depth attachment of the draw framebuffer
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearBuffer.xhtml
+pub fn clear_depth_buffer(depth: GLfloat) {
+    gl_call!(clear_depth_buffer, gl::ClearBufferfv(gl::DEPTH, 0, &depth));
+}
+
+/// Clear the stencil attachment of the draw framebuffer
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearBuffer.xhtml
+pub fn clear_stencil_buffer(stencil: GLint) {
+    gl_call!(clear_stencil_buffer, gl::ClearBufferiv(gl::STENCIL, 0, &stencil));
+}
+
+/// Clear both the depth and stencil attachments of the draw framebuffer
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearBuffer.xhtml
+pub fn clear_depth_stencil_buffer(depth: GLfloat, stencil: GLint) {
+    gl_call!(clear_depth_stencil_buffer, gl::ClearBufferfi(gl::DEPTH_STENCIL, 0, depth, stencil));
 }
-*/
 
 /// Render primitives from array data
 ///
 /// # Examples
 /// ```rust,no_run
+/// let vertex_data: [f32; 6] = [0.0, 0.5, 0.5, -0.5, -0.5, -0.5];
+///
+/// let vao = rgl::gen_vertex_array();
+/// rgl::bind_vertex_array(vao);
+///
 /// let vbo = rgl::gen_buffer();
-/// //...
 /// rgl::bind_buffer(rgl::Target::ArrayBuffer, vbo);
+/// rgl::buffer_data(rgl::Target::ArrayBuffer, &vertex_data, rgl::Usage::StaticDraw);
+/// rgl::enable_vertex_attrib_array(0);
+/// rgl::vertex_attrib_pointer(0, 2, rgl::Type::Float, false, 0, 0);
+///
 /// rgl::draw_arrays(rgl::Primitive::Triangles, 0, 3);
 /// ```
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawArrays.xhtml
 pub fn draw_arrays(primitive: enums::Primitive, first: GLint, count: GLsizei) {
-    unsafe {
-        gl::DrawArrays(primitive as u32, first, count);
-    }
+    gl_call!(draw_arrays, gl::DrawArrays(primitive as u32, first, count));
 }
 
-/// Render primitives from array data
+/// Render primitives from the indices in the bound element array buffer
 ///
 /// # Examples
 /// ```rust,no_run
+/// let vertex_data: [f32; 8] = [-0.5, -0.5, 0.5, -0.5, 0.5, 0.5, -0.5, 0.5];
+/// let index_data: [u32; 6] = [0, 1, 2, 2, 3, 0];
+///
 /// let vao = rgl::gen_vertex_array();
-/// //...
 /// rgl::bind_vertex_array(vao);
-/// rgl::draw_elements(rgl::Primitive::Triangles, 36, rgl::Type::UInt);
+///
+/// let vbo = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::ArrayBuffer, vbo);
+/// rgl::buffer_data(rgl::Target::ArrayBuffer, &vertex_data, rgl::Usage::StaticDraw);
+/// rgl::enable_vertex_attrib_array(0);
+/// rgl::vertex_attrib_pointer(0, 2, rgl::Type::Float, false, 0, 0);
+///
+/// let ibo = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::ElementArrayBuffer, ibo);
+/// rgl::buffer_data(rgl::Target::ElementArrayBuffer, &index_data, rgl::Usage::StaticDraw);
+///
+/// rgl::draw_elements(rgl::Primitive::Triangles, 6, rgl::IndexType::UnsignedInt);
 /// ```
 ///
-/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawArrays.xhtml
-/// TODO: ptr thing
-pub fn draw_elements(primitive: enums::Primitive, count: GLsizei, type_: enums::Type) {
-    unsafe {
-        gl::DrawElements(primitive as GLenum, count, type_ as GLenum, ptr::null());
-    }
+/// Strips and fans can be split into several primitives in one draw with primitive restart, see
+/// `primitive_restart_index`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawElements.xhtml
+pub fn draw_elements(primitive: enums::Primitive, count: GLsizei, index_type: enums::IndexType) {
+    draw_elements_offset(primitive, count, index_type, 0);
+}
+
+/// Render primitives from the indices in the bound element array buffer, starting `offset` bytes in
+///
+/// # Examples
+/// ```rust,no_run
+/// //Draw the second triangle of the quad from draw_elements
+/// rgl::draw_elements_offset(rgl::Primitive::Triangles, 3, rgl::IndexType::UnsignedInt, 3 * 4);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawElements.xhtml
+pub fn draw_elements_offset(
+    primitive: enums::Primitive,
+    count: GLsizei,
+    index_type: enums::IndexType,
+    offset: usize,
+) {
+    gl_call!(draw_elements_offset, gl::DrawElements(primitive as GLenum, count, index_type as GLenum, offset as *const c_void));
+}
+
+/// Sets the index that starts a new primitive while `Capability::PrimitiveRestart` is enabled
+///
+/// The index is compared before conversion, so with `IndexType::UnsignedShort` it must fit in a
+/// u16 to ever match. `Capability::PrimitiveRestartFixedIndex` instead always uses the maximum value
+/// of the index type being drawn, 0xFFFF for u16, and needs no index to be set
+///
+/// # Examples
+/// ```rust,no_run
+/// //Two rows of a terrain strip in one draw, split by u32::MAX
+/// let indices: [u32; 9] = [0, 4, 1, 5, u32::MAX, 4, 8, 5, 9];
+/// //...upload the indices
+///
+/// rgl::enable(rgl::Capability::PrimitiveRestart);
+/// rgl::primitive_restart_index(u32::MAX);
+/// rgl::draw_elements(rgl::Primitive::TriangleStrip, indices.len() as i32, rgl::IndexType::UnsignedInt);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPrimitiveRestartIndex.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn primitive_restart_index(index: GLuint) {
+    gl_call!(primitive_restart_index, gl::PrimitiveRestartIndex(index));
+}
+
+/// Draw multiple instances of a range of array data
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawArraysInstanced.xhtml
+pub fn draw_arrays_instanced(
+    primitive: enums::Primitive,
+    first: GLint,
+    count: GLsizei,
+    instance_count: GLsizei,
+) {
+    gl_call!(draw_arrays_instanced, gl::DrawArraysInstanced(primitive as GLenum, first, count, instance_count));
 }
 
 /// Draw multiple instances of a set of elements
 ///
 /// # Examples
 /// ```rust,no_run
-/// let vao = rgl::gen_vertex_array();
-/// rgl::bind_vertex_array(vao);
-/// rgl::draw_elements_instanced(rgl::Primitive::Triangles, 36, rgl::Type::UInt, 8);
+/// //The vertex shader places each quad using its instance index
+/// let vertex_source = "#version 330 core
+/// layout (location = 0) in vec2 position;
+///
+/// void main() {
+///     vec2 cell = vec2(gl_InstanceID % 10, gl_InstanceID / 10);
+///     gl_Position = vec4((position + cell) / 5.0 - 1.0, 0.0, 1.0);
+/// }";
+///
+/// let quad = rgl::gen_vertex_array();
+/// //...
+/// rgl::bind_vertex_array(quad);
+/// rgl::draw_elements_instanced(rgl::Primitive::Triangles, 6, rgl::IndexType::UnsignedInt, 0, 100);
 /// ```
 ///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawElementsInstanced.xhtml
-/// TODO: Ptr thing
 pub fn draw_elements_instanced(
     primitive: enums::Primitive,
     count: GLsizei,
-    type_: enums::Type,
-    prim_count: GLsizei,
+    index_type: enums::IndexType,
+    offset: usize,
+    instance_count: GLsizei,
 ) {
-    unsafe {
-        gl::DrawElementsInstanced(
-            primitive as GLenum,
-            count,
-            type_ as GLenum,
-            ptr::null(),
-            prim_count,
-        );
-    }
+    gl_call!(draw_elements_instanced, gl::DrawElementsInstanced(
+        primitive as GLenum,
+        count,
+        index_type as GLenum,
+        offset as *const c_void,
+        instance_count,
+    ));
 }
 
-
-/// render primitives from array data
-/// 
+/// Like `draw_elements_offset`, with a hint that every index lies between `start` and `end`
+///
+/// The driver can use the range to avoid scanning the indices, drawing is the same as without it
+///
 /// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawRangeElements.xhtml
-pub fn draw_range_elements(mode: enums::Primitive, start: GLuint, end: GLuint, count: GLsizei, type_: enums::Type) {
-    unsafe {
-        gl::DrawRangeElements(mode as GLenum, start, end, count, type_ as GLenum, ptr::null());
-    }
-}
\ No newline at end of file
+pub fn draw_range_elements(
+    primitive: enums::Primitive,
+    start: GLuint,
+    end: GLuint,
+    count: GLsizei,
+    index_type: enums::IndexType,
+    offset: usize,
+) {
+    gl_call!(draw_range_elements, gl::DrawRangeElements(
+        primitive as GLenum,
+        start,
+        end,
+        count,
+        index_type as GLenum,
+        offset as *const c_void,
+    ));
+}
+
+/// Like `draw_elements_offset`, with `base_vertex` added to every index before fetching vertices
+///
+/// This lets several meshes share one vertex and index buffer without rewriting their indices
+///
+/// # Examples
+/// ```rust,no_run
+/// struct SubMesh {
+///     index_offset: usize,
+///     index_count: i32,
+///     base_vertex: i32,
+/// }
+///
+/// let sub_meshes: Vec<SubMesh> = Vec::new();
+/// //...fill one vertex buffer and one index buffer with every mesh
+///
+/// for mesh in sub_meshes.iter() {
+///     rgl::draw_elements_base_vertex(
+///         rgl::Primitive::Triangles,
+///         mesh.index_count,
+///         rgl::IndexType::UnsignedShort,
+///         mesh.index_offset,
+///         mesh.base_vertex,
+///     );
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawElementsBaseVertex.xhtml
+pub fn draw_elements_base_vertex(
+    primitive: enums::Primitive,
+    count: GLsizei,
+    index_type: enums::IndexType,
+    offset: usize,
+    base_vertex: GLint,
+) {
+    gl_call!(draw_elements_base_vertex, gl::DrawElementsBaseVertex(
+        primitive as GLenum,
+        count,
+        index_type as GLenum,
+        offset as *const c_void,
+        base_vertex,
+    ));
+}
+
+/// Combination of `draw_range_elements` and `draw_elements_base_vertex`
+///
+/// `start` and `end` bound the indices before `base_vertex` is added
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawRangeElementsBaseVertex.xhtml
+pub fn draw_range_elements_base_vertex(
+    primitive: enums::Primitive,
+    start: GLuint,
+    end: GLuint,
+    count: GLsizei,
+    index_type: enums::IndexType,
+    offset: usize,
+    base_vertex: GLint,
+) {
+    gl_call!(draw_range_elements_base_vertex, gl::DrawRangeElementsBaseVertex(
+        primitive as GLenum,
+        start,
+        end,
+        count,
+        index_type as GLenum,
+        offset as *const c_void,
+        base_vertex,
+    ));
+}
+
+/// Combination of `draw_elements_instanced` and `draw_elements_base_vertex`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawElementsInstancedBaseVertex.xhtml
+pub fn draw_elements_instanced_base_vertex(
+    primitive: enums::Primitive,
+    count: GLsizei,
+    index_type: enums::IndexType,
+    offset: usize,
+    instance_count: GLsizei,
+    base_vertex: GLint,
+) {
+    gl_call!(draw_elements_instanced_base_vertex, gl::DrawElementsInstancedBaseVertex(
+        primitive as GLenum,
+        count,
+        index_type as GLenum,
+        offset as *const c_void,
+        instance_count,
+        base_vertex,
+    ));
+}
+
+/// Like `draw_arrays_instanced`, with per-instance attributes starting at `base_instance`
+///
+/// `gl_InstanceID` still starts at 0. Requires GL 4.2 or `ARB_base_instance`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawArraysInstancedBaseInstance.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn draw_arrays_instanced_base_instance(
+    primitive: enums::Primitive,
+    first: GLint,
+    count: GLsizei,
+    instance_count: GLsizei,
+    base_instance: GLuint,
+) {
+    gl_call!(draw_arrays_instanced_base_instance, gl::DrawArraysInstancedBaseInstance(
+        primitive as GLenum,
+        first,
+        count,
+        instance_count,
+        base_instance,
+    ));
+}
+
+/// Like `draw_elements_instanced_base_vertex`, with per-instance attributes starting at
+/// `base_instance`
+///
+/// Batches of static geometry can then keep their per-instance data in one buffer too. Requires
+/// GL 4.2 or `ARB_base_instance`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawElementsInstancedBaseVertexBaseInstance.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn draw_elements_instanced_base_vertex_base_instance(
+    primitive: enums::Primitive,
+    count: GLsizei,
+    index_type: enums::IndexType,
+    offset: usize,
+    instance_count: GLsizei,
+    base_vertex: GLint,
+    base_instance: GLuint,
+) {
+    gl_call!(draw_elements_instanced_base_vertex_base_instance, gl::DrawElementsInstancedBaseVertexBaseInstance(
+        primitive as GLenum,
+        count,
+        index_type as GLenum,
+        offset as *const c_void,
+        instance_count,
+        base_vertex,
+        base_instance,
+    ));
+}
+
+/// Draw several ranges of array data in one call, range `i` starts at `firsts[i]` with
+/// `counts[i]` vertices
+///
+/// # Panics
+/// If `firsts` and `counts` have different lengths
+///
+/// # Examples
+/// ```rust,no_run
+/// //Three terrain chunks of 64 vertices packed one after another
+/// let firsts = [0, 64, 128];
+/// let counts = [64, 64, 64];
+/// rgl::multi_draw_arrays(rgl::Primitive::TriangleStrip, &firsts, &counts);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glMultiDrawArrays.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn multi_draw_arrays(primitive: enums::Primitive, firsts: &[GLint], counts: &[GLsizei]) {
+    assert!(
+        firsts.len() == counts.len(),
+        "multi_draw_arrays was given {} firsts but {} counts",
+        firsts.len(),
+        counts.len()
+    );
+    gl_call!(multi_draw_arrays, gl::MultiDrawArrays(
+        primitive as GLenum,
+        firsts.as_ptr(),
+        counts.as_ptr(),
+        counts.len() as GLsizei,
+    ));
+}
+
+/// Draw several ranges of the bound element array buffer in one call, range `i` starts
+/// `offsets[i]` bytes in and has `counts[i]` indices
+///
+/// # Panics
+/// If `counts` and `offsets` have different lengths
+///
+/// # Examples
+/// ```rust,no_run
+/// //Two UI panels of 6 u16 indices each
+/// let counts = [6, 6];
+/// let offsets = [0, 6 * 2];
+/// rgl::multi_draw_elements(rgl::Primitive::Triangles, &counts, rgl::IndexType::UnsignedShort, &offsets);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glMultiDrawElements.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn multi_draw_elements(
+    primitive: enums::Primitive,
+    counts: &[GLsizei],
+    index_type: enums::IndexType,
+    offsets: &[usize],
+) {
+    assert!(
+        counts.len() == offsets.len(),
+        "multi_draw_elements was given {} counts but {} offsets",
+        counts.len(),
+        offsets.len()
+    );
+    let offsets: Vec<*const c_void> = offsets.iter().map(|&offset| offset as *const c_void).collect();
+    gl_call!(multi_draw_elements, gl::MultiDrawElements(
+        primitive as GLenum,
+        counts.as_ptr(),
+        index_type as GLenum,
+        offsets.as_ptr(),
+        counts.len() as GLsizei,
+    ));
+}
+
+/// The parameters of one draw read by `draw_arrays_indirect`, laid out as GL expects
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DrawArraysIndirectCommand {
+    pub count: GLuint,
+    pub instance_count: GLuint,
+    pub first: GLuint,
+    /// Must be 0 before GL 4.2
+    pub base_instance: GLuint,
+}
+
+/// The parameters of one draw read by `draw_elements_indirect`, laid out as GL expects
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DrawElementsIndirectCommand {
+    pub count: GLuint,
+    pub instance_count: GLuint,
+    /// Counted in indices, not bytes
+    pub first_index: GLuint,
+    pub base_vertex: GLint,
+    /// Must be 0 before GL 4.2
+    pub base_instance: GLuint,
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for DrawArraysIndirectCommand {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for DrawArraysIndirectCommand {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for DrawElementsIndirectCommand {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for DrawElementsIndirectCommand {}
+
+/// Render primitives from array data, with the parameters read from the `DrawArraysIndirectCommand`
+/// at byte `offset` in the buffer bound to `DrawIndirectBuffer`
+///
+/// Requires GL 4.0 or `ARB_draw_indirect`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawArraysIndirect.xhtml
+pub fn draw_arrays_indirect(primitive: enums::Primitive, offset: usize) {
+    gl_call!(draw_arrays_indirect, gl::DrawArraysIndirect(primitive as GLenum, offset as *const c_void));
+}
+
+/// Render primitives from the bound element array buffer, with the parameters read from the
+/// `DrawElementsIndirectCommand` at byte `offset` in the buffer bound to `DrawIndirectBuffer`
+///
+/// Requires GL 4.0 or `ARB_draw_indirect`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawElementsIndirect.xhtml
+pub fn draw_elements_indirect(primitive: enums::Primitive, index_type: enums::IndexType, offset: usize) {
+    gl_call!(draw_elements_indirect, gl::DrawElementsIndirect(
+        primitive as GLenum,
+        index_type as GLenum,
+        offset as *const c_void,
+    ));
+}
+
+/// Like `draw_arrays_indirect`, for `draw_count` commands starting at byte `offset`
+///
+/// `stride` is the byte distance between commands, 0 means they are tightly packed. Requires
+/// GL 4.3 or `ARB_multi_draw_indirect`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glMultiDrawArraysIndirect.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn multi_draw_arrays_indirect(primitive: enums::Primitive, offset: usize, draw_count: GLsizei, stride: GLsizei) {
+    gl_call!(multi_draw_arrays_indirect, gl::MultiDrawArraysIndirect(
+        primitive as GLenum,
+        offset as *const c_void,
+        draw_count,
+        stride,
+    ));
+}
+
+/// Like `draw_elements_indirect`, for `draw_count` commands starting at byte `offset`
+///
+/// `stride` is the byte distance between commands, 0 means they are tightly packed. Requires
+/// GL 4.3 or `ARB_multi_draw_indirect`
+///
+/// # Examples
+/// ```rust,no_run
+/// //One command per sub-mesh of a merged vertex and index buffer
+/// let commands = [
+///     rgl::DrawElementsIndirectCommand { count: 36, instance_count: 1, first_index: 0, base_vertex: 0, base_instance: 0 },
+///     rgl::DrawElementsIndirectCommand { count: 120, instance_count: 10, first_index: 36, base_vertex: 24, base_instance: 1 },
+/// ];
+///
+/// let indirect = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::DrawIndirectBuffer, indirect);
+/// rgl::buffer_data(rgl::Target::DrawIndirectBuffer, &commands, rgl::Usage::DynamicDraw);
+///
+/// rgl::multi_draw_elements_indirect(
+///     rgl::Primitive::Triangles,
+///     rgl::IndexType::UnsignedInt,
+///     0,
+///     commands.len() as i32,
+///     0,
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glMultiDrawElementsIndirect.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn multi_draw_elements_indirect(
+    primitive: enums::Primitive,
+    index_type: enums::IndexType,
+    offset: usize,
+    draw_count: GLsizei,
+    stride: GLsizei,
+) {
+    gl_call!(multi_draw_elements_indirect, gl::MultiDrawElementsIndirect(
+        primitive as GLenum,
+        index_type as GLenum,
+        offset as *const c_void,
+        draw_count,
+        stride,
+    ));
+}
+
+/// Sets the number of control points per patch drawn with `Primitive::Patches`
+///
+/// Requires GL 4.0 or `ARB_tessellation_shader`
+///
+/// # Examples
+/// ```rust,no_run
+/// //Terrain made of quads with 4 control points each
+/// let control = rgl::create_shader(rgl::ShaderType::TessControl);
+/// let evaluation = rgl::create_shader(rgl::ShaderType::TessEvaluation);
+/// //...compile them and link them with a vertex and fragment shader
+///
+/// assert!(rgl::max_patch_vertices() >= 4);
+/// rgl::patch_vertices(4);
+/// rgl::draw_arrays(rgl::Primitive::Patches, 0, 64 * 4);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPatchParameter.xhtml
+pub fn patch_vertices(vertices: GLint) {
+    gl_call!(
+        patch_vertices,
+        gl::PatchParameteri(enums::PatchParam::Vertices as GLenum, vertices)
+    );
+}
+
+/// Sets the 4 outer tessellation levels used when no tessellation control shader is active
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPatchParameter.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn patch_default_outer_level(levels: &[GLfloat; 4]) {
+    gl_call!(
+        patch_default_outer_level,
+        gl::PatchParameterfv(enums::PatchParam::DefaultOuterLevel as GLenum, levels.as_ptr())
+    );
+}
+
+/// Sets the 2 inner tessellation levels used when no tessellation control shader is active
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPatchParameter.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn patch_default_inner_level(levels: &[GLfloat; 2]) {
+    gl_call!(
+        patch_default_inner_level,
+        gl::PatchParameterfv(enums::PatchParam::DefaultInnerLevel as GLenum, levels.as_ptr())
+    );
+}
+
+/// The largest number of control points per patch, at least 32
+pub fn max_patch_vertices() -> GLint {
+    let mut vertices = 0;
+    gl_call!(max_patch_vertices, gl::GetIntegerv(gl::MAX_PATCH_VERTICES, &mut vertices));
+    vertices
+}
@@ -0,0 +1,308 @@
+//! Direct state access counterparts of the bind-based functions
+//!
+//! These take the object to operate on directly, rather than acting on whatever is bound to a
+//! target, so uploads do not disturb the bindings used for drawing. They require GL 4.5 or
+//! `ARB_direct_state_access`, and objects should be created with the `create_*` functions, as
+//! names from the `gen_*` functions do not become objects until they are first bound.
+
+use super::buffers::{self, Buffer, MapError, MappedBuffer, Plain, VAO};
+use super::enums;
+use super::textures::{self, Texture};
+
+use gl;
+use gl::types::*;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr;
+
+/// Creates and initializes the data store of `buffer`, see `buffer_data`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferData.xhtml
+pub fn named_buffer_data<T: Plain>(buffer: Buffer, data: &[T], usage: enums::Usage) {
+    gl_call!(named_buffer_data, gl::NamedBufferData(
+        buffer.0,
+        mem::size_of_val(data) as GLsizeiptr,
+        buffers::data_ptr(data),
+        usage as GLenum,
+    ));
+}
+
+/// Replaces part of the data store of `buffer` with `data`, starting at byte `offset`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferSubData.xhtml
+pub fn named_buffer_sub_data<T: Plain>(buffer: Buffer, offset: usize, data: &[T]) {
+    gl_call!(named_buffer_sub_data, gl::NamedBufferSubData(
+        buffer.0,
+        offset as GLintptr,
+        mem::size_of_val(data) as GLsizeiptr,
+        buffers::data_ptr(data),
+    ));
+}
+
+/// Creates an immutable data store for `buffer`, initialized with `data`, see `buffer_storage`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferStorage.xhtml
+pub fn named_buffer_storage<T: Plain>(buffer: Buffer, data: &[T], flags: enums::StorageFlags) {
+    gl_call!(named_buffer_storage, gl::NamedBufferStorage(
+        buffer.0,
+        mem::size_of_val(data) as GLsizeiptr,
+        buffers::data_ptr(data),
+        flags.bits(),
+    ));
+}
+
+/// Creates an immutable data store of `size` bytes for `buffer`, without any initial data, see
+/// `buffer_storage_reserve`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBufferStorage.xhtml
+pub fn named_buffer_storage_reserve(buffer: Buffer, size: usize, flags: enums::StorageFlags) {
+    gl_call!(named_buffer_storage_reserve, gl::NamedBufferStorage(buffer.0, size as GLsizeiptr, ptr::null(), flags.bits()));
+}
+
+/// Maps `length` bytes of `buffer`, starting at `offset`, into client memory, see `map_buffer_range`
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn upload() -> Result<(), rgl::MapError> {
+/// let vbo = rgl::create_buffer();
+/// rgl::named_buffer_storage(vbo, &[0u8; 1024], rgl::StorageFlags::MAP_WRITE);
+///
+/// let mut mapped = rgl::map_named_buffer_range(vbo, 0, 1024, rgl::MapAccess::WRITE)?;
+/// mapped[..4].copy_from_slice(&1.0f32.to_ne_bytes());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glMapBufferRange.xhtml
+pub fn map_named_buffer_range(
+    buffer: Buffer,
+    offset: usize,
+    length: usize,
+    access: enums::MapAccess,
+) -> Result<MappedBuffer, MapError> {
+    if length == 0 {
+        return Err(MapError::EmptyRange);
+    }
+
+    let data = gl_call!(map_named_buffer_range, gl::MapNamedBufferRange(
+        buffer.0,
+        offset as GLintptr,
+        length as GLsizeiptr,
+        access.bits(),
+    ));
+    if data.is_null() {
+        return Err(MapError::Failed);
+    }
+
+    Ok(MappedBuffer::named(buffer, data, length, access))
+}
+
+/// Makes writes to part of the range mapped from `buffer` visible to GL, see
+/// `flush_mapped_buffer_range`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFlushMappedBufferRange.xhtml
+pub fn flush_mapped_named_buffer_range(buffer: Buffer, offset: usize, length: usize) {
+    gl_call!(flush_mapped_named_buffer_range, gl::FlushMappedNamedBufferRange(
+        buffer.0,
+        offset as GLintptr,
+        length as GLsizeiptr,
+    ));
+}
+
+/// Copies `size` bytes from `read` to `write` on the GPU, see `copy_buffer_sub_data`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCopyBufferSubData.xhtml
+pub fn copy_named_buffer_sub_data(read: Buffer, write: Buffer, read_offset: usize, write_offset: usize, size: usize) {
+    debug_assert!(
+        read.0 != write.0
+            || read_offset.saturating_add(size) <= write_offset
+            || write_offset.saturating_add(size) <= read_offset,
+        "copy_named_buffer_sub_data: overlapping ranges of the same buffer"
+    );
+    gl_call!(copy_named_buffer_sub_data, gl::CopyNamedBufferSubData(
+        read.0,
+        write.0,
+        read_offset as GLintptr,
+        write_offset as GLintptr,
+        size as GLsizeiptr,
+    ));
+}
+
+/// Replaces a rectangle of a level of a two-dimensional texture, see `tex_sub_image_2d`
+///
+/// # Panics
+/// If `data` is smaller than the rectangle, laid out by the current unpack state, see `PixelLayout::set_unpack`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexSubImage2D.xhtml
+pub fn texture_sub_image_2d<T: Plain>(
+    texture: Texture,
+    level: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+    data: &[T],
+) {
+    let data = buffers::as_bytes(data);
+    textures::check_pixel_data(data, textures::current_unpack().data_size(width, height, format, type_));
+    gl_call!(texture_sub_image_2d, gl::TextureSubImage2D(
+        texture.0,
+        level,
+        x,
+        y,
+        width,
+        height,
+        format as GLenum,
+        type_ as GLenum,
+        data.as_ptr() as *const c_void,
+    ));
+}
+
+/// Reads a box of mip level `level` of `texture` into a newly allocated `Vec`, without binding it
+///
+/// The `Vec` is sized like `get_tex_image`'s, and for two-dimensional textures `z` is 0 and `depth`
+/// 1. Requires GL 4.5 or `ARB_get_texture_sub_image`
+///
+/// # Panics
+/// If the box would not fit in memory
+///
+/// # Examples
+/// ```rust,no_run
+/// # let texture = rgl::create_texture(rgl::TextureTarget::Texture2d);
+/// //Read back the 16x16 tile at (64, 32)
+/// let tile = rgl::get_texture_sub_image(texture, 0, 64, 32, 0, 16, 16, 1, rgl::PixelFormat::Rgba, rgl::PixelType::UnsignedByte);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetTextureSubImage.xhtml
+pub fn get_texture_sub_image(
+    texture: Texture,
+    level: GLint,
+    x: GLint,
+    y: GLint,
+    z: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+) -> Vec<u8> {
+    let size = textures::PixelLayout::pack()
+        .data_size_3d(width, height, depth, format, type_)
+        .expect("get_texture_sub_image would not fit in memory");
+    let mut pixels = vec![0; size];
+    textures::without_pack_buffer(|| gl_call!(get_texture_sub_image, gl::GetTextureSubImage(
+        texture.0,
+        level,
+        x,
+        y,
+        z,
+        width,
+        height,
+        depth,
+        format as GLenum,
+        type_ as GLenum,
+        pixels.len().min(GLsizei::MAX as usize) as GLsizei,
+        pixels.as_mut_ptr() as *mut c_void,
+    )));
+    pixels
+}
+
+/// Sets an integer parameter of `texture`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexParameter.xhtml
+pub fn texture_parameteri(texture: Texture, param_name: enums::TextureParameter, param: GLint) {
+    gl_call!(texture_parameteri, gl::TextureParameteri(texture.0, param_name as GLenum, param));
+}
+
+/// Sets a float parameter of `texture`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexParameter.xhtml
+pub fn texture_parameterf(texture: Texture, param_name: enums::TextureParameter, param: GLfloat) {
+    gl_call!(texture_parameterf, gl::TextureParameterf(texture.0, param_name as GLenum, param));
+}
+
+/// Generates the full mipmap chain of `texture` from its base level
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenerateMipmap.xhtml
+pub fn generate_texture_mipmap(texture: Texture) {
+    gl_call!(generate_texture_mipmap, gl::GenerateTextureMipmap(texture.0));
+}
+
+/// Enables an attribute of `vao`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glEnableVertexAttribArray.xhtml
+pub fn enable_vertex_array_attrib(vao: VAO, index: GLuint) {
+    gl_call!(enable_vertex_array_attrib, gl::EnableVertexArrayAttrib(vao.0, index));
+}
+
+/// Describes the layout of an attribute of `vao`, see `vertex_attrib_format`
+///
+/// # Examples
+/// ```rust,no_run
+/// let vertices = rgl::create_buffer();
+/// let indices = rgl::create_buffer();
+/// //...upload the mesh
+///
+/// let vao = rgl::create_vertex_array();
+/// rgl::enable_vertex_array_attrib(vao, 0);
+/// rgl::vertex_array_attrib_format(vao, 0, 3, rgl::Type::Float, false, 0);
+/// rgl::vertex_array_attrib_binding(vao, 0, 0);
+/// rgl::vertex_array_vertex_buffer(vao, 0, vertices, 0, 12);
+/// rgl::vertex_array_element_buffer(vao, indices);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribFormat.xhtml
+pub fn vertex_array_attrib_format(
+    vao: VAO,
+    attrib_index: GLuint,
+    size: GLint,
+    type_: enums::Type,
+    normalised: bool,
+    relative_offset: GLuint,
+) {
+    gl_call!(vertex_array_attrib_format, gl::VertexArrayAttribFormat(
+        vao.0,
+        attrib_index,
+        size,
+        type_ as GLenum,
+        normalised as GLboolean,
+        relative_offset
+    ));
+}
+
+/// Like `vertex_array_attrib_format`, for attributes that reach the shader as integers
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribFormat.xhtml
+pub fn vertex_array_attrib_i_format(vao: VAO, attrib_index: GLuint, size: GLint, type_: enums::IntType, relative_offset: GLuint) {
+    gl_call!(vertex_array_attrib_i_format, gl::VertexArrayAttribIFormat(vao.0, attrib_index, size, type_ as GLenum, relative_offset));
+}
+
+/// Binds a buffer to a vertex buffer binding point of `vao`, see `bind_vertex_buffer`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindVertexBuffer.xhtml
+pub fn vertex_array_vertex_buffer(vao: VAO, binding_index: GLuint, buffer: Buffer, offset: usize, stride: GLsizei) {
+    gl_call!(vertex_array_vertex_buffer, gl::VertexArrayVertexBuffer(vao.0, binding_index, buffer.0, offset as GLintptr, stride));
+}
+
+/// Makes an attribute of `vao` read from a vertex buffer binding point
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexAttribBinding.xhtml
+pub fn vertex_array_attrib_binding(vao: VAO, attrib_index: GLuint, binding_index: GLuint) {
+    gl_call!(vertex_array_attrib_binding, gl::VertexArrayAttribBinding(vao.0, attrib_index, binding_index));
+}
+
+/// Sets the instance divisor of a vertex buffer binding point of `vao`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexBindingDivisor.xhtml
+pub fn vertex_array_binding_divisor(vao: VAO, binding_index: GLuint, divisor: GLuint) {
+    gl_call!(vertex_array_binding_divisor, gl::VertexArrayBindingDivisor(vao.0, binding_index, divisor));
+}
+
+/// Sets the element array buffer of `vao`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glVertexArrayElementBuffer.xhtml
+pub fn vertex_array_element_buffer(vao: VAO, buffer: Buffer) {
+    gl_call!(vertex_array_element_buffer, gl::VertexArrayElementBuffer(vao.0, buffer.0));
+}
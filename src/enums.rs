@@ -1,90 +1,2142 @@
 use gl;
 
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum Target {
-    ArrayBuffer = gl::ARRAY_BUFFER,
-    ElementArrayBuffer = gl::ELEMENT_ARRAY_BUFFER,
+gl_enum! {
+    /// Buffer binding targets
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::Target;
+    ///
+    /// assert_eq!(Target::ArrayBuffer as u32, gl::ARRAY_BUFFER);
+    /// assert_eq!(Target::AtomicCounterBuffer as u32, gl::ATOMIC_COUNTER_BUFFER);
+    /// assert_eq!(Target::CopyReadBuffer as u32, gl::COPY_READ_BUFFER);
+    /// assert_eq!(Target::CopyWriteBuffer as u32, gl::COPY_WRITE_BUFFER);
+    /// assert_eq!(Target::DispatchIndirectBuffer as u32, gl::DISPATCH_INDIRECT_BUFFER);
+    /// assert_eq!(Target::DrawIndirectBuffer as u32, gl::DRAW_INDIRECT_BUFFER);
+    /// assert_eq!(Target::ElementArrayBuffer as u32, gl::ELEMENT_ARRAY_BUFFER);
+    /// assert_eq!(Target::PixelPackBuffer as u32, gl::PIXEL_PACK_BUFFER);
+    /// assert_eq!(Target::PixelUnpackBuffer as u32, gl::PIXEL_UNPACK_BUFFER);
+    /// assert_eq!(Target::QueryBuffer as u32, gl::QUERY_BUFFER);
+    /// assert_eq!(Target::ShaderStorageBuffer as u32, gl::SHADER_STORAGE_BUFFER);
+    /// assert_eq!(Target::TextureBuffer as u32, gl::TEXTURE_BUFFER);
+    /// assert_eq!(Target::TransformFeedbackBuffer as u32, gl::TRANSFORM_FEEDBACK_BUFFER);
+    /// assert_eq!(Target::UniformBuffer as u32, gl::UNIFORM_BUFFER);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum Target {
+        ArrayBuffer = gl::ARRAY_BUFFER,
+        AtomicCounterBuffer = gl::ATOMIC_COUNTER_BUFFER,
+        /// Conventional source binding for `copy_buffer_sub_data`, it has no other effect on GL state
+        CopyReadBuffer = gl::COPY_READ_BUFFER,
+        /// Conventional destination binding for `copy_buffer_sub_data`, it has no other effect on GL state
+        CopyWriteBuffer = gl::COPY_WRITE_BUFFER,
+        DispatchIndirectBuffer = gl::DISPATCH_INDIRECT_BUFFER,
+        DrawIndirectBuffer = gl::DRAW_INDIRECT_BUFFER,
+        ElementArrayBuffer = gl::ELEMENT_ARRAY_BUFFER,
+        PixelPackBuffer = gl::PIXEL_PACK_BUFFER,
+        PixelUnpackBuffer = gl::PIXEL_UNPACK_BUFFER,
+        QueryBuffer = gl::QUERY_BUFFER,
+        ShaderStorageBuffer = gl::SHADER_STORAGE_BUFFER,
+        TextureBuffer = gl::TEXTURE_BUFFER,
+        TransformFeedbackBuffer = gl::TRANSFORM_FEEDBACK_BUFFER,
+        UniformBuffer = gl::UNIFORM_BUFFER,
+    }
 }
 
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum Usage {
-    StaticDraw = gl::STATIC_DRAW,
+gl_enum! {
+    /// Buffer targets with indexed binding points, the subset of `Target` accepted by `bind_buffer_base`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::IndexedTarget;
+    ///
+    /// assert_eq!(IndexedTarget::AtomicCounterBuffer as u32, gl::ATOMIC_COUNTER_BUFFER);
+    /// assert_eq!(IndexedTarget::ShaderStorageBuffer as u32, gl::SHADER_STORAGE_BUFFER);
+    /// assert_eq!(IndexedTarget::TransformFeedbackBuffer as u32, gl::TRANSFORM_FEEDBACK_BUFFER);
+    /// assert_eq!(IndexedTarget::UniformBuffer as u32, gl::UNIFORM_BUFFER);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum IndexedTarget {
+        AtomicCounterBuffer = gl::ATOMIC_COUNTER_BUFFER,
+        ShaderStorageBuffer = gl::SHADER_STORAGE_BUFFER,
+        TransformFeedbackBuffer = gl::TRANSFORM_FEEDBACK_BUFFER,
+        UniformBuffer = gl::UNIFORM_BUFFER,
+    }
 }
 
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum Type {
-    Float = gl::FLOAT,
+gl_enum! {
+    /// Buffer data store usage hints
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::Usage;
+    ///
+    /// assert_eq!(Usage::StreamDraw as u32, gl::STREAM_DRAW);
+    /// assert_eq!(Usage::StreamRead as u32, gl::STREAM_READ);
+    /// assert_eq!(Usage::StreamCopy as u32, gl::STREAM_COPY);
+    /// assert_eq!(Usage::StaticDraw as u32, gl::STATIC_DRAW);
+    /// assert_eq!(Usage::StaticRead as u32, gl::STATIC_READ);
+    /// assert_eq!(Usage::StaticCopy as u32, gl::STATIC_COPY);
+    /// assert_eq!(Usage::DynamicDraw as u32, gl::DYNAMIC_DRAW);
+    /// assert_eq!(Usage::DynamicRead as u32, gl::DYNAMIC_READ);
+    /// assert_eq!(Usage::DynamicCopy as u32, gl::DYNAMIC_COPY);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum Usage {
+        StreamDraw = gl::STREAM_DRAW,
+        StreamRead = gl::STREAM_READ,
+        StreamCopy = gl::STREAM_COPY,
+        StaticDraw = gl::STATIC_DRAW,
+        StaticRead = gl::STATIC_READ,
+        StaticCopy = gl::STATIC_COPY,
+        DynamicDraw = gl::DYNAMIC_DRAW,
+        DynamicRead = gl::DYNAMIC_READ,
+        DynamicCopy = gl::DYNAMIC_COPY,
+    }
+}
+
+#[deprecated(since = "0.3.0", note = "renamed to Target")]
+pub type GLTarget = Target;
+#[deprecated(since = "0.3.0", note = "renamed to Usage")]
+pub type GLUsage = Usage;
+
+gl_bitfield! {
+    /// Access to a mapped buffer range, see `map_buffer_range`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::MapAccess;
+    ///
+    /// let access = MapAccess::WRITE | MapAccess::INVALIDATE_RANGE;
+    /// assert!(access.contains(MapAccess::WRITE));
+    /// assert!(!access.contains(MapAccess::READ));
+    /// assert_eq!(access.bits(), gl::MAP_WRITE_BIT | gl::MAP_INVALIDATE_RANGE_BIT);
+    /// assert_eq!(MapAccess::PERSISTENT.bits(), gl::MAP_PERSISTENT_BIT);
+    /// ```
+    pub struct MapAccess {
+        const READ = gl::MAP_READ_BIT;
+        const WRITE = gl::MAP_WRITE_BIT;
+        /// The previous contents of the range may be discarded
+        const INVALIDATE_RANGE = gl::MAP_INVALIDATE_RANGE_BIT;
+        /// The previous contents of the whole buffer may be discarded
+        const INVALIDATE_BUFFER = gl::MAP_INVALIDATE_BUFFER_BIT;
+        /// Modified ranges must be flushed explicitly before unmapping
+        const FLUSH_EXPLICIT = gl::MAP_FLUSH_EXPLICIT_BIT;
+        /// GL does not wait for pending operations on the buffer before mapping
+        const UNSYNCHRONIZED = gl::MAP_UNSYNCHRONIZED_BIT;
+        /// The buffer may stay mapped while it is used by GL
+        const PERSISTENT = gl::MAP_PERSISTENT_BIT;
+        /// Writes become visible to GL without an explicit barrier
+        const COHERENT = gl::MAP_COHERENT_BIT;
+    }
+}
+
+gl_bitfield! {
+    /// How an immutable data store created with `buffer_storage` may be used
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::StorageFlags;
+    ///
+    /// let flags = StorageFlags::MAP_WRITE | StorageFlags::MAP_PERSISTENT | StorageFlags::MAP_COHERENT;
+    /// assert_eq!(flags.bits(), gl::MAP_WRITE_BIT | gl::MAP_PERSISTENT_BIT | gl::MAP_COHERENT_BIT);
+    /// assert_eq!(StorageFlags::DYNAMIC_STORAGE.bits(), gl::DYNAMIC_STORAGE_BIT);
+    /// assert_eq!(StorageFlags::CLIENT_STORAGE.bits(), gl::CLIENT_STORAGE_BIT);
+    /// ```
+    pub struct StorageFlags {
+        const MAP_READ = gl::MAP_READ_BIT;
+        const MAP_WRITE = gl::MAP_WRITE_BIT;
+        const MAP_PERSISTENT = gl::MAP_PERSISTENT_BIT;
+        const MAP_COHERENT = gl::MAP_COHERENT_BIT;
+        /// The contents may be updated with `buffer_sub_data`
+        const DYNAMIC_STORAGE = gl::DYNAMIC_STORAGE_BIT;
+        /// Hints that the data store should live in client memory
+        const CLIENT_STORAGE = gl::CLIENT_STORAGE_BIT;
+    }
+}
+
+gl_enum! {
+    /// Component types of vertex attribute and index data
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::Type;
+    ///
+    /// assert_eq!(Type::Byte as u32, gl::BYTE);
+    /// assert_eq!(Type::UnsignedByte as u32, gl::UNSIGNED_BYTE);
+    /// assert_eq!(Type::Short as u32, gl::SHORT);
+    /// assert_eq!(Type::UnsignedShort as u32, gl::UNSIGNED_SHORT);
+    /// assert_eq!(Type::Int as u32, gl::INT);
+    /// assert_eq!(Type::UnsignedInt as u32, gl::UNSIGNED_INT);
+    /// assert_eq!(Type::HalfFloat as u32, gl::HALF_FLOAT);
+    /// assert_eq!(Type::Float as u32, gl::FLOAT);
+    /// assert_eq!(Type::Double as u32, gl::DOUBLE);
+    /// assert_eq!(Type::Fixed as u32, gl::FIXED);
+    /// assert_eq!(Type::Int2101010Rev as u32, gl::INT_2_10_10_10_REV);
+    /// assert_eq!(Type::UnsignedInt2101010Rev as u32, gl::UNSIGNED_INT_2_10_10_10_REV);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Type {
+        Byte = gl::BYTE,
+        UnsignedByte = gl::UNSIGNED_BYTE,
+        Short = gl::SHORT,
+        UnsignedShort = gl::UNSIGNED_SHORT,
+        Int = gl::INT,
+        UnsignedInt = gl::UNSIGNED_INT,
+        HalfFloat = gl::HALF_FLOAT,
+        Float = gl::FLOAT,
+        Double = gl::DOUBLE,
+        Fixed = gl::FIXED,
+        Int2101010Rev = gl::INT_2_10_10_10_REV,
+        UnsignedInt2101010Rev = gl::UNSIGNED_INT_2_10_10_10_REV,
+    }
+}
+
+gl_enum! {
+    /// Types of the values in an element array buffer, the index subset of `Type`
+    #[derive(Clone, Copy, Debug)]
+    pub enum IndexType {
+        UnsignedByte = gl::UNSIGNED_BYTE,
+        UnsignedShort = gl::UNSIGNED_SHORT,
+        UnsignedInt = gl::UNSIGNED_INT,
+    }
+}
+
+impl From<IndexType> for Type {
+    fn from(index_type: IndexType) -> Type {
+        match index_type {
+            IndexType::UnsignedByte => Type::UnsignedByte,
+            IndexType::UnsignedShort => Type::UnsignedShort,
+            IndexType::UnsignedInt => Type::UnsignedInt,
+        }
+    }
+}
+
+gl_enum! {
+    /// Integer component types, the subset of `Type` accepted by `vertex_attrib_i_pointer`
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum IntType {
+        Byte = gl::BYTE,
+        UnsignedByte = gl::UNSIGNED_BYTE,
+        Short = gl::SHORT,
+        UnsignedShort = gl::UNSIGNED_SHORT,
+        Int = gl::INT,
+        UnsignedInt = gl::UNSIGNED_INT,
+    }
+}
 
-    UByte = gl::UNSIGNED_BYTE,
-    UShort = gl::UNSIGNED_SHORT,
-    UInt = gl::UNSIGNED_INT,
+impl From<IntType> for Type {
+    fn from(int_type: IntType) -> Type {
+        match int_type {
+            IntType::Byte => Type::Byte,
+            IntType::UnsignedByte => Type::UnsignedByte,
+            IntType::Short => Type::Short,
+            IntType::UnsignedShort => Type::UnsignedShort,
+            IntType::Int => Type::Int,
+            IntType::UnsignedInt => Type::UnsignedInt,
+        }
+    }
 }
 
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum ClearMask {
-    ColorBufferBit = gl::COLOR_BUFFER_BIT,
-    DepthBufferBit = gl::DEPTH_BUFFER_BIT,
-    StencilBufferBit = gl::STENCIL_BUFFER_BIT,
+gl_bitfield! {
+    /// Buffers to clear, combine them with `|`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::ClearMask;
+    ///
+    /// const ALL: ClearMask = ClearMask::COLOR.union(ClearMask::DEPTH).union(ClearMask::STENCIL);
+    ///
+    /// let mask = ClearMask::COLOR | ClearMask::DEPTH | ClearMask::STENCIL;
+    /// assert_eq!(mask, ALL);
+    /// assert_eq!(mask.bits(), gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT);
+    /// ```
+    pub struct ClearMask {
+        const COLOR = gl::COLOR_BUFFER_BIT;
+        const DEPTH = gl::DEPTH_BUFFER_BIT;
+        const STENCIL = gl::STENCIL_BUFFER_BIT;
+    }
 }
 
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum Primitive {
-    Triangles = gl::TRIANGLES,
+gl_bitfield! {
+    /// Kinds of memory accesses ordered by `memory_barrier`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::BarrierMask;
+    ///
+    /// let barriers = BarrierMask::SHADER_STORAGE | BarrierMask::VERTEX_ATTRIB_ARRAY;
+    /// assert_eq!(barriers.bits(), gl::SHADER_STORAGE_BARRIER_BIT | gl::VERTEX_ATTRIB_ARRAY_BARRIER_BIT);
+    /// assert!(BarrierMask::ALL.contains(barriers));
+    /// assert_eq!(BarrierMask::ALL.bits(), gl::ALL_BARRIER_BITS);
+    /// ```
+    pub struct BarrierMask {
+        const VERTEX_ATTRIB_ARRAY = gl::VERTEX_ATTRIB_ARRAY_BARRIER_BIT;
+        const ELEMENT_ARRAY = gl::ELEMENT_ARRAY_BARRIER_BIT;
+        const UNIFORM = gl::UNIFORM_BARRIER_BIT;
+        const TEXTURE_FETCH = gl::TEXTURE_FETCH_BARRIER_BIT;
+        const SHADER_IMAGE_ACCESS = gl::SHADER_IMAGE_ACCESS_BARRIER_BIT;
+        const COMMAND = gl::COMMAND_BARRIER_BIT;
+        const PIXEL_BUFFER = gl::PIXEL_BUFFER_BARRIER_BIT;
+        const TEXTURE_UPDATE = gl::TEXTURE_UPDATE_BARRIER_BIT;
+        const BUFFER_UPDATE = gl::BUFFER_UPDATE_BARRIER_BIT;
+        const FRAMEBUFFER = gl::FRAMEBUFFER_BARRIER_BIT;
+        const TRANSFORM_FEEDBACK = gl::TRANSFORM_FEEDBACK_BARRIER_BIT;
+        const ATOMIC_COUNTER = gl::ATOMIC_COUNTER_BARRIER_BIT;
+        const SHADER_STORAGE = gl::SHADER_STORAGE_BARRIER_BIT;
+        /// Every kind of access
+        const ALL = gl::ALL_BARRIER_BITS;
+    }
+}
+
+gl_enum! {
+    /// Kinds of primitives to render
+    #[derive(Clone, Copy, Debug)]
+    pub enum Primitive {
+        Points = gl::POINTS,
+        Lines = gl::LINES,
+        LineStrip = gl::LINE_STRIP,
+        LineLoop = gl::LINE_LOOP,
+        Triangles = gl::TRIANGLES,
+        TriangleStrip = gl::TRIANGLE_STRIP,
+        TriangleFan = gl::TRIANGLE_FAN,
+        LinesAdjacency = gl::LINES_ADJACENCY,
+        LineStripAdjacency = gl::LINE_STRIP_ADJACENCY,
+        TrianglesAdjacency = gl::TRIANGLES_ADJACENCY,
+        TriangleStripAdjacency = gl::TRIANGLE_STRIP_ADJACENCY,
+        /// Groups of control points for the tessellation stages, sized with `patch_vertices`
+        Patches = gl::PATCHES,
+    }
 }
 pub type Mode = Primitive;
 
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum ShaderType {
-    Vertex = gl::VERTEX_SHADER,
-    Fragment = gl::FRAGMENT_SHADER,
-}
-
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum ShaderInfoParam {
-    ShaderType = gl::SHADER_TYPE,
-    DeleteStatus = gl::DELETE_STATUS,
-    CompileStatus = gl::COMPILE_STATUS,
-    InfoLogLength = gl::INFO_LOG_LENGTH,
-    ShaderSourceLength = gl::SHADER_SOURCE_LENGTH,
-}
-
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum TexTarget {
-    _2D = gl::TEXTURE_2D,
-    CubeMap = gl::TEXTURE_CUBE_MAP
-}
-
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum TexFormat {
-    RGB = gl::RGB,
-    RGBA = gl::RGBA,
-}
-
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum TexParamName {
-    MinFilter = gl::TEXTURE_MIN_FILTER,
-    MagFilter = gl::TEXTURE_MAG_FILTER,
-    WrapS = gl::TEXTURE_WRAP_S,
-    WrapT = gl::TEXTURE_WRAP_T  
-}
-
-#[repr(u32)]
-#[derive(Clone, Copy)]
-pub enum TexParam {
-    Nearest = gl::NEAREST,
-    Linear = gl::LINEAR,
-    NearestMipmapNearest = gl::NEAREST_MIPMAP_NEAREST,
-    LinearMipmapLinear = gl::LINEAR_MIPMAP_LINEAR,
-    NearestMipmapLinear = gl::NEAREST_MIPMAP_LINEAR,
-    LinearMipmapNearest = gl::LINEAR_MIPMAP_NEAREST
-}
\ No newline at end of file
+gl_enum! {
+    /// Parameters of the patches drawn with `Primitive::Patches`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::PatchParam;
+    ///
+    /// assert_eq!(PatchParam::Vertices as u32, gl::PATCH_VERTICES);
+    /// assert_eq!(PatchParam::DefaultOuterLevel as u32, gl::PATCH_DEFAULT_OUTER_LEVEL);
+    /// assert_eq!(PatchParam::DefaultInnerLevel as u32, gl::PATCH_DEFAULT_INNER_LEVEL);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum PatchParam {
+        /// Control points per patch, set with `patch_vertices`
+        Vertices = gl::PATCH_VERTICES,
+        /// The 4 outer levels used without a tessellation control shader, set with `patch_default_outer_level`
+        DefaultOuterLevel = gl::PATCH_DEFAULT_OUTER_LEVEL,
+        /// The 2 inner levels used without a tessellation control shader, set with `patch_default_inner_level`
+        DefaultInnerLevel = gl::PATCH_DEFAULT_INNER_LEVEL,
+    }
+}
+
+gl_enum! {
+    /// Strings describing the context, see `get_string`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::StringName;
+    ///
+    /// assert_eq!(StringName::Vendor as u32, gl::VENDOR);
+    /// assert_eq!(StringName::Renderer as u32, gl::RENDERER);
+    /// assert_eq!(StringName::Version as u32, gl::VERSION);
+    /// assert_eq!(StringName::ShadingLanguageVersion as u32, gl::SHADING_LANGUAGE_VERSION);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum StringName {
+        Vendor = gl::VENDOR,
+        Renderer = gl::RENDERER,
+        Version = gl::VERSION,
+        ShadingLanguageVersion = gl::SHADING_LANGUAGE_VERSION,
+    }
+}
+
+gl_enum! {
+    /// State and implementation limits read with `get_integer`, `get_float` and `get_boolean`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::Parameter;
+    ///
+    /// assert_eq!(Parameter::MaxTextureSize as u32, gl::MAX_TEXTURE_SIZE);
+    /// assert_eq!(Parameter::MaxTextureImageUnits as u32, gl::MAX_TEXTURE_IMAGE_UNITS);
+    /// assert_eq!(Parameter::MaxCombinedTextureImageUnits as u32, gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS);
+    /// assert_eq!(Parameter::MaxVertexAttribs as u32, gl::MAX_VERTEX_ATTRIBS);
+    /// assert_eq!(Parameter::MaxUniformBlockSize as u32, gl::MAX_UNIFORM_BLOCK_SIZE);
+    /// assert_eq!(Parameter::MaxSamples as u32, gl::MAX_SAMPLES);
+    /// assert_eq!(Parameter::MaxColorAttachments as u32, gl::MAX_COLOR_ATTACHMENTS);
+    /// assert_eq!(Parameter::UniformBufferOffsetAlignment as u32, gl::UNIFORM_BUFFER_OFFSET_ALIGNMENT);
+    /// assert_eq!(Parameter::Viewport as u32, gl::VIEWPORT);
+    /// assert_eq!(Parameter::CurrentProgram as u32, gl::CURRENT_PROGRAM);
+    /// assert_eq!(Parameter::MaxComputeWorkGroupCount as u32, gl::MAX_COMPUTE_WORK_GROUP_COUNT);
+    /// assert_eq!(Parameter::MaxComputeWorkGroupSize as u32, gl::MAX_COMPUTE_WORK_GROUP_SIZE);
+    /// assert_eq!(Parameter::LineWidth as u32, gl::LINE_WIDTH);
+    /// assert_eq!(Parameter::PointSizeRange as u32, gl::POINT_SIZE_RANGE);
+    /// assert_eq!(Parameter::DepthWritemask as u32, gl::DEPTH_WRITEMASK);
+    /// assert_eq!(Parameter::MaxClipDistances as u32, gl::MAX_CLIP_DISTANCES);
+    /// assert_eq!(Parameter::QuadsFollowProvokingVertexConvention as u32, gl::QUADS_FOLLOW_PROVOKING_VERTEX_CONVENTION);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum Parameter {
+        MaxTextureSize = gl::MAX_TEXTURE_SIZE,
+        Max3dTextureSize = gl::MAX_3D_TEXTURE_SIZE,
+        MaxCubeMapTextureSize = gl::MAX_CUBE_MAP_TEXTURE_SIZE,
+        MaxArrayTextureLayers = gl::MAX_ARRAY_TEXTURE_LAYERS,
+        MaxRenderbufferSize = gl::MAX_RENDERBUFFER_SIZE,
+        MaxTextureImageUnits = gl::MAX_TEXTURE_IMAGE_UNITS,
+        MaxCombinedTextureImageUnits = gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS,
+        MaxVertexAttribs = gl::MAX_VERTEX_ATTRIBS,
+        MaxUniformBlockSize = gl::MAX_UNIFORM_BLOCK_SIZE,
+        MaxUniformBufferBindings = gl::MAX_UNIFORM_BUFFER_BINDINGS,
+        MaxShaderStorageBlockSize = gl::MAX_SHADER_STORAGE_BLOCK_SIZE,
+        MaxShaderStorageBufferBindings = gl::MAX_SHADER_STORAGE_BUFFER_BINDINGS,
+        MaxSamples = gl::MAX_SAMPLES,
+        MaxColorAttachments = gl::MAX_COLOR_ATTACHMENTS,
+        MaxDrawBuffers = gl::MAX_DRAW_BUFFERS,
+        UniformBufferOffsetAlignment = gl::UNIFORM_BUFFER_OFFSET_ALIGNMENT,
+        /// 2 values, the largest viewport width and height
+        MaxViewportDims = gl::MAX_VIEWPORT_DIMS,
+        /// The number of viewports for `viewport_indexed` and `viewport_array`, at least 16
+        MaxViewports = gl::MAX_VIEWPORTS,
+        /// 3 values, read each with `get_integer_indexed`
+        MaxComputeWorkGroupCount = gl::MAX_COMPUTE_WORK_GROUP_COUNT,
+        /// 3 values, read each with `get_integer_indexed`
+        MaxComputeWorkGroupSize = gl::MAX_COMPUTE_WORK_GROUP_SIZE,
+        MaxComputeWorkGroupInvocations = gl::MAX_COMPUTE_WORK_GROUP_INVOCATIONS,
+        MajorVersion = gl::MAJOR_VERSION,
+        MinorVersion = gl::MINOR_VERSION,
+        /// 4 values, x, y, width and height
+        Viewport = gl::VIEWPORT,
+        /// 4 values, x, y, width and height
+        ScissorBox = gl::SCISSOR_BOX,
+        CurrentProgram = gl::CURRENT_PROGRAM,
+        LineWidth = gl::LINE_WIDTH,
+        PointSize = gl::POINT_SIZE,
+        /// 2 values, the smallest and largest point size, read with `get_float2`
+        PointSizeRange = gl::POINT_SIZE_RANGE,
+        MaxTextureLodBias = gl::MAX_TEXTURE_LOD_BIAS,
+        DepthWritemask = gl::DEPTH_WRITEMASK,
+        /// The number of clip distances for `enable_clip_distance`, at least 8
+        MaxClipDistances = gl::MAX_CLIP_DISTANCES,
+        /// Whether quads follow `provoking_vertex`, read with `get_boolean`
+        QuadsFollowProvokingVertexConvention = gl::QUADS_FOLLOW_PROVOKING_VERTEX_CONVENTION,
+    }
+}
+
+gl_enum! {
+    /// Where the `gl_PointCoord` origin of point sprites lies, see `point_sprite_coord_origin`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::SpriteCoordOrigin;
+    ///
+    /// assert_eq!(SpriteCoordOrigin::LowerLeft as u32, gl::LOWER_LEFT);
+    /// assert_eq!(SpriteCoordOrigin::UpperLeft as u32, gl::UPPER_LEFT);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum SpriteCoordOrigin {
+        LowerLeft = gl::LOWER_LEFT,
+        /// The default
+        UpperLeft = gl::UPPER_LEFT,
+    }
+}
+
+gl_enum! {
+    /// Server-side capabilities toggled with `enable` and `disable`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::Capability;
+    ///
+    /// assert_eq!(Capability::Blend as u32, gl::BLEND);
+    /// assert_eq!(Capability::CullFace as u32, gl::CULL_FACE);
+    /// assert_eq!(Capability::DepthTest as u32, gl::DEPTH_TEST);
+    /// assert_eq!(Capability::Dither as u32, gl::DITHER);
+    /// assert_eq!(Capability::PolygonOffsetFill as u32, gl::POLYGON_OFFSET_FILL);
+    /// assert_eq!(Capability::SampleAlphaToCoverage as u32, gl::SAMPLE_ALPHA_TO_COVERAGE);
+    /// assert_eq!(Capability::SampleCoverage as u32, gl::SAMPLE_COVERAGE);
+    /// assert_eq!(Capability::ScissorTest as u32, gl::SCISSOR_TEST);
+    /// assert_eq!(Capability::StencilTest as u32, gl::STENCIL_TEST);
+    /// assert_eq!(Capability::Multisample as u32, gl::MULTISAMPLE);
+    /// assert_eq!(Capability::FramebufferSrgb as u32, gl::FRAMEBUFFER_SRGB);
+    /// assert_eq!(Capability::ProgramPointSize as u32, gl::PROGRAM_POINT_SIZE);
+    /// assert_eq!(Capability::DepthClamp as u32, gl::DEPTH_CLAMP);
+    /// assert_eq!(Capability::LineSmooth as u32, gl::LINE_SMOOTH);
+    /// assert_eq!(Capability::PrimitiveRestart as u32, gl::PRIMITIVE_RESTART);
+    /// assert_eq!(Capability::PrimitiveRestartFixedIndex as u32, gl::PRIMITIVE_RESTART_FIXED_INDEX);
+    /// assert_eq!(Capability::RasterizerDiscard as u32, gl::RASTERIZER_DISCARD);
+    /// assert_eq!(Capability::TextureCubeMapSeamless as u32, gl::TEXTURE_CUBE_MAP_SEAMLESS);
+    /// assert_eq!(Capability::ColorLogicOp as u32, gl::COLOR_LOGIC_OP);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum Capability {
+        Blend = gl::BLEND,
+        CullFace = gl::CULL_FACE,
+        DepthTest = gl::DEPTH_TEST,
+        Dither = gl::DITHER,
+        PolygonOffsetFill = gl::POLYGON_OFFSET_FILL,
+        SampleAlphaToCoverage = gl::SAMPLE_ALPHA_TO_COVERAGE,
+        SampleCoverage = gl::SAMPLE_COVERAGE,
+        ScissorTest = gl::SCISSOR_TEST,
+        StencilTest = gl::STENCIL_TEST,
+        Multisample = gl::MULTISAMPLE,
+        FramebufferSrgb = gl::FRAMEBUFFER_SRGB,
+        /// Points are sized by `gl_PointSize` in the shader instead of `point_size`
+        ProgramPointSize = gl::PROGRAM_POINT_SIZE,
+        DepthClamp = gl::DEPTH_CLAMP,
+        LineSmooth = gl::LINE_SMOOTH,
+        /// An index equal to the one set with `primitive_restart_index` starts a new primitive
+        PrimitiveRestart = gl::PRIMITIVE_RESTART,
+        /// The maximum value of the index type starts a new primitive, the portable choice on GL 4.3 and ES 3
+        PrimitiveRestartFixedIndex = gl::PRIMITIVE_RESTART_FIXED_INDEX,
+        DebugOutput = gl::DEBUG_OUTPUT,
+        DebugOutputSynchronous = gl::DEBUG_OUTPUT_SYNCHRONOUS,
+        /// Primitives are discarded before rasterization, for example while only capturing transform feedback
+        RasterizerDiscard = gl::RASTERIZER_DISCARD,
+        /// Cube maps are filtered across the edges of their faces
+        TextureCubeMapSeamless = gl::TEXTURE_CUBE_MAP_SEAMLESS,
+        /// Colours are combined with the `logic_op` instead of blended, desktop GL only
+        ColorLogicOp = gl::COLOR_LOGIC_OP,
+    }
+}
+
+gl_enum! {
+    /// Factors the source and destination colours are multiplied by when blending
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::BlendFactor;
+    ///
+    /// assert_eq!(BlendFactor::Zero as u32, gl::ZERO);
+    /// assert_eq!(BlendFactor::One as u32, gl::ONE);
+    /// assert_eq!(BlendFactor::SrcColor as u32, gl::SRC_COLOR);
+    /// assert_eq!(BlendFactor::OneMinusSrcColor as u32, gl::ONE_MINUS_SRC_COLOR);
+    /// assert_eq!(BlendFactor::DstColor as u32, gl::DST_COLOR);
+    /// assert_eq!(BlendFactor::OneMinusDstColor as u32, gl::ONE_MINUS_DST_COLOR);
+    /// assert_eq!(BlendFactor::SrcAlpha as u32, gl::SRC_ALPHA);
+    /// assert_eq!(BlendFactor::OneMinusSrcAlpha as u32, gl::ONE_MINUS_SRC_ALPHA);
+    /// assert_eq!(BlendFactor::DstAlpha as u32, gl::DST_ALPHA);
+    /// assert_eq!(BlendFactor::OneMinusDstAlpha as u32, gl::ONE_MINUS_DST_ALPHA);
+    /// assert_eq!(BlendFactor::ConstantColor as u32, gl::CONSTANT_COLOR);
+    /// assert_eq!(BlendFactor::OneMinusConstantColor as u32, gl::ONE_MINUS_CONSTANT_COLOR);
+    /// assert_eq!(BlendFactor::ConstantAlpha as u32, gl::CONSTANT_ALPHA);
+    /// assert_eq!(BlendFactor::OneMinusConstantAlpha as u32, gl::ONE_MINUS_CONSTANT_ALPHA);
+    /// assert_eq!(BlendFactor::SrcAlphaSaturate as u32, gl::SRC_ALPHA_SATURATE);
+    /// assert_eq!(BlendFactor::Src1Color as u32, gl::SRC1_COLOR);
+    /// assert_eq!(BlendFactor::OneMinusSrc1Color as u32, gl::ONE_MINUS_SRC1_COLOR);
+    /// assert_eq!(BlendFactor::Src1Alpha as u32, gl::SRC1_ALPHA);
+    /// assert_eq!(BlendFactor::OneMinusSrc1Alpha as u32, gl::ONE_MINUS_SRC1_ALPHA);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum BlendFactor {
+        Zero = gl::ZERO,
+        One = gl::ONE,
+        SrcColor = gl::SRC_COLOR,
+        OneMinusSrcColor = gl::ONE_MINUS_SRC_COLOR,
+        DstColor = gl::DST_COLOR,
+        OneMinusDstColor = gl::ONE_MINUS_DST_COLOR,
+        SrcAlpha = gl::SRC_ALPHA,
+        OneMinusSrcAlpha = gl::ONE_MINUS_SRC_ALPHA,
+        DstAlpha = gl::DST_ALPHA,
+        OneMinusDstAlpha = gl::ONE_MINUS_DST_ALPHA,
+        ConstantColor = gl::CONSTANT_COLOR,
+        OneMinusConstantColor = gl::ONE_MINUS_CONSTANT_COLOR,
+        ConstantAlpha = gl::CONSTANT_ALPHA,
+        OneMinusConstantAlpha = gl::ONE_MINUS_CONSTANT_ALPHA,
+        SrcAlphaSaturate = gl::SRC_ALPHA_SATURATE,
+        Src1Color = gl::SRC1_COLOR,
+        OneMinusSrc1Color = gl::ONE_MINUS_SRC1_COLOR,
+        Src1Alpha = gl::SRC1_ALPHA,
+        OneMinusSrc1Alpha = gl::ONE_MINUS_SRC1_ALPHA,
+    }
+}
+
+gl_enum! {
+    /// How the weighted source and destination colours are combined when blending
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::BlendEquation;
+    ///
+    /// assert_eq!(BlendEquation::Add as u32, gl::FUNC_ADD);
+    /// assert_eq!(BlendEquation::Subtract as u32, gl::FUNC_SUBTRACT);
+    /// assert_eq!(BlendEquation::ReverseSubtract as u32, gl::FUNC_REVERSE_SUBTRACT);
+    /// assert_eq!(BlendEquation::Min as u32, gl::MIN);
+    /// assert_eq!(BlendEquation::Max as u32, gl::MAX);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum BlendEquation {
+        Add = gl::FUNC_ADD,
+        Subtract = gl::FUNC_SUBTRACT,
+        ReverseSubtract = gl::FUNC_REVERSE_SUBTRACT,
+        Min = gl::MIN,
+        Max = gl::MAX,
+    }
+}
+
+gl_enum! {
+    /// Bitwise operations between the source (s) and destination (d) colour, set with `logic_op`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::LogicOp;
+    ///
+    /// assert_eq!(LogicOp::Clear as u32, gl::CLEAR);
+    /// assert_eq!(LogicOp::Set as u32, gl::SET);
+    /// assert_eq!(LogicOp::Copy as u32, gl::COPY);
+    /// assert_eq!(LogicOp::CopyInverted as u32, gl::COPY_INVERTED);
+    /// assert_eq!(LogicOp::Noop as u32, gl::NOOP);
+    /// assert_eq!(LogicOp::Invert as u32, gl::INVERT);
+    /// assert_eq!(LogicOp::And as u32, gl::AND);
+    /// assert_eq!(LogicOp::Nand as u32, gl::NAND);
+    /// assert_eq!(LogicOp::Or as u32, gl::OR);
+    /// assert_eq!(LogicOp::Nor as u32, gl::NOR);
+    /// assert_eq!(LogicOp::Xor as u32, gl::XOR);
+    /// assert_eq!(LogicOp::Equiv as u32, gl::EQUIV);
+    /// assert_eq!(LogicOp::AndReverse as u32, gl::AND_REVERSE);
+    /// assert_eq!(LogicOp::AndInverted as u32, gl::AND_INVERTED);
+    /// assert_eq!(LogicOp::OrReverse as u32, gl::OR_REVERSE);
+    /// assert_eq!(LogicOp::OrInverted as u32, gl::OR_INVERTED);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum LogicOp {
+        /// 0
+        Clear = gl::CLEAR,
+        /// 1
+        Set = gl::SET,
+        /// s, the default
+        Copy = gl::COPY,
+        /// !s
+        CopyInverted = gl::COPY_INVERTED,
+        /// d
+        Noop = gl::NOOP,
+        /// !d
+        Invert = gl::INVERT,
+        /// s & d
+        And = gl::AND,
+        /// !(s & d)
+        Nand = gl::NAND,
+        /// s | d
+        Or = gl::OR,
+        /// !(s | d)
+        Nor = gl::NOR,
+        /// s ^ d
+        Xor = gl::XOR,
+        /// !(s ^ d)
+        Equiv = gl::EQUIV,
+        /// s & !d
+        AndReverse = gl::AND_REVERSE,
+        /// !s & d
+        AndInverted = gl::AND_INVERTED,
+        /// s | !d
+        OrReverse = gl::OR_REVERSE,
+        /// !s | d
+        OrInverted = gl::OR_INVERTED,
+    }
+}
+
+gl_enum! {
+    /// Comparison functions, shared by the depth test, stencil test and depth texture comparisons
+    #[derive(Clone, Copy, Debug)]
+    pub enum CompareFunc {
+        Never = gl::NEVER,
+        Less = gl::LESS,
+        Equal = gl::EQUAL,
+        Lequal = gl::LEQUAL,
+        Greater = gl::GREATER,
+        Notequal = gl::NOTEQUAL,
+        Gequal = gl::GEQUAL,
+        Always = gl::ALWAYS,
+    }
+}
+
+gl_enum! {
+    /// Actions taken on the stencil buffer depending on the outcome of the stencil and depth tests
+    #[derive(Clone, Copy, Debug)]
+    pub enum StencilOp {
+        Keep = gl::KEEP,
+        Zero = gl::ZERO,
+        Replace = gl::REPLACE,
+        Incr = gl::INCR,
+        IncrWrap = gl::INCR_WRAP,
+        Decr = gl::DECR,
+        DecrWrap = gl::DECR_WRAP,
+        Invert = gl::INVERT,
+    }
+}
+
+gl_enum! {
+    /// Polygon faces that state can apply to
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// assert_eq!(rgl::Face::Front as u32, gl::FRONT);
+    /// assert_eq!(rgl::Face::Back as u32, gl::BACK);
+    /// assert_eq!(rgl::Face::FrontAndBack as u32, gl::FRONT_AND_BACK);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum Face {
+        Front = gl::FRONT,
+        Back = gl::BACK,
+        FrontAndBack = gl::FRONT_AND_BACK,
+    }
+}
+
+gl_enum! {
+    /// Winding order of front-facing polygons
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// assert_eq!(rgl::Winding::Cw as u32, gl::CW);
+    /// assert_eq!(rgl::Winding::Ccw as u32, gl::CCW);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum Winding {
+        Cw = gl::CW,
+        Ccw = gl::CCW,
+    }
+}
+
+gl_enum! {
+    /// How polygons are rasterised
+    #[derive(Clone, Copy, Debug)]
+    pub enum PolygonMode {
+        Point = gl::POINT,
+        Line = gl::LINE,
+        Fill = gl::FILL,
+    }
+}
+
+gl_enum! {
+    /// Behaviours that can be traded between speed and quality with `hint`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::{HintMode, HintTarget};
+    ///
+    /// assert_eq!(HintTarget::FragmentShaderDerivative as u32, gl::FRAGMENT_SHADER_DERIVATIVE_HINT);
+    /// assert_eq!(HintTarget::TextureCompression as u32, gl::TEXTURE_COMPRESSION_HINT);
+    /// assert_eq!(HintMode::DontCare as u32, gl::DONT_CARE);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum HintTarget {
+        /// Sampling quality of antialiased lines
+        LineSmooth = gl::LINE_SMOOTH_HINT,
+        /// Sampling quality of antialiased polygons
+        PolygonSmooth = gl::POLYGON_SMOOTH_HINT,
+        /// Quality of compressing textures uploaded with a generic compressed internal format
+        TextureCompression = gl::TEXTURE_COMPRESSION_HINT,
+        /// Accuracy of `dFdx`, `dFdy` and `fwidth` in fragment shaders
+        FragmentShaderDerivative = gl::FRAGMENT_SHADER_DERIVATIVE_HINT,
+    }
+}
+
+gl_enum! {
+    /// Preference given to `hint`
+    #[derive(Clone, Copy, Debug)]
+    pub enum HintMode {
+        Fastest = gl::FASTEST,
+        Nicest = gl::NICEST,
+        DontCare = gl::DONT_CARE,
+    }
+}
+
+gl_enum! {
+    /// Which vertex of a primitive provides the values of `flat` interpolated outputs
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::ProvokingVertex;
+    ///
+    /// assert_eq!(ProvokingVertex::FirstVertexConvention as u32, gl::FIRST_VERTEX_CONVENTION);
+    /// assert_eq!(ProvokingVertex::LastVertexConvention as u32, gl::LAST_VERTEX_CONVENTION);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum ProvokingVertex {
+        /// The first vertex, as in Direct3D
+        FirstVertexConvention = gl::FIRST_VERTEX_CONVENTION,
+        /// The last vertex, the default
+        LastVertexConvention = gl::LAST_VERTEX_CONVENTION,
+    }
+}
+
+gl_enum! {
+    /// Where a debug message came from
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum DebugSource {
+        Api = gl::DEBUG_SOURCE_API,
+        WindowSystem = gl::DEBUG_SOURCE_WINDOW_SYSTEM,
+        ShaderCompiler = gl::DEBUG_SOURCE_SHADER_COMPILER,
+        ThirdParty = gl::DEBUG_SOURCE_THIRD_PARTY,
+        Application = gl::DEBUG_SOURCE_APPLICATION,
+        Other = gl::DEBUG_SOURCE_OTHER,
+    }
+}
+
+gl_enum! {
+    /// What a debug message is about
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum DebugType {
+        Error = gl::DEBUG_TYPE_ERROR,
+        DeprecatedBehavior = gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR,
+        UndefinedBehavior = gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR,
+        Portability = gl::DEBUG_TYPE_PORTABILITY,
+        Performance = gl::DEBUG_TYPE_PERFORMANCE,
+        Marker = gl::DEBUG_TYPE_MARKER,
+        PushGroup = gl::DEBUG_TYPE_PUSH_GROUP,
+        PopGroup = gl::DEBUG_TYPE_POP_GROUP,
+        Other = gl::DEBUG_TYPE_OTHER,
+    }
+}
+
+gl_enum! {
+    /// How important a debug message is
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum DebugSeverity {
+        High = gl::DEBUG_SEVERITY_HIGH,
+        Medium = gl::DEBUG_SEVERITY_MEDIUM,
+        Low = gl::DEBUG_SEVERITY_LOW,
+        Notification = gl::DEBUG_SEVERITY_NOTIFICATION,
+    }
+}
+
+gl_enum! {
+    /// Kinds of objects that can be given a label with `object_label`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::ObjectType;
+    ///
+    /// assert_eq!(ObjectType::Buffer as u32, gl::BUFFER);
+    /// assert_eq!(ObjectType::Shader as u32, gl::SHADER);
+    /// assert_eq!(ObjectType::Program as u32, gl::PROGRAM);
+    /// assert_eq!(ObjectType::VertexArray as u32, gl::VERTEX_ARRAY);
+    /// assert_eq!(ObjectType::Query as u32, gl::QUERY);
+    /// assert_eq!(ObjectType::ProgramPipeline as u32, gl::PROGRAM_PIPELINE);
+    /// assert_eq!(ObjectType::TransformFeedback as u32, gl::TRANSFORM_FEEDBACK);
+    /// assert_eq!(ObjectType::Sampler as u32, gl::SAMPLER);
+    /// assert_eq!(ObjectType::Texture as u32, gl::TEXTURE);
+    /// assert_eq!(ObjectType::Renderbuffer as u32, gl::RENDERBUFFER);
+    /// assert_eq!(ObjectType::Framebuffer as u32, gl::FRAMEBUFFER);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum ObjectType {
+        Buffer = gl::BUFFER,
+        Shader = gl::SHADER,
+        Program = gl::PROGRAM,
+        VertexArray = gl::VERTEX_ARRAY,
+        Query = gl::QUERY,
+        ProgramPipeline = gl::PROGRAM_PIPELINE,
+        TransformFeedback = gl::TRANSFORM_FEEDBACK,
+        Sampler = gl::SAMPLER,
+        Texture = gl::TEXTURE,
+        Renderbuffer = gl::RENDERBUFFER,
+        Framebuffer = gl::FRAMEBUFFER,
+    }
+}
+
+gl_enum! {
+    /// Kinds of resources a program exposes, used by the program resource queries
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::ProgramInterface;
+    ///
+    /// assert_eq!(ProgramInterface::Uniform as u32, gl::UNIFORM);
+    /// assert_eq!(ProgramInterface::UniformBlock as u32, gl::UNIFORM_BLOCK);
+    /// assert_eq!(ProgramInterface::ProgramInput as u32, gl::PROGRAM_INPUT);
+    /// assert_eq!(ProgramInterface::ProgramOutput as u32, gl::PROGRAM_OUTPUT);
+    /// assert_eq!(ProgramInterface::ShaderStorageBlock as u32, gl::SHADER_STORAGE_BLOCK);
+    /// assert_eq!(ProgramInterface::BufferVariable as u32, gl::BUFFER_VARIABLE);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum ProgramInterface {
+        Uniform = gl::UNIFORM,
+        UniformBlock = gl::UNIFORM_BLOCK,
+        ProgramInput = gl::PROGRAM_INPUT,
+        ProgramOutput = gl::PROGRAM_OUTPUT,
+        ShaderStorageBlock = gl::SHADER_STORAGE_BLOCK,
+        BufferVariable = gl::BUFFER_VARIABLE,
+    }
+}
+
+gl_enum! {
+    /// How captured transform feedback varyings are written to buffers
+    #[derive(Clone, Copy, Debug)]
+    pub enum BufferMode {
+        /// All varyings are written to a single buffer, one vertex after another
+        Interleaved = gl::INTERLEAVED_ATTRIBS,
+        /// Every varying is written to its own buffer binding point
+        Separate = gl::SEPARATE_ATTRIBS,
+    }
+}
+
+gl_enum! {
+    /// What a query object measures between `begin_query` and `end_query`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::QueryTarget;
+    ///
+    /// assert_eq!(QueryTarget::SamplesPassed as u32, gl::SAMPLES_PASSED);
+    /// assert_eq!(QueryTarget::AnySamplesPassed as u32, gl::ANY_SAMPLES_PASSED);
+    /// assert_eq!(QueryTarget::AnySamplesPassedConservative as u32, gl::ANY_SAMPLES_PASSED_CONSERVATIVE);
+    /// assert_eq!(QueryTarget::PrimitivesGenerated as u32, gl::PRIMITIVES_GENERATED);
+    /// assert_eq!(QueryTarget::TransformFeedbackPrimitivesWritten as u32, gl::TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN);
+    /// assert_eq!(QueryTarget::TimeElapsed as u32, gl::TIME_ELAPSED);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum QueryTarget {
+        SamplesPassed = gl::SAMPLES_PASSED,
+        AnySamplesPassed = gl::ANY_SAMPLES_PASSED,
+        /// Like `AnySamplesPassed`, but the implementation may report false positives to answer sooner
+        AnySamplesPassedConservative = gl::ANY_SAMPLES_PASSED_CONSERVATIVE,
+        PrimitivesGenerated = gl::PRIMITIVES_GENERATED,
+        TransformFeedbackPrimitivesWritten = gl::TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN,
+        /// Nanoseconds of GPU time
+        TimeElapsed = gl::TIME_ELAPSED,
+    }
+}
+
+gl_enum! {
+    /// How `begin_conditional_render` uses its query's result
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::ConditionalRenderMode;
+    ///
+    /// assert_eq!(ConditionalRenderMode::QueryWait as u32, gl::QUERY_WAIT);
+    /// assert_eq!(ConditionalRenderMode::QueryNoWait as u32, gl::QUERY_NO_WAIT);
+    /// assert_eq!(ConditionalRenderMode::QueryByRegionWait as u32, gl::QUERY_BY_REGION_WAIT);
+    /// assert_eq!(ConditionalRenderMode::QueryByRegionNoWait as u32, gl::QUERY_BY_REGION_NO_WAIT);
+    /// assert_eq!(ConditionalRenderMode::QueryWaitInverted as u32, gl::QUERY_WAIT_INVERTED);
+    /// assert_eq!(ConditionalRenderMode::QueryNoWaitInverted as u32, gl::QUERY_NO_WAIT_INVERTED);
+    /// assert_eq!(ConditionalRenderMode::QueryByRegionWaitInverted as u32, gl::QUERY_BY_REGION_WAIT_INVERTED);
+    /// assert_eq!(ConditionalRenderMode::QueryByRegionNoWaitInverted as u32, gl::QUERY_BY_REGION_NO_WAIT_INVERTED);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum ConditionalRenderMode {
+        /// The GPU waits for the query's result
+        QueryWait = gl::QUERY_WAIT,
+        /// The commands are drawn anyway if the result is not ready yet
+        QueryNoWait = gl::QUERY_NO_WAIT,
+        /// Like `QueryWait`, results may be used per screen region
+        QueryByRegionWait = gl::QUERY_BY_REGION_WAIT,
+        /// Like `QueryNoWait`, results may be used per screen region
+        QueryByRegionNoWait = gl::QUERY_BY_REGION_NO_WAIT,
+        /// Like `QueryWait`, drawing only if no samples passed. Requires GL 4.5 or
+        /// `ARB_conditional_render_inverted`
+        QueryWaitInverted = gl::QUERY_WAIT_INVERTED,
+        /// Like `QueryNoWait`, drawing only if no samples passed
+        QueryNoWaitInverted = gl::QUERY_NO_WAIT_INVERTED,
+        /// Like `QueryByRegionWait`, drawing only if no samples passed
+        QueryByRegionWaitInverted = gl::QUERY_BY_REGION_WAIT_INVERTED,
+        /// Like `QueryByRegionNoWait`, drawing only if no samples passed
+        QueryByRegionNoWaitInverted = gl::QUERY_BY_REGION_NO_WAIT_INVERTED,
+    }
+}
+
+gl_enum! {
+    /// Result of `client_wait_sync`
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum WaitStatus {
+        /// The fence was signaled before the call
+        AlreadySignaled = gl::ALREADY_SIGNALED,
+        /// The fence was signaled while waiting
+        ConditionSatisfied = gl::CONDITION_SATISFIED,
+        TimeoutExpired = gl::TIMEOUT_EXPIRED,
+        Failed = gl::WAIT_FAILED,
+    }
+}
+
+gl_enum! {
+    /// Framebuffer binding points
+    #[derive(Clone, Copy, Debug)]
+    pub enum FramebufferTarget {
+        Draw = gl::DRAW_FRAMEBUFFER,
+        Read = gl::READ_FRAMEBUFFER,
+        /// Both the draw and read framebuffer
+        Both = gl::FRAMEBUFFER,
+    }
+}
+
+gl_enum! {
+    /// Filter used by `blit_framebuffer` when the source and destination rectangles differ in size
+    #[derive(Clone, Copy, Debug)]
+    pub enum BlitFilter {
+        Nearest = gl::NEAREST,
+        Linear = gl::LINEAR,
+    }
+}
+
+gl_enum! {
+    /// Colour buffers that fragment outputs are written to or pixels are read from, see
+    /// `draw_buffers` and `read_buffer`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::DrawBufferTarget;
+    ///
+    /// assert_eq!(DrawBufferTarget::None as u32, gl::NONE);
+    /// assert_eq!(DrawBufferTarget::ColorAttachment0 as u32, gl::COLOR_ATTACHMENT0);
+    /// assert_eq!(DrawBufferTarget::ColorAttachment7 as u32, gl::COLOR_ATTACHMENT7);
+    /// assert_eq!(DrawBufferTarget::Back as u32, gl::BACK);
+    /// assert_eq!(DrawBufferTarget::Front as u32, gl::FRONT);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum DrawBufferTarget {
+        /// The output is discarded
+        None = gl::NONE,
+        ColorAttachment0 = gl::COLOR_ATTACHMENT0,
+        ColorAttachment1 = gl::COLOR_ATTACHMENT1,
+        ColorAttachment2 = gl::COLOR_ATTACHMENT2,
+        ColorAttachment3 = gl::COLOR_ATTACHMENT3,
+        ColorAttachment4 = gl::COLOR_ATTACHMENT4,
+        ColorAttachment5 = gl::COLOR_ATTACHMENT5,
+        ColorAttachment6 = gl::COLOR_ATTACHMENT6,
+        ColorAttachment7 = gl::COLOR_ATTACHMENT7,
+        /// The back buffer of the default framebuffer
+        Back = gl::BACK,
+        /// The front buffer of the default framebuffer
+        Front = gl::FRONT,
+    }
+}
+pub type ReadBufferTarget = DrawBufferTarget;
+
+gl_enum! {
+    /// Attachment points of a framebuffer object
+    #[derive(Clone, Copy, Debug)]
+    pub enum Attachment {
+        Color0 = gl::COLOR_ATTACHMENT0,
+        Color1 = gl::COLOR_ATTACHMENT1,
+        Color2 = gl::COLOR_ATTACHMENT2,
+        Color3 = gl::COLOR_ATTACHMENT3,
+        Color4 = gl::COLOR_ATTACHMENT4,
+        Color5 = gl::COLOR_ATTACHMENT5,
+        Color6 = gl::COLOR_ATTACHMENT6,
+        Color7 = gl::COLOR_ATTACHMENT7,
+        Depth = gl::DEPTH_ATTACHMENT,
+        Stencil = gl::STENCIL_ATTACHMENT,
+        DepthStencil = gl::DEPTH_STENCIL_ATTACHMENT,
+    }
+}
+
+gl_enum! {
+    /// Buffers of the default framebuffer, which has these instead of the attachments of `Attachment`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::DefaultAttachment;
+    ///
+    /// assert_eq!(DefaultAttachment::FrontLeft as u32, gl::FRONT_LEFT);
+    /// assert_eq!(DefaultAttachment::BackLeft as u32, gl::BACK_LEFT);
+    /// assert_eq!(DefaultAttachment::Depth as u32, gl::DEPTH);
+    /// assert_eq!(DefaultAttachment::Stencil as u32, gl::STENCIL);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum DefaultAttachment {
+        FrontLeft = gl::FRONT_LEFT,
+        /// The back buffer of a double buffered window, which is rendered to
+        BackLeft = gl::BACK_LEFT,
+        Depth = gl::DEPTH,
+        Stencil = gl::STENCIL,
+    }
+}
+
+gl_enum! {
+    /// Properties of a framebuffer attachment, queried with `get_framebuffer_attachment_parameter`
+    /// and `get_default_framebuffer_attachment_parameter`
+    ///
+    /// Only `ObjectType` and `ObjectName` may be queried for an attachment with nothing attached
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::FramebufferAttachmentParameter;
+    ///
+    /// assert_eq!(FramebufferAttachmentParameter::ObjectType as u32, gl::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE);
+    /// assert_eq!(FramebufferAttachmentParameter::ObjectName as u32, gl::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME);
+    /// assert_eq!(FramebufferAttachmentParameter::RedSize as u32, gl::FRAMEBUFFER_ATTACHMENT_RED_SIZE);
+    /// assert_eq!(FramebufferAttachmentParameter::GreenSize as u32, gl::FRAMEBUFFER_ATTACHMENT_GREEN_SIZE);
+    /// assert_eq!(FramebufferAttachmentParameter::BlueSize as u32, gl::FRAMEBUFFER_ATTACHMENT_BLUE_SIZE);
+    /// assert_eq!(FramebufferAttachmentParameter::AlphaSize as u32, gl::FRAMEBUFFER_ATTACHMENT_ALPHA_SIZE);
+    /// assert_eq!(FramebufferAttachmentParameter::DepthSize as u32, gl::FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE);
+    /// assert_eq!(FramebufferAttachmentParameter::StencilSize as u32, gl::FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE);
+    /// assert_eq!(FramebufferAttachmentParameter::ComponentType as u32, gl::FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE);
+    /// assert_eq!(FramebufferAttachmentParameter::ColorEncoding as u32, gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum FramebufferAttachmentParameter {
+        /// `gl::NONE` if nothing is attached, otherwise `gl::TEXTURE`, `gl::RENDERBUFFER` or
+        /// `gl::FRAMEBUFFER_DEFAULT`
+        ObjectType = gl::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE,
+        /// The name of the attached texture or renderbuffer
+        ObjectName = gl::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME,
+        /// Bits of red in the attachment's format, 0 if it has none
+        RedSize = gl::FRAMEBUFFER_ATTACHMENT_RED_SIZE,
+        GreenSize = gl::FRAMEBUFFER_ATTACHMENT_GREEN_SIZE,
+        BlueSize = gl::FRAMEBUFFER_ATTACHMENT_BLUE_SIZE,
+        AlphaSize = gl::FRAMEBUFFER_ATTACHMENT_ALPHA_SIZE,
+        DepthSize = gl::FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE,
+        StencilSize = gl::FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE,
+        /// How the components are stored, `gl::FLOAT`, `gl::INT`, `gl::UNSIGNED_INT`,
+        /// `gl::SIGNED_NORMALIZED` or `gl::UNSIGNED_NORMALIZED`
+        ComponentType = gl::FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE,
+        /// The `ColorEncoding` of the attachment
+        ColorEncoding = gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+    }
+}
+
+gl_enum! {
+    /// How an attachment's colour is encoded, see `FramebufferAttachmentParameter::ColorEncoding`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::ColorEncoding;
+    ///
+    /// assert_eq!(ColorEncoding::Linear as u32, gl::LINEAR);
+    /// assert_eq!(ColorEncoding::Srgb as u32, gl::SRGB);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ColorEncoding {
+        Linear = gl::LINEAR,
+        Srgb = gl::SRGB,
+    }
+}
+
+gl_enum! {
+    #[derive(Clone, Copy, Debug)]
+    pub enum ShaderType {
+        Vertex = gl::VERTEX_SHADER,
+        Fragment = gl::FRAGMENT_SHADER,
+        Geometry = gl::GEOMETRY_SHADER,
+        TessControl = gl::TESS_CONTROL_SHADER,
+        TessEvaluation = gl::TESS_EVALUATION_SHADER,
+        Compute = gl::COMPUTE_SHADER,
+    }
+}
+
+gl_enum! {
+    /// Formats of precompiled shaders for `shader_binary`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use rgl::ShaderBinaryFormat;
+    ///
+    /// //GL_SHADER_BINARY_FORMAT_SPIR_V, which the GL 4.5 bindings do not have
+    /// assert_eq!(ShaderBinaryFormat::SpirV as u32, 0x9551);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ShaderBinaryFormat {
+        /// SPIR-V modules, with GL 4.6 or `ARB_gl_spirv`. The shaders need `specialize_shader` after
+        /// loading before they can be linked
+        SpirV = 0x9551,
+    }
+}
+
+gl_bitfield! {
+    /// Shader stages of a program used by a program pipeline, see `use_program_stages`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::StageMask;
+    ///
+    /// assert_eq!(StageMask::VERTEX.bits(), gl::VERTEX_SHADER_BIT);
+    /// assert_eq!(StageMask::TESS_CONTROL.bits(), gl::TESS_CONTROL_SHADER_BIT);
+    /// assert_eq!(StageMask::TESS_EVALUATION.bits(), gl::TESS_EVALUATION_SHADER_BIT);
+    /// assert_eq!(StageMask::GEOMETRY.bits(), gl::GEOMETRY_SHADER_BIT);
+    /// assert_eq!(StageMask::FRAGMENT.bits(), gl::FRAGMENT_SHADER_BIT);
+    /// assert_eq!(StageMask::COMPUTE.bits(), gl::COMPUTE_SHADER_BIT);
+    /// assert!(StageMask::ALL.contains(StageMask::VERTEX | StageMask::FRAGMENT));
+    /// ```
+    pub struct StageMask {
+        const VERTEX = gl::VERTEX_SHADER_BIT;
+        const TESS_CONTROL = gl::TESS_CONTROL_SHADER_BIT;
+        const TESS_EVALUATION = gl::TESS_EVALUATION_SHADER_BIT;
+        const GEOMETRY = gl::GEOMETRY_SHADER_BIT;
+        const FRAGMENT = gl::FRAGMENT_SHADER_BIT;
+        const COMPUTE = gl::COMPUTE_SHADER_BIT;
+        /// Every stage the program contains
+        const ALL = gl::ALL_SHADER_BITS;
+    }
+}
+
+gl_enum! {
+    #[derive(Clone, Copy, Debug)]
+    pub enum ShaderInfoParam {
+        ShaderType = gl::SHADER_TYPE,
+        DeleteStatus = gl::DELETE_STATUS,
+        CompileStatus = gl::COMPILE_STATUS,
+        InfoLogLength = gl::INFO_LOG_LENGTH,
+        ShaderSourceLength = gl::SHADER_SOURCE_LENGTH,
+    }
+}
+
+gl_enum! {
+    #[derive(Clone, Copy, Debug)]
+    pub enum ProgramInfoParam {
+        DeleteStatus = gl::DELETE_STATUS,
+        LinkStatus = gl::LINK_STATUS,
+        ValidateStatus = gl::VALIDATE_STATUS,
+        InfoLogLength = gl::INFO_LOG_LENGTH,
+        AttachedShaders = gl::ATTACHED_SHADERS,
+        ActiveAttributes = gl::ACTIVE_ATTRIBUTES,
+        ActiveAttributeMaxLength = gl::ACTIVE_ATTRIBUTE_MAX_LENGTH,
+        ActiveUniforms = gl::ACTIVE_UNIFORMS,
+        ActiveUniformMaxLength = gl::ACTIVE_UNIFORM_MAX_LENGTH,
+        ActiveUniformBlocks = gl::ACTIVE_UNIFORM_BLOCKS,
+        ProgramBinaryLength = gl::PROGRAM_BINARY_LENGTH,
+    }
+}
+
+/// GLSL type of an active uniform or attribute, returned by `get_active_uniform` and
+/// `get_active_attrib`
+///
+/// # Examples
+/// ```rust
+/// extern crate gl;
+/// extern crate rgl;
+///
+/// use rgl::UniformType;
+///
+/// assert_eq!(UniformType::from_raw(gl::FLOAT_VEC3), UniformType::FloatVec3);
+/// assert_eq!(UniformType::from_raw(gl::FLOAT_MAT4), UniformType::FloatMat4);
+/// assert_eq!(UniformType::from_raw(gl::SAMPLER_2D), UniformType::Sampler2d);
+/// assert_eq!(UniformType::from_raw(gl::UNSIGNED_INT_ATOMIC_COUNTER), UniformType::UnsignedIntAtomicCounter);
+/// assert_eq!(UniformType::from_raw(gl::INT_IMAGE_BUFFER), UniformType::Unknown(gl::INT_IMAGE_BUFFER));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UniformType {
+    Float,
+    FloatVec2,
+    FloatVec3,
+    FloatVec4,
+    Double,
+    DoubleVec2,
+    DoubleVec3,
+    DoubleVec4,
+    Int,
+    IntVec2,
+    IntVec3,
+    IntVec4,
+    UnsignedInt,
+    UnsignedIntVec2,
+    UnsignedIntVec3,
+    UnsignedIntVec4,
+    Bool,
+    BoolVec2,
+    BoolVec3,
+    BoolVec4,
+    FloatMat2,
+    FloatMat3,
+    FloatMat4,
+    FloatMat2x3,
+    FloatMat2x4,
+    FloatMat3x2,
+    FloatMat3x4,
+    FloatMat4x2,
+    FloatMat4x3,
+    DoubleMat2,
+    DoubleMat3,
+    DoubleMat4,
+    Sampler1d,
+    Sampler2d,
+    Sampler3d,
+    SamplerCube,
+    Sampler2dShadow,
+    Sampler2dArray,
+    Sampler2dArrayShadow,
+    SamplerCubeShadow,
+    SamplerCubeMapArray,
+    Sampler2dMultisample,
+    SamplerBuffer,
+    IntSampler2d,
+    IntSampler3d,
+    IntSamplerCube,
+    IntSampler2dArray,
+    UnsignedIntSampler2d,
+    UnsignedIntSampler3d,
+    UnsignedIntSamplerCube,
+    UnsignedIntSampler2dArray,
+    Image2d,
+    Image3d,
+    ImageCube,
+    Image2dArray,
+    IntImage2d,
+    UnsignedIntImage2d,
+    UnsignedIntAtomicCounter,
+    /// A type this crate does not know about, holding the raw token
+    Unknown(u32),
+}
+
+impl UniformType {
+    /// Converts a raw type token, returning `Unknown` for types this crate does not know about
+    pub fn from_raw(type_: u32) -> UniformType {
+        match type_ {
+            gl::FLOAT => UniformType::Float,
+            gl::FLOAT_VEC2 => UniformType::FloatVec2,
+            gl::FLOAT_VEC3 => UniformType::FloatVec3,
+            gl::FLOAT_VEC4 => UniformType::FloatVec4,
+            gl::DOUBLE => UniformType::Double,
+            gl::DOUBLE_VEC2 => UniformType::DoubleVec2,
+            gl::DOUBLE_VEC3 => UniformType::DoubleVec3,
+            gl::DOUBLE_VEC4 => UniformType::DoubleVec4,
+            gl::INT => UniformType::Int,
+            gl::INT_VEC2 => UniformType::IntVec2,
+            gl::INT_VEC3 => UniformType::IntVec3,
+            gl::INT_VEC4 => UniformType::IntVec4,
+            gl::UNSIGNED_INT => UniformType::UnsignedInt,
+            gl::UNSIGNED_INT_VEC2 => UniformType::UnsignedIntVec2,
+            gl::UNSIGNED_INT_VEC3 => UniformType::UnsignedIntVec3,
+            gl::UNSIGNED_INT_VEC4 => UniformType::UnsignedIntVec4,
+            gl::BOOL => UniformType::Bool,
+            gl::BOOL_VEC2 => UniformType::BoolVec2,
+            gl::BOOL_VEC3 => UniformType::BoolVec3,
+            gl::BOOL_VEC4 => UniformType::BoolVec4,
+            gl::FLOAT_MAT2 => UniformType::FloatMat2,
+            gl::FLOAT_MAT3 => UniformType::FloatMat3,
+            gl::FLOAT_MAT4 => UniformType::FloatMat4,
+            gl::FLOAT_MAT2x3 => UniformType::FloatMat2x3,
+            gl::FLOAT_MAT2x4 => UniformType::FloatMat2x4,
+            gl::FLOAT_MAT3x2 => UniformType::FloatMat3x2,
+            gl::FLOAT_MAT3x4 => UniformType::FloatMat3x4,
+            gl::FLOAT_MAT4x2 => UniformType::FloatMat4x2,
+            gl::FLOAT_MAT4x3 => UniformType::FloatMat4x3,
+            gl::DOUBLE_MAT2 => UniformType::DoubleMat2,
+            gl::DOUBLE_MAT3 => UniformType::DoubleMat3,
+            gl::DOUBLE_MAT4 => UniformType::DoubleMat4,
+            gl::SAMPLER_1D => UniformType::Sampler1d,
+            gl::SAMPLER_2D => UniformType::Sampler2d,
+            gl::SAMPLER_3D => UniformType::Sampler3d,
+            gl::SAMPLER_CUBE => UniformType::SamplerCube,
+            gl::SAMPLER_2D_SHADOW => UniformType::Sampler2dShadow,
+            gl::SAMPLER_2D_ARRAY => UniformType::Sampler2dArray,
+            gl::SAMPLER_2D_ARRAY_SHADOW => UniformType::Sampler2dArrayShadow,
+            gl::SAMPLER_CUBE_SHADOW => UniformType::SamplerCubeShadow,
+            gl::SAMPLER_CUBE_MAP_ARRAY => UniformType::SamplerCubeMapArray,
+            gl::SAMPLER_2D_MULTISAMPLE => UniformType::Sampler2dMultisample,
+            gl::SAMPLER_BUFFER => UniformType::SamplerBuffer,
+            gl::INT_SAMPLER_2D => UniformType::IntSampler2d,
+            gl::INT_SAMPLER_3D => UniformType::IntSampler3d,
+            gl::INT_SAMPLER_CUBE => UniformType::IntSamplerCube,
+            gl::INT_SAMPLER_2D_ARRAY => UniformType::IntSampler2dArray,
+            gl::UNSIGNED_INT_SAMPLER_2D => UniformType::UnsignedIntSampler2d,
+            gl::UNSIGNED_INT_SAMPLER_3D => UniformType::UnsignedIntSampler3d,
+            gl::UNSIGNED_INT_SAMPLER_CUBE => UniformType::UnsignedIntSamplerCube,
+            gl::UNSIGNED_INT_SAMPLER_2D_ARRAY => UniformType::UnsignedIntSampler2dArray,
+            gl::IMAGE_2D => UniformType::Image2d,
+            gl::IMAGE_3D => UniformType::Image3d,
+            gl::IMAGE_CUBE => UniformType::ImageCube,
+            gl::IMAGE_2D_ARRAY => UniformType::Image2dArray,
+            gl::INT_IMAGE_2D => UniformType::IntImage2d,
+            gl::UNSIGNED_INT_IMAGE_2D => UniformType::UnsignedIntImage2d,
+            gl::UNSIGNED_INT_ATOMIC_COUNTER => UniformType::UnsignedIntAtomicCounter,
+            _ => UniformType::Unknown(type_),
+        }
+    }
+}
+
+/// Same as `UniformType::from_raw`, every token converts so `TryFrom` cannot fail
+impl From<u32> for UniformType {
+    fn from(type_: u32) -> UniformType {
+        UniformType::from_raw(type_)
+    }
+}
+
+gl_enum! {
+    #[derive(Clone, Copy, Debug)]
+    pub enum TextureTarget {
+        Texture1d = gl::TEXTURE_1D,
+        Texture2d = gl::TEXTURE_2D,
+        Texture3d = gl::TEXTURE_3D,
+        Texture1dArray = gl::TEXTURE_1D_ARRAY,
+        Texture2dArray = gl::TEXTURE_2D_ARRAY,
+        TextureCubeMap = gl::TEXTURE_CUBE_MAP,
+        TextureCubeMapArray = gl::TEXTURE_CUBE_MAP_ARRAY,
+        Texture2dMultisample = gl::TEXTURE_2D_MULTISAMPLE,
+    }
+}
+
+gl_enum! {
+    /// Faces of a cube map texture, in the order GL numbers them
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::CubeMapFace;
+    ///
+    /// assert_eq!(CubeMapFace::PositiveX as u32, gl::TEXTURE_CUBE_MAP_POSITIVE_X);
+    /// assert_eq!(CubeMapFace::NegativeX as u32, gl::TEXTURE_CUBE_MAP_NEGATIVE_X);
+    /// assert_eq!(CubeMapFace::PositiveY as u32, gl::TEXTURE_CUBE_MAP_POSITIVE_Y);
+    /// assert_eq!(CubeMapFace::NegativeY as u32, gl::TEXTURE_CUBE_MAP_NEGATIVE_Y);
+    /// assert_eq!(CubeMapFace::PositiveZ as u32, gl::TEXTURE_CUBE_MAP_POSITIVE_Z);
+    /// assert_eq!(CubeMapFace::NegativeZ as u32, gl::TEXTURE_CUBE_MAP_NEGATIVE_Z);
+    ///
+    /// for (i, face) in CubeMapFace::ALL.iter().enumerate() {
+    ///     assert_eq!(*face as u32, gl::TEXTURE_CUBE_MAP_POSITIVE_X + i as u32);
+    /// }
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum CubeMapFace {
+        PositiveX = gl::TEXTURE_CUBE_MAP_POSITIVE_X,
+        NegativeX = gl::TEXTURE_CUBE_MAP_NEGATIVE_X,
+        PositiveY = gl::TEXTURE_CUBE_MAP_POSITIVE_Y,
+        NegativeY = gl::TEXTURE_CUBE_MAP_NEGATIVE_Y,
+        PositiveZ = gl::TEXTURE_CUBE_MAP_POSITIVE_Z,
+        NegativeZ = gl::TEXTURE_CUBE_MAP_NEGATIVE_Z,
+    }
+}
+
+impl CubeMapFace {
+    /// Every face, in the canonical +X, -X, +Y, -Y, +Z, -Z order
+    pub const ALL: [CubeMapFace; 6] = [
+        CubeMapFace::PositiveX,
+        CubeMapFace::NegativeX,
+        CubeMapFace::PositiveY,
+        CubeMapFace::NegativeY,
+        CubeMapFace::PositiveZ,
+        CubeMapFace::NegativeZ,
+    ];
+}
+
+//S3TC is an extension that never became core, so the gl crate has no tokens for it
+const COMPRESSED_RGB_S3TC_DXT1_EXT: u32 = 0x83F0;
+const COMPRESSED_RGBA_S3TC_DXT1_EXT: u32 = 0x83F1;
+const COMPRESSED_RGBA_S3TC_DXT3_EXT: u32 = 0x83F2;
+const COMPRESSED_RGBA_S3TC_DXT5_EXT: u32 = 0x83F3;
+const COMPRESSED_SRGB_S3TC_DXT1_EXT: u32 = 0x8C4C;
+const COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT: u32 = 0x8C4D;
+const COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT: u32 = 0x8C4E;
+const COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT: u32 = 0x8C4F;
+
+gl_enum! {
+    /// Block compressed texture formats, uploaded with `compressed_tex_image_2d`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::CompressedFormat;
+    ///
+    /// assert_eq!(CompressedFormat::RgbaS3tcDxt5 as u32, 0x83F3);
+    /// assert_eq!(CompressedFormat::RgRgtc2 as u32, gl::COMPRESSED_RG_RGTC2);
+    /// assert_eq!(CompressedFormat::RgbaBptcUnorm as u32, gl::COMPRESSED_RGBA_BPTC_UNORM);
+    /// assert_eq!(CompressedFormat::Rgba8Etc2Eac as u32, gl::COMPRESSED_RGBA8_ETC2_EAC);
+    ///
+    /// assert_eq!(CompressedFormat::SrgbAlphaS3tcDxt5 as u32, 0x8C4F);
+    ///
+    /// assert_eq!(CompressedFormat::from_raw(gl::COMPRESSED_R11_EAC), Some(CompressedFormat::R11Eac));
+    /// assert_eq!(CompressedFormat::from_raw(0x8C4C), Some(CompressedFormat::SrgbS3tcDxt1));
+    /// assert_eq!(CompressedFormat::from_raw(gl::RGBA8), None);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum CompressedFormat {
+        /// BC1, requires `EXT_texture_compression_s3tc`
+        RgbS3tcDxt1 = COMPRESSED_RGB_S3TC_DXT1_EXT,
+        /// BC1 with 1 bit alpha, requires `EXT_texture_compression_s3tc`
+        RgbaS3tcDxt1 = COMPRESSED_RGBA_S3TC_DXT1_EXT,
+        /// BC2, requires `EXT_texture_compression_s3tc`
+        RgbaS3tcDxt3 = COMPRESSED_RGBA_S3TC_DXT3_EXT,
+        /// BC3, requires `EXT_texture_compression_s3tc`
+        RgbaS3tcDxt5 = COMPRESSED_RGBA_S3TC_DXT5_EXT,
+        /// BC1 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        SrgbS3tcDxt1 = COMPRESSED_SRGB_S3TC_DXT1_EXT,
+        /// BC1 with sRGB colour and 1 bit alpha, requires `EXT_texture_sRGB` and
+        /// `EXT_texture_compression_s3tc`
+        SrgbAlphaS3tcDxt1 = COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,
+        /// BC2 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        SrgbAlphaS3tcDxt3 = COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,
+        /// BC3 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        SrgbAlphaS3tcDxt5 = COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT,
+        /// BC4
+        RedRgtc1 = gl::COMPRESSED_RED_RGTC1,
+        SignedRedRgtc1 = gl::COMPRESSED_SIGNED_RED_RGTC1,
+        /// BC5
+        RgRgtc2 = gl::COMPRESSED_RG_RGTC2,
+        SignedRgRgtc2 = gl::COMPRESSED_SIGNED_RG_RGTC2,
+        /// BC6H
+        RgbBptcSignedFloat = gl::COMPRESSED_RGB_BPTC_SIGNED_FLOAT,
+        RgbBptcUnsignedFloat = gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT,
+        /// BC7
+        RgbaBptcUnorm = gl::COMPRESSED_RGBA_BPTC_UNORM,
+        SrgbAlphaBptcUnorm = gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM,
+        Rgb8Etc2 = gl::COMPRESSED_RGB8_ETC2,
+        Srgb8Etc2 = gl::COMPRESSED_SRGB8_ETC2,
+        Rgb8PunchthroughAlpha1Etc2 = gl::COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2,
+        Srgb8PunchthroughAlpha1Etc2 = gl::COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2,
+        Rgba8Etc2Eac = gl::COMPRESSED_RGBA8_ETC2_EAC,
+        Srgb8Alpha8Etc2Eac = gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC,
+        R11Eac = gl::COMPRESSED_R11_EAC,
+        SignedR11Eac = gl::COMPRESSED_SIGNED_R11_EAC,
+        Rg11Eac = gl::COMPRESSED_RG11_EAC,
+        SignedRg11Eac = gl::COMPRESSED_SIGNED_RG11_EAC,
+    }
+}
+
+impl CompressedFormat {
+    /// Converts a raw format token, returning `None` for formats this crate does not know about
+    pub fn from_raw(format: u32) -> Option<CompressedFormat> {
+        match format {
+            COMPRESSED_RGB_S3TC_DXT1_EXT => Some(CompressedFormat::RgbS3tcDxt1),
+            COMPRESSED_RGBA_S3TC_DXT1_EXT => Some(CompressedFormat::RgbaS3tcDxt1),
+            COMPRESSED_RGBA_S3TC_DXT3_EXT => Some(CompressedFormat::RgbaS3tcDxt3),
+            COMPRESSED_RGBA_S3TC_DXT5_EXT => Some(CompressedFormat::RgbaS3tcDxt5),
+            COMPRESSED_SRGB_S3TC_DXT1_EXT => Some(CompressedFormat::SrgbS3tcDxt1),
+            COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT => Some(CompressedFormat::SrgbAlphaS3tcDxt1),
+            COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT => Some(CompressedFormat::SrgbAlphaS3tcDxt3),
+            COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT => Some(CompressedFormat::SrgbAlphaS3tcDxt5),
+            gl::COMPRESSED_RED_RGTC1 => Some(CompressedFormat::RedRgtc1),
+            gl::COMPRESSED_SIGNED_RED_RGTC1 => Some(CompressedFormat::SignedRedRgtc1),
+            gl::COMPRESSED_RG_RGTC2 => Some(CompressedFormat::RgRgtc2),
+            gl::COMPRESSED_SIGNED_RG_RGTC2 => Some(CompressedFormat::SignedRgRgtc2),
+            gl::COMPRESSED_RGB_BPTC_SIGNED_FLOAT => Some(CompressedFormat::RgbBptcSignedFloat),
+            gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT => Some(CompressedFormat::RgbBptcUnsignedFloat),
+            gl::COMPRESSED_RGBA_BPTC_UNORM => Some(CompressedFormat::RgbaBptcUnorm),
+            gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM => Some(CompressedFormat::SrgbAlphaBptcUnorm),
+            gl::COMPRESSED_RGB8_ETC2 => Some(CompressedFormat::Rgb8Etc2),
+            gl::COMPRESSED_SRGB8_ETC2 => Some(CompressedFormat::Srgb8Etc2),
+            gl::COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2 => Some(CompressedFormat::Rgb8PunchthroughAlpha1Etc2),
+            gl::COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2 => Some(CompressedFormat::Srgb8PunchthroughAlpha1Etc2),
+            gl::COMPRESSED_RGBA8_ETC2_EAC => Some(CompressedFormat::Rgba8Etc2Eac),
+            gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC => Some(CompressedFormat::Srgb8Alpha8Etc2Eac),
+            gl::COMPRESSED_R11_EAC => Some(CompressedFormat::R11Eac),
+            gl::COMPRESSED_SIGNED_R11_EAC => Some(CompressedFormat::SignedR11Eac),
+            gl::COMPRESSED_RG11_EAC => Some(CompressedFormat::Rg11Eac),
+            gl::COMPRESSED_SIGNED_RG11_EAC => Some(CompressedFormat::SignedRg11Eac),
+            _ => None,
+        }
+    }
+}
+
+gl_enum! {
+    /// Sized formats for immutable texture storage allocated with `tex_storage_2d` and `tex_storage_3d`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::SizedInternalFormat;
+    ///
+    /// assert_eq!(SizedInternalFormat::Rgba8 as u32, gl::RGBA8);
+    /// assert_eq!(SizedInternalFormat::Srgb8Alpha8 as u32, gl::SRGB8_ALPHA8);
+    /// assert_eq!(SizedInternalFormat::Rgba16F as u32, gl::RGBA16F);
+    /// assert_eq!(SizedInternalFormat::DepthComponent32F as u32, gl::DEPTH_COMPONENT32F);
+    /// assert_eq!(SizedInternalFormat::CompressedRgbaBptcUnorm as u32, gl::COMPRESSED_RGBA_BPTC_UNORM);
+    /// assert_eq!(SizedInternalFormat::CompressedRgbaS3tcDxt5 as u32, 0x83F3);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum SizedInternalFormat {
+        R8 = gl::R8,
+        Rg8 = gl::RG8,
+        Rgb8 = gl::RGB8,
+        Rgba8 = gl::RGBA8,
+        Srgb8 = gl::SRGB8,
+        Srgb8Alpha8 = gl::SRGB8_ALPHA8,
+        Rgba16 = gl::RGBA16,
+        Rgb10A2 = gl::RGB10_A2,
+        R16F = gl::R16F,
+        Rg16F = gl::RG16F,
+        Rgba16F = gl::RGBA16F,
+        R32F = gl::R32F,
+        Rg32F = gl::RG32F,
+        Rgba32F = gl::RGBA32F,
+        R11FG11FB10F = gl::R11F_G11F_B10F,
+        R32UI = gl::R32UI,
+        Rgba8UI = gl::RGBA8UI,
+        DepthComponent16 = gl::DEPTH_COMPONENT16,
+        DepthComponent24 = gl::DEPTH_COMPONENT24,
+        DepthComponent32F = gl::DEPTH_COMPONENT32F,
+        Depth24Stencil8 = gl::DEPTH24_STENCIL8,
+        Depth32FStencil8 = gl::DEPTH32F_STENCIL8,
+        StencilIndex8 = gl::STENCIL_INDEX8,
+
+        /// BC1, requires `EXT_texture_compression_s3tc`
+        CompressedRgbS3tcDxt1 = COMPRESSED_RGB_S3TC_DXT1_EXT,
+        /// BC1 with 1 bit alpha, requires `EXT_texture_compression_s3tc`
+        CompressedRgbaS3tcDxt1 = COMPRESSED_RGBA_S3TC_DXT1_EXT,
+        /// BC2, requires `EXT_texture_compression_s3tc`
+        CompressedRgbaS3tcDxt3 = COMPRESSED_RGBA_S3TC_DXT3_EXT,
+        /// BC3, requires `EXT_texture_compression_s3tc`
+        CompressedRgbaS3tcDxt5 = COMPRESSED_RGBA_S3TC_DXT5_EXT,
+        /// BC1 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        CompressedSrgbS3tcDxt1 = COMPRESSED_SRGB_S3TC_DXT1_EXT,
+        /// BC1 with sRGB colour and 1 bit alpha, requires `EXT_texture_sRGB` and
+        /// `EXT_texture_compression_s3tc`
+        CompressedSrgbAlphaS3tcDxt1 = COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,
+        /// BC2 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        CompressedSrgbAlphaS3tcDxt3 = COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,
+        /// BC3 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        CompressedSrgbAlphaS3tcDxt5 = COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT,
+        /// BC4
+        CompressedRedRgtc1 = gl::COMPRESSED_RED_RGTC1,
+        /// BC5
+        CompressedRgRgtc2 = gl::COMPRESSED_RG_RGTC2,
+        /// BC6H
+        CompressedRgbBptcUnsignedFloat = gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT,
+        /// BC7
+        CompressedRgbaBptcUnorm = gl::COMPRESSED_RGBA_BPTC_UNORM,
+        /// BC7 with sRGB colour
+        CompressedSrgbAlphaBptcUnorm = gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM,
+        CompressedSrgb8Etc2 = gl::COMPRESSED_SRGB8_ETC2,
+        CompressedSrgb8Alpha8Etc2Eac = gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC,
+    }
+}
+
+gl_enum! {
+    /// Format a texture's image is stored in on the GPU
+    ///
+    /// The sRGB formats store gamma encoded colour, which is decoded to linear when sampled. With
+    /// `Capability::FramebufferSrgb` enabled, rendering to them encodes linear colour back to sRGB
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::InternalFormat;
+    ///
+    /// assert_eq!(InternalFormat::Rgba8 as u32, gl::RGBA8);
+    /// assert_eq!(InternalFormat::Srgb as u32, gl::SRGB);
+    /// assert_eq!(InternalFormat::SrgbAlpha as u32, gl::SRGB_ALPHA);
+    /// assert_eq!(InternalFormat::Srgb8 as u32, gl::SRGB8);
+    /// assert_eq!(InternalFormat::Srgb8Alpha8 as u32, gl::SRGB8_ALPHA8);
+    /// assert_eq!(InternalFormat::CompressedSrgbAlpha as u32, gl::COMPRESSED_SRGB_ALPHA);
+    /// assert_eq!(InternalFormat::CompressedSrgbAlphaBptcUnorm as u32, gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM);
+    /// assert_eq!(InternalFormat::CompressedSrgbAlphaS3tcDxt5 as u32, 0x8C4F);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum InternalFormat {
+        Red = gl::RED,
+        Rg = gl::RG,
+        Rgb = gl::RGB,
+        Rgba = gl::RGBA,
+        DepthComponent = gl::DEPTH_COMPONENT,
+        DepthStencil = gl::DEPTH_STENCIL,
+
+        R8 = gl::R8,
+        Rg8 = gl::RG8,
+        Rgb8 = gl::RGB8,
+        Rgba8 = gl::RGBA8,
+        R16F = gl::R16F,
+        Rg16F = gl::RG16F,
+        Rgb16F = gl::RGB16F,
+        Rgba16F = gl::RGBA16F,
+        R32F = gl::R32F,
+        Rg32F = gl::RG32F,
+        Rgb32F = gl::RGB32F,
+        Rgba32F = gl::RGBA32F,
+        DepthComponent16 = gl::DEPTH_COMPONENT16,
+        DepthComponent24 = gl::DEPTH_COMPONENT24,
+        DepthComponent32F = gl::DEPTH_COMPONENT32F,
+        Depth24Stencil8 = gl::DEPTH24_STENCIL8,
+        Depth32FStencil8 = gl::DEPTH32F_STENCIL8,
+        StencilIndex8 = gl::STENCIL_INDEX8,
+        Rgb10A2 = gl::RGB10_A2,
+        R11FG11FB10F = gl::R11F_G11F_B10F,
+
+        /// Unsized sRGB colour, the driver picks the precision
+        Srgb = gl::SRGB,
+        /// Unsized sRGB colour with linear alpha, the driver picks the precision
+        SrgbAlpha = gl::SRGB_ALPHA,
+        Srgb8 = gl::SRGB8,
+        /// sRGB colour with linear alpha, the usual format for colour textures and render targets
+        Srgb8Alpha8 = gl::SRGB8_ALPHA8,
+        /// sRGB colour compressed in a format the driver picks
+        CompressedSrgb = gl::COMPRESSED_SRGB,
+        /// sRGB colour with linear alpha compressed in a format the driver picks
+        CompressedSrgbAlpha = gl::COMPRESSED_SRGB_ALPHA,
+        /// BC7 with sRGB colour
+        CompressedSrgbAlphaBptcUnorm = gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM,
+        CompressedSrgb8Etc2 = gl::COMPRESSED_SRGB8_ETC2,
+        CompressedSrgb8Alpha8Etc2Eac = gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC,
+        /// BC1 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        CompressedSrgbS3tcDxt1 = COMPRESSED_SRGB_S3TC_DXT1_EXT,
+        /// BC1 with sRGB colour and 1 bit alpha, requires `EXT_texture_sRGB` and
+        /// `EXT_texture_compression_s3tc`
+        CompressedSrgbAlphaS3tcDxt1 = COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,
+        /// BC2 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        CompressedSrgbAlphaS3tcDxt3 = COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,
+        /// BC3 with sRGB colour, requires `EXT_texture_sRGB` and `EXT_texture_compression_s3tc`
+        CompressedSrgbAlphaS3tcDxt5 = COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT,
+    }
+}
+
+gl_enum! {
+    /// How a shader may access an image bound with `bind_image_texture`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::ImageAccess;
+    ///
+    /// assert_eq!(ImageAccess::ReadOnly as u32, gl::READ_ONLY);
+    /// assert_eq!(ImageAccess::WriteOnly as u32, gl::WRITE_ONLY);
+    /// assert_eq!(ImageAccess::ReadWrite as u32, gl::READ_WRITE);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum ImageAccess {
+        ReadOnly = gl::READ_ONLY,
+        WriteOnly = gl::WRITE_ONLY,
+        ReadWrite = gl::READ_WRITE,
+    }
+}
+
+gl_enum! {
+    /// Format a shader reads and writes an image in, matching the format layout qualifier of the
+    /// `image2D` and friends, like `layout(rgba16f)`
+    ///
+    /// The texture's internal format must be of the same size, and textures of a different format are
+    /// reinterpreted
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::ImageFormat;
+    ///
+    /// assert_eq!(ImageFormat::Rgba32F as u32, gl::RGBA32F);
+    /// assert_eq!(ImageFormat::R11FG11FB10F as u32, gl::R11F_G11F_B10F);
+    /// assert_eq!(ImageFormat::Rgb10A2UI as u32, gl::RGB10_A2UI);
+    /// assert_eq!(ImageFormat::R32I as u32, gl::R32I);
+    /// assert_eq!(ImageFormat::Rg8Snorm as u32, gl::RG8_SNORM);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum ImageFormat {
+        Rgba32F = gl::RGBA32F,
+        Rgba16F = gl::RGBA16F,
+        Rg32F = gl::RG32F,
+        Rg16F = gl::RG16F,
+        R11FG11FB10F = gl::R11F_G11F_B10F,
+        R32F = gl::R32F,
+        R16F = gl::R16F,
+        Rgba32UI = gl::RGBA32UI,
+        Rgba16UI = gl::RGBA16UI,
+        Rgb10A2UI = gl::RGB10_A2UI,
+        Rgba8UI = gl::RGBA8UI,
+        Rg32UI = gl::RG32UI,
+        Rg16UI = gl::RG16UI,
+        Rg8UI = gl::RG8UI,
+        R32UI = gl::R32UI,
+        R16UI = gl::R16UI,
+        R8UI = gl::R8UI,
+        Rgba32I = gl::RGBA32I,
+        Rgba16I = gl::RGBA16I,
+        Rgba8I = gl::RGBA8I,
+        Rg32I = gl::RG32I,
+        Rg16I = gl::RG16I,
+        Rg8I = gl::RG8I,
+        R32I = gl::R32I,
+        R16I = gl::R16I,
+        R8I = gl::R8I,
+        Rgba16 = gl::RGBA16,
+        Rgb10A2 = gl::RGB10_A2,
+        Rgba8 = gl::RGBA8,
+        Rg16 = gl::RG16,
+        Rg8 = gl::RG8,
+        R16 = gl::R16,
+        R8 = gl::R8,
+        Rgba16Snorm = gl::RGBA16_SNORM,
+        Rgba8Snorm = gl::RGBA8_SNORM,
+        Rg16Snorm = gl::RG16_SNORM,
+        Rg8Snorm = gl::RG8_SNORM,
+        R16Snorm = gl::R16_SNORM,
+        R8Snorm = gl::R8_SNORM,
+    }
+}
+
+gl_enum! {
+    /// How pixel data is laid out in client memory, set with `pixel_store`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::PixelStoreParam;
+    ///
+    /// assert_eq!(PixelStoreParam::UnpackAlignment as u32, gl::UNPACK_ALIGNMENT);
+    /// assert_eq!(PixelStoreParam::PackAlignment as u32, gl::PACK_ALIGNMENT);
+    /// assert_eq!(PixelStoreParam::UnpackImageHeight as u32, gl::UNPACK_IMAGE_HEIGHT);
+    /// assert_eq!(PixelStoreParam::PackSkipImages as u32, gl::PACK_SKIP_IMAGES);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum PixelStoreParam {
+        PackAlignment = gl::PACK_ALIGNMENT,
+        PackRowLength = gl::PACK_ROW_LENGTH,
+        PackSkipPixels = gl::PACK_SKIP_PIXELS,
+        PackSkipRows = gl::PACK_SKIP_ROWS,
+        PackImageHeight = gl::PACK_IMAGE_HEIGHT,
+        PackSkipImages = gl::PACK_SKIP_IMAGES,
+        UnpackAlignment = gl::UNPACK_ALIGNMENT,
+        UnpackRowLength = gl::UNPACK_ROW_LENGTH,
+        UnpackSkipPixels = gl::UNPACK_SKIP_PIXELS,
+        UnpackSkipRows = gl::UNPACK_SKIP_ROWS,
+        UnpackImageHeight = gl::UNPACK_IMAGE_HEIGHT,
+        UnpackSkipImages = gl::UNPACK_SKIP_IMAGES,
+    }
+}
+
+gl_enum! {
+    /// Layout of the components of client-side pixel data
+    ///
+    /// Every function that moves pixels between client memory and GL describes them with a
+    /// `PixelFormat` and a `PixelType`, whatever the `InternalFormat` or `SizedInternalFormat` they are
+    /// stored in. `bytes_per_pixel` gives the size of a pixel, and which pairs are valid
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::PixelFormat;
+    ///
+    /// assert_eq!(PixelFormat::Red as u32, gl::RED);
+    /// assert_eq!(PixelFormat::Rg as u32, gl::RG);
+    /// assert_eq!(PixelFormat::Rgb as u32, gl::RGB);
+    /// assert_eq!(PixelFormat::Bgr as u32, gl::BGR);
+    /// assert_eq!(PixelFormat::Rgba as u32, gl::RGBA);
+    /// assert_eq!(PixelFormat::Bgra as u32, gl::BGRA);
+    /// assert_eq!(PixelFormat::RedInteger as u32, gl::RED_INTEGER);
+    /// assert_eq!(PixelFormat::RgInteger as u32, gl::RG_INTEGER);
+    /// assert_eq!(PixelFormat::RgbInteger as u32, gl::RGB_INTEGER);
+    /// assert_eq!(PixelFormat::BgrInteger as u32, gl::BGR_INTEGER);
+    /// assert_eq!(PixelFormat::RgbaInteger as u32, gl::RGBA_INTEGER);
+    /// assert_eq!(PixelFormat::BgraInteger as u32, gl::BGRA_INTEGER);
+    /// assert_eq!(PixelFormat::DepthComponent as u32, gl::DEPTH_COMPONENT);
+    /// assert_eq!(PixelFormat::DepthStencil as u32, gl::DEPTH_STENCIL);
+    /// assert_eq!(PixelFormat::StencilIndex as u32, gl::STENCIL_INDEX);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum PixelFormat {
+        Red = gl::RED,
+        Rg = gl::RG,
+        Rgb = gl::RGB,
+        Bgr = gl::BGR,
+        Rgba = gl::RGBA,
+        Bgra = gl::BGRA,
+        /// Components of integer textures, like `R32UI`, which are not normalised
+        RedInteger = gl::RED_INTEGER,
+        RgInteger = gl::RG_INTEGER,
+        RgbInteger = gl::RGB_INTEGER,
+        BgrInteger = gl::BGR_INTEGER,
+        RgbaInteger = gl::RGBA_INTEGER,
+        BgraInteger = gl::BGRA_INTEGER,
+        DepthComponent = gl::DEPTH_COMPONENT,
+        /// Only valid with the `UnsignedInt24_8` and `Float32UnsignedInt24_8Rev` types
+        DepthStencil = gl::DEPTH_STENCIL,
+        StencilIndex = gl::STENCIL_INDEX,
+    }
+}
+
+gl_enum! {
+    /// Data type of the components of client-side pixel data
+    ///
+    /// The packed types hold every component of a pixel in a single value, with the bit counts of the
+    /// components in the order of the `PixelFormat`, reversed for the `Rev` types
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::PixelType;
+    ///
+    /// assert_eq!(PixelType::UnsignedByte as u32, gl::UNSIGNED_BYTE);
+    /// assert_eq!(PixelType::Byte as u32, gl::BYTE);
+    /// assert_eq!(PixelType::UnsignedShort as u32, gl::UNSIGNED_SHORT);
+    /// assert_eq!(PixelType::Short as u32, gl::SHORT);
+    /// assert_eq!(PixelType::UnsignedInt as u32, gl::UNSIGNED_INT);
+    /// assert_eq!(PixelType::Int as u32, gl::INT);
+    /// assert_eq!(PixelType::HalfFloat as u32, gl::HALF_FLOAT);
+    /// assert_eq!(PixelType::Float as u32, gl::FLOAT);
+    /// assert_eq!(PixelType::UnsignedInt24_8 as u32, gl::UNSIGNED_INT_24_8);
+    /// assert_eq!(PixelType::Float32UnsignedInt24_8Rev as u32, gl::FLOAT_32_UNSIGNED_INT_24_8_REV);
+    /// assert_eq!(PixelType::UnsignedShort5_6_5 as u32, gl::UNSIGNED_SHORT_5_6_5);
+    /// assert_eq!(PixelType::UnsignedShort5_6_5Rev as u32, gl::UNSIGNED_SHORT_5_6_5_REV);
+    /// assert_eq!(PixelType::UnsignedShort4_4_4_4 as u32, gl::UNSIGNED_SHORT_4_4_4_4);
+    /// assert_eq!(PixelType::UnsignedShort4_4_4_4Rev as u32, gl::UNSIGNED_SHORT_4_4_4_4_REV);
+    /// assert_eq!(PixelType::UnsignedShort5_5_5_1 as u32, gl::UNSIGNED_SHORT_5_5_5_1);
+    /// assert_eq!(PixelType::UnsignedShort1_5_5_5Rev as u32, gl::UNSIGNED_SHORT_1_5_5_5_REV);
+    /// assert_eq!(PixelType::UnsignedInt10_10_10_2 as u32, gl::UNSIGNED_INT_10_10_10_2);
+    /// assert_eq!(PixelType::UnsignedInt2_10_10_10Rev as u32, gl::UNSIGNED_INT_2_10_10_10_REV);
+    /// assert_eq!(PixelType::UnsignedInt10F11F11FRev as u32, gl::UNSIGNED_INT_10F_11F_11F_REV);
+    /// assert_eq!(PixelType::UnsignedInt5_9_9_9Rev as u32, gl::UNSIGNED_INT_5_9_9_9_REV);
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum PixelType {
+        UnsignedByte = gl::UNSIGNED_BYTE,
+        Byte = gl::BYTE,
+        UnsignedShort = gl::UNSIGNED_SHORT,
+        Short = gl::SHORT,
+        UnsignedInt = gl::UNSIGNED_INT,
+        Int = gl::INT,
+        HalfFloat = gl::HALF_FLOAT,
+        Float = gl::FLOAT,
+        /// 24 bits of depth and 8 of stencil, for `PixelFormat::DepthStencil`
+        UnsignedInt24_8 = gl::UNSIGNED_INT_24_8,
+        /// A 32 bit float depth, then 24 unused bits and 8 of stencil, for `PixelFormat::DepthStencil`
+        Float32UnsignedInt24_8Rev = gl::FLOAT_32_UNSIGNED_INT_24_8_REV,
+        UnsignedShort5_6_5 = gl::UNSIGNED_SHORT_5_6_5,
+        UnsignedShort5_6_5Rev = gl::UNSIGNED_SHORT_5_6_5_REV,
+        UnsignedShort4_4_4_4 = gl::UNSIGNED_SHORT_4_4_4_4,
+        UnsignedShort4_4_4_4Rev = gl::UNSIGNED_SHORT_4_4_4_4_REV,
+        UnsignedShort5_5_5_1 = gl::UNSIGNED_SHORT_5_5_5_1,
+        UnsignedShort1_5_5_5Rev = gl::UNSIGNED_SHORT_1_5_5_5_REV,
+        UnsignedInt10_10_10_2 = gl::UNSIGNED_INT_10_10_10_2,
+        UnsignedInt2_10_10_10Rev = gl::UNSIGNED_INT_2_10_10_10_REV,
+        /// The packed floats of `R11FG11FB10F`, for `PixelFormat::Rgb`
+        UnsignedInt10F11F11FRev = gl::UNSIGNED_INT_10F_11F_11F_REV,
+        /// Three 9 bit mantissas sharing a 5 bit exponent, for `PixelFormat::Rgb`
+        UnsignedInt5_9_9_9Rev = gl::UNSIGNED_INT_5_9_9_9_REV,
+    }
+}
+
+gl_enum! {
+    #[derive(Clone, Copy, Debug)]
+    pub enum TextureParameter {
+        MinFilter = gl::TEXTURE_MIN_FILTER,
+        MagFilter = gl::TEXTURE_MAG_FILTER,
+        WrapS = gl::TEXTURE_WRAP_S,
+        WrapT = gl::TEXTURE_WRAP_T,
+        WrapR = gl::TEXTURE_WRAP_R,
+        BaseLevel = gl::TEXTURE_BASE_LEVEL,
+        MaxLevel = gl::TEXTURE_MAX_LEVEL,
+        BorderColor = gl::TEXTURE_BORDER_COLOR,
+        CompareMode = gl::TEXTURE_COMPARE_MODE,
+        CompareFunc = gl::TEXTURE_COMPARE_FUNC,
+        MinLod = gl::TEXTURE_MIN_LOD,
+        MaxLod = gl::TEXTURE_MAX_LOD,
+        LodBias = gl::TEXTURE_LOD_BIAS,
+        /// `GL_TEXTURE_MAX_ANISOTROPY`, which the GL 4.5 bindings predate. GL 4.6 and
+        /// `EXT_texture_filter_anisotropic` share the value
+        MaxAnisotropy = 0x84FE,
+        SwizzleR = gl::TEXTURE_SWIZZLE_R,
+        SwizzleG = gl::TEXTURE_SWIZZLE_G,
+        SwizzleB = gl::TEXTURE_SWIZZLE_B,
+        SwizzleA = gl::TEXTURE_SWIZZLE_A,
+        /// All four swizzles at once, only settable with a vector of 4 values
+        SwizzleRgba = gl::TEXTURE_SWIZZLE_RGBA,
+    }
+}
+
+gl_enum! {
+    /// Properties of a single mip level of a texture, queried with `get_tex_level_parameter`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::TextureLevelParameter;
+    ///
+    /// assert_eq!(TextureLevelParameter::Width as u32, gl::TEXTURE_WIDTH);
+    /// assert_eq!(TextureLevelParameter::Height as u32, gl::TEXTURE_HEIGHT);
+    /// assert_eq!(TextureLevelParameter::Depth as u32, gl::TEXTURE_DEPTH);
+    /// assert_eq!(TextureLevelParameter::InternalFormat as u32, gl::TEXTURE_INTERNAL_FORMAT);
+    /// assert_eq!(TextureLevelParameter::Compressed as u32, gl::TEXTURE_COMPRESSED);
+    /// assert_eq!(TextureLevelParameter::CompressedImageSize as u32, gl::TEXTURE_COMPRESSED_IMAGE_SIZE);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum TextureLevelParameter {
+        Width = gl::TEXTURE_WIDTH,
+        Height = gl::TEXTURE_HEIGHT,
+        /// The depth of three-dimensional levels, or the layers of array levels
+        Depth = gl::TEXTURE_DEPTH,
+        /// The raw internal format token the level is actually stored in
+        InternalFormat = gl::TEXTURE_INTERNAL_FORMAT,
+        /// Bits of red per texel, 0 if the format has none
+        RedSize = gl::TEXTURE_RED_SIZE,
+        GreenSize = gl::TEXTURE_GREEN_SIZE,
+        BlueSize = gl::TEXTURE_BLUE_SIZE,
+        AlphaSize = gl::TEXTURE_ALPHA_SIZE,
+        DepthSize = gl::TEXTURE_DEPTH_SIZE,
+        StencilSize = gl::TEXTURE_STENCIL_SIZE,
+        /// `gl::TRUE` if the level is stored compressed
+        Compressed = gl::TEXTURE_COMPRESSED,
+        /// Bytes of a compressed level
+        CompressedImageSize = gl::TEXTURE_COMPRESSED_IMAGE_SIZE,
+    }
+}
+
+gl_enum! {
+    /// Whether sampling a depth texture returns its depth or the result of a comparison against it
+    #[derive(Clone, Copy, Debug)]
+    pub enum CompareMode {
+        None = gl::NONE,
+        /// Compare against the reference value in the texture coordinate, as `sampler2DShadow` needs
+        CompareRefToTexture = gl::COMPARE_REF_TO_TEXTURE,
+    }
+}
+
+gl_enum! {
+    /// How texture coordinates outside of [0, 1] are handled
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::WrapMode;
+    ///
+    /// assert_eq!(WrapMode::Repeat as u32, gl::REPEAT);
+    /// assert_eq!(WrapMode::MirroredRepeat as u32, gl::MIRRORED_REPEAT);
+    /// assert_eq!(WrapMode::ClampToEdge as u32, gl::CLAMP_TO_EDGE);
+    /// assert_eq!(WrapMode::ClampToBorder as u32, gl::CLAMP_TO_BORDER);
+    /// assert_eq!(WrapMode::MirrorClampToEdge as u32, gl::MIRROR_CLAMP_TO_EDGE);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum WrapMode {
+        Repeat = gl::REPEAT,
+        MirroredRepeat = gl::MIRRORED_REPEAT,
+        ClampToEdge = gl::CLAMP_TO_EDGE,
+        ClampToBorder = gl::CLAMP_TO_BORDER,
+        MirrorClampToEdge = gl::MIRROR_CLAMP_TO_EDGE,
+    }
+}
+
+gl_enum! {
+    /// Where a channel of a swizzled texture reads its value from, see `tex_swizzle`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::Swizzle;
+    ///
+    /// assert_eq!(Swizzle::Red as u32, gl::RED);
+    /// assert_eq!(Swizzle::Green as u32, gl::GREEN);
+    /// assert_eq!(Swizzle::Blue as u32, gl::BLUE);
+    /// assert_eq!(Swizzle::Alpha as u32, gl::ALPHA);
+    /// assert_eq!(Swizzle::Zero as u32, gl::ZERO);
+    /// assert_eq!(Swizzle::One as u32, gl::ONE);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub enum Swizzle {
+        Red = gl::RED,
+        Green = gl::GREEN,
+        Blue = gl::BLUE,
+        Alpha = gl::ALPHA,
+        /// The constant 0
+        Zero = gl::ZERO,
+        /// The constant 1
+        One = gl::ONE,
+    }
+}
+
+gl_enum! {
+    /// Texture minifying filters, including the mipmapped ones
+    #[derive(Clone, Copy, Debug)]
+    pub enum MinFilter {
+        Nearest = gl::NEAREST,
+        Linear = gl::LINEAR,
+        NearestMipmapNearest = gl::NEAREST_MIPMAP_NEAREST,
+        LinearMipmapNearest = gl::LINEAR_MIPMAP_NEAREST,
+        NearestMipmapLinear = gl::NEAREST_MIPMAP_LINEAR,
+        LinearMipmapLinear = gl::LINEAR_MIPMAP_LINEAR,
+    }
+}
+
+gl_enum! {
+    /// Texture magnification filters, mipmaps are never used when magnifying
+    #[derive(Clone, Copy, Debug)]
+    pub enum MagFilter {
+        Nearest = gl::NEAREST,
+        Linear = gl::LINEAR,
+    }
+}
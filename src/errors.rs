@@ -0,0 +1,337 @@
+use super::buffers::{BufferRangeError, MapError, MapLost};
+use super::framebuffers::FramebufferStatus;
+use super::info::LoadError;
+use super::shaders::{IncludeError, ShaderError};
+
+use gl;
+use gl::types::*;
+use std::error;
+use std::ffi::NulError;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+#[cfg(feature = "error-check")]
+use std::sync::RwLock;
+
+/// Errors reported by `glGetError`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GlError {
+    InvalidEnum,
+    InvalidValue,
+    InvalidOperation,
+    InvalidFramebufferOperation,
+    OutOfMemory,
+    StackUnderflow,
+    StackOverflow,
+    ContextLost,
+    /// An error code this crate does not know about
+    Unknown(GLenum),
+}
+
+impl GlError {
+    /// Converts a raw error code, returning `None` for `GL_NO_ERROR`
+    ///
+    /// # Examples
+    /// ```rust
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use rgl::GlError;
+    ///
+    /// assert_eq!(GlError::from_raw(gl::NO_ERROR), None);
+    /// assert_eq!(GlError::from_raw(gl::INVALID_ENUM), Some(GlError::InvalidEnum));
+    /// assert_eq!(GlError::from_raw(gl::OUT_OF_MEMORY), Some(GlError::OutOfMemory));
+    /// ```
+    pub fn from_raw(error: GLenum) -> Option<GlError> {
+        match error {
+            gl::NO_ERROR => None,
+            gl::INVALID_ENUM => Some(GlError::InvalidEnum),
+            gl::INVALID_VALUE => Some(GlError::InvalidValue),
+            gl::INVALID_OPERATION => Some(GlError::InvalidOperation),
+            gl::INVALID_FRAMEBUFFER_OPERATION => Some(GlError::InvalidFramebufferOperation),
+            gl::OUT_OF_MEMORY => Some(GlError::OutOfMemory),
+            gl::STACK_UNDERFLOW => Some(GlError::StackUnderflow),
+            gl::STACK_OVERFLOW => Some(GlError::StackOverflow),
+            gl::CONTEXT_LOST => Some(GlError::ContextLost),
+            other => Some(GlError::Unknown(other)),
+        }
+    }
+}
+
+impl fmt::Display for GlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GlError::InvalidEnum => write!(f, "invalid enum"),
+            GlError::InvalidValue => write!(f, "invalid value"),
+            GlError::InvalidOperation => write!(f, "invalid operation"),
+            GlError::InvalidFramebufferOperation => write!(f, "invalid framebuffer operation"),
+            GlError::OutOfMemory => write!(f, "out of memory"),
+            GlError::StackUnderflow => write!(f, "stack underflow"),
+            GlError::StackOverflow => write!(f, "stack overflow"),
+            GlError::ContextLost => write!(f, "context lost"),
+            GlError::Unknown(code) => write!(f, "unknown error 0x{:04X}", code),
+        }
+    }
+}
+
+impl error::Error for GlError {}
+
+/// Returns the oldest recorded error, or `None` if no error has occurred
+///
+/// # Examples
+/// ```rust,no_run
+/// if let Some(error) = rgl::get_error() {
+///     println!("OpenGL error: {}", error);
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetError.xhtml
+pub fn get_error() -> Option<GlError> {
+    GlError::from_raw(unsafe { gl::GetError() })
+}
+
+/// Returns the oldest recorded error as an `Err`, for use with `?`
+pub fn get_error_checked() -> Result<(), GlError> {
+    match get_error() {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Returns every recorded error, as GL can queue more than one
+pub fn drain_errors() -> Vec<GlError> {
+    let mut errors = Vec::new();
+    while let Some(error) = get_error() {
+        errors.push(error);
+    }
+    errors
+}
+
+/// A slice was too small for the data a function needs to read or write
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SizeError {
+    /// Bytes the function needs
+    pub required: usize,
+    /// Bytes the slice actually holds
+    pub actual: usize,
+}
+
+impl fmt::Display for SizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "slice holds {} bytes but {} are required", self.actual, self.required)
+    }
+}
+
+impl error::Error for SizeError {}
+
+/// A raw token converted with `TryFrom` is not a variant of the enum
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownEnum {
+    /// Name of the enum
+    pub name: &'static str,
+    /// The raw token
+    pub value: GLenum,
+}
+
+impl fmt::Display for UnknownEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#x} is not a {}", self.value, self.name)
+    }
+}
+
+impl error::Error for UnknownEnum {}
+
+/// Any error reported by this crate
+///
+/// The error types of the individual functions all convert into it, so initialisation code can
+/// bubble every failure up with `?`
+///
+/// # Examples
+/// ```rust,no_run
+/// fn init(vertex_source: &str, fragment_source: &str) -> Result<rgl::Program, rgl::Error> {
+///     let program = rgl::create_program();
+///     for &(type_, source) in &[(rgl::ShaderType::Vertex, vertex_source), (rgl::ShaderType::Fragment, fragment_source)] {
+///         let shader = rgl::create_shader(type_);
+///         rgl::shader_source(shader, source);
+///         rgl::compile_shader_checked(shader)?;
+///         rgl::attach_shader(program, shader);
+///     }
+///     rgl::link_program_checked(program)?;
+///
+///     let mvp = rgl::get_uniform_location_checked(program, "u_mvp")?;
+///     rgl::program_uniform_matrix4fv(program, mvp, false, &[0.0; 16]);
+///
+///     rgl::check_framebuffer_status(rgl::FramebufferTarget::Draw)?;
+///     rgl::get_error_checked()?;
+///     Ok(program)
+/// }
+/// ```
+///
+/// ```rust
+/// let error: rgl::Error = rgl::ShaderError::Link(String::from("no main")).into();
+/// assert_eq!(error, rgl::Error::ProgramLink(String::from("no main")));
+/// assert_eq!(error.to_string(), "program failed to link: no main");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An error from `glGetError`
+    Gl(GlError),
+    /// A shader failed to compile, with the info log
+    ShaderCompile(String),
+    /// A program failed to link, with the info log
+    ProgramLink(String),
+    FramebufferIncomplete(FramebufferStatus),
+    Map(MapError),
+    MapLost,
+    Size(SizeError),
+    BufferRange(BufferRangeError),
+    /// A program has no active uniform, attribute or block of this name
+    NotFound(String),
+    /// An argument the function cannot work with
+    InvalidInput(&'static str),
+    /// A string passed to GL contained a NUL
+    Nul(NulError),
+    /// A file could not be read
+    Io(PathBuf, io::ErrorKind),
+    Include(IncludeError),
+    Load(LoadError),
+    UnknownEnum(UnknownEnum),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Gl(ref error) => write!(f, "GL error: {}", error),
+            Error::ShaderCompile(ref log) => write!(f, "shader failed to compile: {}", log),
+            Error::ProgramLink(ref log) => write!(f, "program failed to link: {}", log),
+            Error::FramebufferIncomplete(ref status) => status.fmt(f),
+            Error::Map(ref error) => error.fmt(f),
+            Error::MapLost => MapLost.fmt(f),
+            Error::Size(ref error) => error.fmt(f),
+            Error::BufferRange(ref error) => error.fmt(f),
+            Error::NotFound(ref name) => write!(f, "no active resource called {}", name),
+            Error::InvalidInput(reason) => write!(f, "invalid input: {}", reason),
+            Error::Nul(ref error) => error.fmt(f),
+            Error::Io(ref path, kind) => write!(f, "failed to read {}: {}", path.display(), io::Error::from(kind)),
+            Error::Include(ref error) => error.fmt(f),
+            Error::Load(ref error) => error.fmt(f),
+            Error::UnknownEnum(ref error) => error.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Nul(ref error) => Some(error),
+            Error::Include(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<GlError> for Error {
+    fn from(error: GlError) -> Error {
+        Error::Gl(error)
+    }
+}
+
+impl From<ShaderError> for Error {
+    fn from(error: ShaderError) -> Error {
+        match error {
+            ShaderError::Compile(log) => Error::ShaderCompile(log),
+            ShaderError::Link(log) => Error::ProgramLink(log),
+        }
+    }
+}
+
+impl From<FramebufferStatus> for Error {
+    fn from(status: FramebufferStatus) -> Error {
+        Error::FramebufferIncomplete(status)
+    }
+}
+
+impl From<MapError> for Error {
+    fn from(error: MapError) -> Error {
+        Error::Map(error)
+    }
+}
+
+impl From<MapLost> for Error {
+    fn from(_: MapLost) -> Error {
+        Error::MapLost
+    }
+}
+
+impl From<SizeError> for Error {
+    fn from(error: SizeError) -> Error {
+        Error::Size(error)
+    }
+}
+
+impl From<BufferRangeError> for Error {
+    fn from(error: BufferRangeError) -> Error {
+        Error::BufferRange(error)
+    }
+}
+
+impl From<NulError> for Error {
+    fn from(error: NulError) -> Error {
+        Error::Nul(error)
+    }
+}
+
+impl From<IncludeError> for Error {
+    fn from(error: IncludeError) -> Error {
+        Error::Include(error)
+    }
+}
+
+impl From<LoadError> for Error {
+    fn from(error: LoadError) -> Error {
+        Error::Load(error)
+    }
+}
+
+impl From<UnknownEnum> for Error {
+    fn from(error: UnknownEnum) -> Error {
+        Error::UnknownEnum(error)
+    }
+}
+
+/// Called with the name of the wrapper function and the error, see `set_error_callback`
+#[cfg(feature = "error-check")]
+pub type ErrorCallback = fn(&'static str, GlError);
+
+#[cfg(feature = "error-check")]
+static ERROR_CALLBACK: RwLock<Option<ErrorCallback>> = RwLock::new(None);
+
+/// Sets the function called when a wrapper generates a GL error, `None` restores the default
+///
+/// The default callback panics with the name of the wrapper and the error, for example
+/// `buffer_data: InvalidOperation`. Only available with the `error-check` feature
+///
+/// # Examples
+/// ```rust,ignore
+/// fn log_error(function: &'static str, error: rgl::GlError) {
+///     eprintln!("{}: {:?}", function, error);
+/// }
+///
+/// rgl::set_error_callback(Some(log_error));
+/// ```
+#[cfg(feature = "error-check")]
+pub fn set_error_callback(callback: Option<ErrorCallback>) {
+    *ERROR_CALLBACK.write().unwrap_or_else(|error| error.into_inner()) = callback;
+}
+
+#[cfg(feature = "error-check")]
+#[doc(hidden)]
+pub fn check_errors(function: &'static str) {
+    while let Some(error) = GlError::from_raw(unsafe { gl::GetError() }) {
+        let callback = *ERROR_CALLBACK.read().unwrap_or_else(|error| error.into_inner());
+        match callback {
+            Some(callback) => callback(function, error),
+            None => panic!("{}: {:?}", function, error),
+        }
+    }
+}
@@ -0,0 +1,766 @@
+use super::enums;
+use super::errors::SizeError;
+use super::textures::{self, Texture};
+
+use gl;
+use gl::types::*;
+use std::error;
+use std::fmt;
+use std::os::raw::c_void;
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct Framebuffer(pub GLuint);
+
+impl Framebuffer {
+    /// The name 0, which binds no framebuffer object, leaving the default framebuffer bound
+    pub const NONE: Framebuffer = Framebuffer(0);
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct Renderbuffer(pub GLuint);
+
+impl Renderbuffer {
+    /// The name 0, which binds no renderbuffer
+    pub const NONE: Renderbuffer = Renderbuffer(0);
+}
+
+/// Reasons a framebuffer can be incomplete, returned by `check_framebuffer_status`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FramebufferStatus {
+    /// The default framebuffer is bound but does not exist
+    Undefined,
+    IncompleteAttachment,
+    MissingAttachment,
+    IncompleteDrawBuffer,
+    IncompleteReadBuffer,
+    Unsupported,
+    IncompleteMultisample,
+    IncompleteLayerTargets,
+    /// A status this crate does not know about
+    Unknown(GLenum),
+}
+
+impl fmt::Display for FramebufferStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FramebufferStatus::Undefined => write!(f, "framebuffer is undefined"),
+            FramebufferStatus::IncompleteAttachment => write!(f, "framebuffer has an incomplete attachment"),
+            FramebufferStatus::MissingAttachment => write!(f, "framebuffer has no attachments"),
+            FramebufferStatus::IncompleteDrawBuffer => write!(f, "framebuffer draw buffer has no attachment"),
+            FramebufferStatus::IncompleteReadBuffer => write!(f, "framebuffer read buffer has no attachment"),
+            FramebufferStatus::Unsupported => write!(f, "framebuffer attachment combination is unsupported"),
+            FramebufferStatus::IncompleteMultisample => write!(f, "framebuffer attachments have mismatched samples"),
+            FramebufferStatus::IncompleteLayerTargets => write!(f, "framebuffer attachments are not all layered"),
+            FramebufferStatus::Unknown(status) => write!(f, "framebuffer is incomplete (0x{:04X})", status),
+        }
+    }
+}
+
+impl error::Error for FramebufferStatus {}
+
+/// Generates framebuffer objects, one for every element of the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenFramebuffers.xhtml
+pub fn gen_framebuffers(framebuffers: &mut [Framebuffer]) {
+    gl_call!(gen_framebuffers, gl::GenFramebuffers(framebuffers.len() as GLsizei, framebuffers.as_mut_ptr() as *mut GLuint));
+}
+
+/// Generates a single framebuffer
+pub fn gen_framebuffer() -> Framebuffer {
+    let mut framebuffer = [Framebuffer(0)];
+    gen_framebuffers(&mut framebuffer);
+    framebuffer[0]
+}
+
+/// Creates framebuffer objects, one for every element of the slice
+///
+/// Unlike `gen_framebuffers`, the objects exist straight away without binding them first.
+/// Requires GL 4.5 or `ARB_direct_state_access`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateFramebuffers.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn create_framebuffers(framebuffers: &mut [Framebuffer]) {
+    gl_call!(create_framebuffers, gl::CreateFramebuffers(framebuffers.len() as GLsizei, framebuffers.as_mut_ptr() as *mut GLuint));
+}
+
+/// Creates a single framebuffer, see `create_framebuffers`
+#[cfg(not(feature = "gles2"))]
+pub fn create_framebuffer() -> Framebuffer {
+    let mut framebuffer = [Framebuffer(0)];
+    create_framebuffers(&mut framebuffer);
+    framebuffer[0]
+}
+
+/// Binds a framebuffer, so it is rendered to and/or read from
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindFramebuffer.xhtml
+pub fn bind_framebuffer<H: Into<Framebuffer>>(target: enums::FramebufferTarget, framebuffer: H) {
+    let framebuffer: Framebuffer = framebuffer.into();
+    gl_call!(bind_framebuffer, gl::BindFramebuffer(target as GLenum, framebuffer.0));
+}
+
+/// Binds the default framebuffer, the window's, to `target`
+///
+/// # Examples
+/// ```rust,no_run
+/// # let offscreen = rgl::gen_framebuffer();
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Both, offscreen);
+/// //...draw the scene
+///
+/// //Back to the screen for the final pass
+/// rgl::unbind_framebuffer(rgl::FramebufferTarget::Both);
+/// ```
+pub fn unbind_framebuffer(target: enums::FramebufferTarget) {
+    gl_call!(unbind_framebuffer, gl::BindFramebuffer(target as GLenum, 0));
+}
+
+/// Deletes every framebuffer object in the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteFramebuffers.xhtml
+pub fn delete_framebuffers(framebuffers: &[Framebuffer]) {
+    gl_call!(delete_framebuffers, gl::DeleteFramebuffers(framebuffers.len() as GLsizei, framebuffers.as_ptr() as *const GLuint));
+}
+
+pub fn delete_framebuffer(framebuffer: Framebuffer) {
+    delete_framebuffers(&[framebuffer]);
+}
+
+/// Whether `framebuffer` names a framebuffer object, see `is_buffer`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsFramebuffer.xhtml
+pub fn is_framebuffer(framebuffer: Framebuffer) -> bool {
+    gl_call!(is_framebuffer, gl::IsFramebuffer(framebuffer.0)) == gl::TRUE
+}
+
+/// Attaches a level of a texture to the framebuffer bound to `target`
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn shadow_map() -> Result<(), rgl::FramebufferStatus> {
+/// let depth = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, depth);
+/// rgl::tex_image_2d(
+///     rgl::TextureTarget::Texture2d,
+///     0,
+///     rgl::InternalFormat::DepthComponent24,
+///     2048,
+///     2048,
+///     rgl::PixelFormat::DepthComponent,
+///     rgl::PixelType::Float,
+///     None,
+/// );
+///
+/// let fbo = rgl::gen_framebuffer();
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Both, fbo);
+/// rgl::framebuffer_texture_2d(
+///     rgl::FramebufferTarget::Both,
+///     rgl::Attachment::Depth,
+///     rgl::TextureTarget::Texture2d,
+///     depth,
+///     0,
+/// );
+/// rgl::check_framebuffer_status(rgl::FramebufferTarget::Both)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFramebufferTexture.xhtml
+pub fn framebuffer_texture_2d(
+    target: enums::FramebufferTarget,
+    attachment: enums::Attachment,
+    texture_target: enums::TextureTarget,
+    texture: Texture,
+    level: GLint,
+) {
+    gl_call!(framebuffer_texture_2d, gl::FramebufferTexture2D(
+        target as GLenum,
+        attachment as GLenum,
+        texture_target as GLenum,
+        texture.0,
+        level
+    ));
+}
+
+/// Attaches a single layer of a level of a three-dimensional, array or cube map texture to the
+/// framebuffer bound to `target`
+///
+/// A `layer` past the end of the texture, or a texture of another kind, makes the framebuffer
+/// incomplete, which `check_framebuffer_status` reports
+///
+/// # Examples
+/// ```rust,no_run
+/// # let (fbo, shadow_maps) = (rgl::gen_framebuffer(), rgl::gen_texture());
+/// //Render into layer 2 of a 4 layer depth array texture
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Both, fbo);
+/// rgl::framebuffer_texture_layer(rgl::FramebufferTarget::Both, rgl::Attachment::Depth, shadow_maps, 0, 2);
+/// assert!(rgl::check_framebuffer_status(rgl::FramebufferTarget::Both).is_ok());
+/// ```
+///
+/// ```rust,no_run
+/// # fn cascades() -> Result<(), rgl::FramebufferStatus> {
+/// //Cascaded shadow maps, one layer of a depth array texture per cascade
+/// let cascades = 4;
+/// let shadow_maps = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2dArray, shadow_maps);
+/// rgl::tex_image_3d(
+///     rgl::TextureTarget::Texture2dArray,
+///     0,
+///     rgl::InternalFormat::DepthComponent32F,
+///     2048,
+///     2048,
+///     cascades,
+///     rgl::PixelFormat::DepthComponent,
+///     rgl::PixelType::Float,
+///     None,
+/// );
+///
+/// let fbo = rgl::gen_framebuffer();
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Both, fbo);
+/// for cascade in 0..cascades {
+///     rgl::framebuffer_texture_layer(rgl::FramebufferTarget::Both, rgl::Attachment::Depth, shadow_maps, 0, cascade);
+///     rgl::check_framebuffer_status(rgl::FramebufferTarget::Both)?;
+///     //...render the cascade
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFramebufferTextureLayer.xhtml
+pub fn framebuffer_texture_layer(
+    target: enums::FramebufferTarget,
+    attachment: enums::Attachment,
+    texture: Texture,
+    level: GLint,
+    layer: GLint,
+) {
+    gl_call!(framebuffer_texture_layer, gl::FramebufferTextureLayer(
+        target as GLenum,
+        attachment as GLenum,
+        texture.0,
+        level,
+        layer
+    ));
+}
+
+/// Attaches a whole level of a texture to the framebuffer bound to `target`
+///
+/// For three-dimensional, array and cube map textures every layer is attached, making the
+/// framebuffer layered, and a geometry shader picks the layer of each primitive with `gl_Layer`.
+/// Cube map faces are layers 0 to 5 in `CubeMapFace` order
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn shadows() -> Result<(), rgl::FramebufferStatus> {
+/// //Single pass point light shadows, the geometry shader emits each triangle once per face
+/// let geometry_source = "#version 330 core
+/// layout (triangles) in;
+/// layout (triangle_strip, max_vertices = 18) out;
+/// uniform mat4 face_matrices[6];
+///
+/// void main() {
+///     for (int face = 0; face < 6; face++) {
+///         gl_Layer = face;
+///         for (int i = 0; i < 3; i++) {
+///             gl_Position = face_matrices[face] * gl_in[i].gl_Position;
+///             EmitVertex();
+///         }
+///         EndPrimitive();
+///     }
+/// }";
+/// assert!(rgl::max_geometry_output_vertices() >= 18);
+///
+/// let shadow_map = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::TextureCubeMap, shadow_map);
+/// for face in rgl::CubeMapFace::ALL.iter() {
+///     rgl::tex_image_2d_cube_face(
+///         *face,
+///         0,
+///         rgl::InternalFormat::DepthComponent32F,
+///         1024,
+///         1024,
+///         rgl::PixelFormat::DepthComponent,
+///         rgl::PixelType::Float,
+///         None,
+///     );
+/// }
+///
+/// let fbo = rgl::gen_framebuffer();
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Both, fbo);
+/// rgl::framebuffer_texture(rgl::FramebufferTarget::Both, rgl::Attachment::Depth, shadow_map, 0);
+/// rgl::check_framebuffer_status(rgl::FramebufferTarget::Both)?;
+/// //...draw the scene once with the program using geometry_source
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFramebufferTexture.xhtml
+pub fn framebuffer_texture(
+    target: enums::FramebufferTarget,
+    attachment: enums::Attachment,
+    texture: Texture,
+    level: GLint,
+) {
+    gl_call!(framebuffer_texture, gl::FramebufferTexture(
+        target as GLenum,
+        attachment as GLenum,
+        texture.0,
+        level
+    ));
+}
+
+/// Attaches a renderbuffer to the framebuffer bound to `target`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFramebufferRenderbuffer.xhtml
+pub fn framebuffer_renderbuffer(
+    target: enums::FramebufferTarget,
+    attachment: enums::Attachment,
+    renderbuffer: Renderbuffer,
+) {
+    gl_call!(framebuffer_renderbuffer, gl::FramebufferRenderbuffer(
+        target as GLenum,
+        attachment as GLenum,
+        gl::RENDERBUFFER,
+        renderbuffer.0
+    ));
+}
+
+/// Checks whether the framebuffer bound to `target` is complete, and so can be used
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCheckFramebufferStatus.xhtml
+pub fn check_framebuffer_status(target: enums::FramebufferTarget) -> Result<(), FramebufferStatus> {
+    let status = gl_call!(check_framebuffer_status, gl::CheckFramebufferStatus(target as GLenum));
+
+    match status {
+        gl::FRAMEBUFFER_COMPLETE => Ok(()),
+        gl::FRAMEBUFFER_UNDEFINED => Err(FramebufferStatus::Undefined),
+        gl::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => Err(FramebufferStatus::IncompleteAttachment),
+        gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => Err(FramebufferStatus::MissingAttachment),
+        gl::FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => Err(FramebufferStatus::IncompleteDrawBuffer),
+        gl::FRAMEBUFFER_INCOMPLETE_READ_BUFFER => Err(FramebufferStatus::IncompleteReadBuffer),
+        gl::FRAMEBUFFER_UNSUPPORTED => Err(FramebufferStatus::Unsupported),
+        gl::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => Err(FramebufferStatus::IncompleteMultisample),
+        gl::FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => Err(FramebufferStatus::IncompleteLayerTargets),
+        other => Err(FramebufferStatus::Unknown(other)),
+    }
+}
+
+/// Returns a property of an attachment of the framebuffer object bound to `target`
+///
+/// Use `get_default_framebuffer_attachment_parameter` while the default framebuffer is bound, its
+/// buffers are named by `DefaultAttachment` rather than `Attachment`
+///
+/// # Examples
+/// ```rust,no_run
+/// # extern crate gl;
+/// # extern crate rgl;
+/// //Check what a framebuffer built elsewhere renders colour into
+/// # let framebuffer = rgl::gen_framebuffer();
+/// let target = rgl::FramebufferTarget::Read;
+/// let attachment = rgl::Attachment::Color0;
+/// rgl::bind_framebuffer(target, framebuffer);
+///
+/// let type_ = rgl::get_framebuffer_attachment_parameter(target, attachment, rgl::FramebufferAttachmentParameter::ObjectType);
+/// if type_ == gl::TEXTURE as i32 {
+///     let texture = rgl::get_framebuffer_attachment_parameter(target, attachment, rgl::FramebufferAttachmentParameter::ObjectName);
+///     let float_colour = rgl::get_framebuffer_attachment_parameter(target, attachment, rgl::FramebufferAttachmentParameter::ComponentType) == gl::FLOAT as i32;
+///     println!("texture {}, float colour: {}", texture, float_colour);
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetFramebufferAttachmentParameter.xhtml
+pub fn get_framebuffer_attachment_parameter(
+    target: enums::FramebufferTarget,
+    attachment: enums::Attachment,
+    param_name: enums::FramebufferAttachmentParameter,
+) -> GLint {
+    let mut value = 0;
+    gl_call!(get_framebuffer_attachment_parameter, gl::GetFramebufferAttachmentParameteriv(
+        target as GLenum,
+        attachment as GLenum,
+        param_name as GLenum,
+        &mut value
+    ));
+    value
+}
+
+/// Returns a property of a buffer of the default framebuffer, which must be bound to `target`
+///
+/// # Examples
+/// ```rust,no_run
+/// # extern crate gl;
+/// # extern crate rgl;
+/// //Only use stencil based outlines if the window was created with a stencil buffer
+/// let target = rgl::FramebufferTarget::Draw;
+/// rgl::unbind_framebuffer(target);
+///
+/// let has_stencil = rgl::get_default_framebuffer_attachment_parameter(
+///     target,
+///     rgl::DefaultAttachment::Stencil,
+///     rgl::FramebufferAttachmentParameter::ObjectType,
+/// ) != gl::NONE as i32
+///     && rgl::get_default_framebuffer_attachment_parameter(
+///         target,
+///         rgl::DefaultAttachment::Stencil,
+///         rgl::FramebufferAttachmentParameter::StencilSize,
+///     ) > 0;
+/// ```
+///
+/// ```rust,no_run
+/// //Only let GL encode to sRGB if the window was created with an sRGB back buffer, otherwise
+/// //the shaders have to apply the gamma curve themselves
+/// rgl::unbind_framebuffer(rgl::FramebufferTarget::Draw);
+/// let encoding = rgl::get_default_framebuffer_attachment_parameter(
+///     rgl::FramebufferTarget::Draw,
+///     rgl::DefaultAttachment::BackLeft,
+///     rgl::FramebufferAttachmentParameter::ColorEncoding,
+/// );
+/// if encoding == rgl::ColorEncoding::Srgb as i32 {
+///     rgl::enable(rgl::Capability::FramebufferSrgb);
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetFramebufferAttachmentParameter.xhtml
+pub fn get_default_framebuffer_attachment_parameter(
+    target: enums::FramebufferTarget,
+    attachment: enums::DefaultAttachment,
+    param_name: enums::FramebufferAttachmentParameter,
+) -> GLint {
+    let mut value = 0;
+    gl_call!(get_default_framebuffer_attachment_parameter, gl::GetFramebufferAttachmentParameteriv(
+        target as GLenum,
+        attachment as GLenum,
+        param_name as GLenum,
+        &mut value
+    ));
+    value
+}
+
+/// Generates renderbuffer objects, one for every element of the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenRenderbuffers.xhtml
+pub fn gen_renderbuffers(renderbuffers: &mut [Renderbuffer]) {
+    gl_call!(gen_renderbuffers, gl::GenRenderbuffers(renderbuffers.len() as GLsizei, renderbuffers.as_mut_ptr() as *mut GLuint));
+}
+
+/// Generates a single renderbuffer
+pub fn gen_renderbuffer() -> Renderbuffer {
+    let mut renderbuffer = [Renderbuffer(0)];
+    gen_renderbuffers(&mut renderbuffer);
+    renderbuffer[0]
+}
+
+/// Creates renderbuffer objects, one for every element of the slice
+///
+/// Unlike `gen_renderbuffers`, the objects exist straight away without binding them first.
+/// Requires GL 4.5 or `ARB_direct_state_access`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateRenderbuffers.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn create_renderbuffers(renderbuffers: &mut [Renderbuffer]) {
+    gl_call!(create_renderbuffers, gl::CreateRenderbuffers(renderbuffers.len() as GLsizei, renderbuffers.as_mut_ptr() as *mut GLuint));
+}
+
+/// Creates a single renderbuffer, see `create_renderbuffers`
+#[cfg(not(feature = "gles2"))]
+pub fn create_renderbuffer() -> Renderbuffer {
+    let mut renderbuffer = [Renderbuffer(0)];
+    create_renderbuffers(&mut renderbuffer);
+    renderbuffer[0]
+}
+
+/// Binds a renderbuffer, so `renderbuffer_storage` allocates its storage
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindRenderbuffer.xhtml
+pub fn bind_renderbuffer<H: Into<Renderbuffer>>(renderbuffer: H) {
+    let renderbuffer: Renderbuffer = renderbuffer.into();
+    gl_call!(bind_renderbuffer, gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer.0));
+}
+
+/// Unbinds the current renderbuffer, `renderbuffer_storage` is an error until another is bound
+pub fn unbind_renderbuffer() {
+    gl_call!(unbind_renderbuffer, gl::BindRenderbuffer(gl::RENDERBUFFER, 0));
+}
+
+/// Allocates storage for the bound renderbuffer
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glRenderbufferStorage.xhtml
+pub fn renderbuffer_storage(internal_format: enums::InternalFormat, width: GLsizei, height: GLsizei) {
+    gl_call!(renderbuffer_storage, gl::RenderbufferStorage(gl::RENDERBUFFER, internal_format as GLenum, width, height));
+}
+
+/// Allocates multisampled storage for the bound renderbuffer
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn msaa_target() -> Result<rgl::Framebuffer, rgl::FramebufferStatus> {
+/// let color = rgl::gen_renderbuffer();
+/// rgl::bind_renderbuffer(color);
+/// rgl::renderbuffer_storage_multisample(4, rgl::InternalFormat::Rgba8, 1280, 720);
+///
+/// let depth = rgl::gen_renderbuffer();
+/// rgl::bind_renderbuffer(depth);
+/// rgl::renderbuffer_storage_multisample(4, rgl::InternalFormat::Depth24Stencil8, 1280, 720);
+///
+/// let fbo = rgl::gen_framebuffer();
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Both, fbo);
+/// rgl::framebuffer_renderbuffer(rgl::FramebufferTarget::Both, rgl::Attachment::Color0, color);
+/// rgl::framebuffer_renderbuffer(rgl::FramebufferTarget::Both, rgl::Attachment::DepthStencil, depth);
+/// rgl::check_framebuffer_status(rgl::FramebufferTarget::Both)?;
+/// # Ok(fbo)
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glRenderbufferStorageMultisample.xhtml
+pub fn renderbuffer_storage_multisample(
+    samples: GLsizei,
+    internal_format: enums::InternalFormat,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    gl_call!(renderbuffer_storage_multisample, gl::RenderbufferStorageMultisample(
+        gl::RENDERBUFFER,
+        samples,
+        internal_format as GLenum,
+        width,
+        height
+    ));
+}
+
+/// Deletes every renderbuffer object in the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteRenderbuffers.xhtml
+pub fn delete_renderbuffers(renderbuffers: &[Renderbuffer]) {
+    gl_call!(delete_renderbuffers, gl::DeleteRenderbuffers(renderbuffers.len() as GLsizei, renderbuffers.as_ptr() as *const GLuint));
+}
+
+pub fn delete_renderbuffer(renderbuffer: Renderbuffer) {
+    delete_renderbuffers(&[renderbuffer]);
+}
+
+/// Whether `renderbuffer` names a renderbuffer object, see `is_buffer`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsRenderbuffer.xhtml
+pub fn is_renderbuffer(renderbuffer: Renderbuffer) -> bool {
+    gl_call!(is_renderbuffer, gl::IsRenderbuffer(renderbuffer.0)) == gl::TRUE
+}
+
+/// Sets the colour buffers of the bound draw framebuffer that fragment outputs are written to,
+/// output `i` goes to `buffers[i]`
+///
+/// Passing more buffers than `Parameter::MaxDrawBuffers` is a `GL_INVALID_VALUE`, reported by
+/// the `error-check` feature
+///
+/// # Examples
+/// ```rust,no_run
+/// use rgl::DrawBufferTarget;
+///
+/// //Deferred shading, the geometry pass writes to three attachments at once
+/// let g_buffer = rgl::gen_framebuffer();
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Draw, g_buffer);
+/// //...attach the albedo, normal and id textures
+/// rgl::draw_buffers(&[
+///     DrawBufferTarget::ColorAttachment0,
+///     DrawBufferTarget::ColorAttachment1,
+///     DrawBufferTarget::ColorAttachment2,
+/// ]);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawBuffers.xhtml
+pub fn draw_buffers(buffers: &[enums::DrawBufferTarget]) {
+    let buffers: Vec<GLenum> = buffers.iter().map(|&buffer| buffer as GLenum).collect();
+    gl_call!(draw_buffers, gl::DrawBuffers(buffers.len() as GLsizei, buffers.as_ptr()));
+}
+
+/// Sets the single colour buffer of the bound draw framebuffer that fragment output 0 is written to
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawBuffer.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn draw_buffer(buffer: enums::DrawBufferTarget) {
+    gl_call!(draw_buffer, gl::DrawBuffer(buffer as GLenum));
+}
+
+/// Sets the colour buffer of the bound read framebuffer that `read_pixels` and
+/// `blit_framebuffer` read from
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glReadBuffer.xhtml
+pub fn read_buffer(buffer: enums::ReadBufferTarget) {
+    gl_call!(read_buffer, gl::ReadBuffer(buffer as GLenum));
+}
+
+/// Copies a rectangle of the read framebuffer to a rectangle of the draw framebuffer
+///
+/// Rectangles are `(x0, y0, x1, y1)`, and are flipped when `x1 < x0` or `y1 < y0`. Blitting from a
+/// multisampled framebuffer resolves it. GL only allows `BlitFilter::Linear` for colour, blits
+/// including depth or stencil must use `BlitFilter::Nearest`
+///
+/// # Panics
+/// In debug builds, if `mask` includes depth or stencil and `filter` is `BlitFilter::Linear`
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn resolve() -> Result<(), rgl::FramebufferStatus> {
+/// //Render to a 4x MSAA framebuffer, then resolve it to the default framebuffer
+/// let (width, height) = (1280, 720);
+///
+/// let color = rgl::gen_renderbuffer();
+/// rgl::bind_renderbuffer(color);
+/// rgl::renderbuffer_storage_multisample(4, rgl::InternalFormat::Rgba8, width, height);
+///
+/// let depth = rgl::gen_renderbuffer();
+/// rgl::bind_renderbuffer(depth);
+/// rgl::renderbuffer_storage_multisample(4, rgl::InternalFormat::Depth24Stencil8, width, height);
+///
+/// let msaa = rgl::gen_framebuffer();
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Both, msaa);
+/// rgl::framebuffer_renderbuffer(rgl::FramebufferTarget::Both, rgl::Attachment::Color0, color);
+/// rgl::framebuffer_renderbuffer(rgl::FramebufferTarget::Both, rgl::Attachment::DepthStencil, depth);
+/// rgl::check_framebuffer_status(rgl::FramebufferTarget::Both)?;
+///
+/// //...draw the scene
+///
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Read, msaa);
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Draw, rgl::Framebuffer(0));
+/// rgl::blit_framebuffer(
+///     (0, 0, width, height),
+///     (0, 0, width, height),
+///     rgl::ClearMask::COLOR,
+///     rgl::BlitFilter::Nearest,
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```rust,no_run
+/// //Upscale a low resolution 320x180 render to a 1920x1200 window, keeping the aspect ratio with
+/// //black bars at the top and bottom
+/// let low_res = rgl::gen_framebuffer();
+/// //...draw the frame into low_res
+/// let (window_width, window_height) = (1920, 1200);
+///
+/// let scaled_height = window_width * 180 / 320;
+/// let bar = (window_height - scaled_height) / 2;
+///
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Draw, rgl::Framebuffer(0));
+/// rgl::clear_color(0.0, 0.0, 0.0, 1.0);
+/// rgl::clear(rgl::ClearMask::COLOR);
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Read, low_res);
+/// rgl::blit_framebuffer(
+///     (0, 0, 320, 180),
+///     (0, bar, window_width, bar + scaled_height),
+///     rgl::ClearMask::COLOR,
+///     rgl::BlitFilter::Linear,
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlitFramebuffer.xhtml
+pub fn blit_framebuffer(
+    src: (GLint, GLint, GLint, GLint),
+    dst: (GLint, GLint, GLint, GLint),
+    mask: enums::ClearMask,
+    filter: enums::BlitFilter,
+) {
+    debug_assert!(
+        !(matches!(filter, enums::BlitFilter::Linear)
+            && (mask.contains(enums::ClearMask::DEPTH) || mask.contains(enums::ClearMask::STENCIL))),
+        "depth and stencil blits must use BlitFilter::Nearest"
+    );
+    gl_call!(blit_framebuffer, gl::BlitFramebuffer(
+        src.0,
+        src.1,
+        src.2,
+        src.3,
+        dst.0,
+        dst.1,
+        dst.2,
+        dst.3,
+        mask.bits(),
+        filter as GLenum
+    ));
+}
+
+/// The largest sample count supported for multisampled renderbuffers and textures
+pub fn max_samples() -> GLsizei {
+    let mut samples = 0;
+    gl_call!(max_samples, gl::GetIntegerv(gl::MAX_SAMPLES, &mut samples));
+    samples
+}
+
+/// The largest number of layers a layered framebuffer can have
+pub fn max_framebuffer_layers() -> GLint {
+    let mut layers = 0;
+    gl_call!(max_framebuffer_layers, gl::GetIntegerv(gl::MAX_FRAMEBUFFER_LAYERS, &mut layers));
+    layers
+}
+
+/// Reads a block of pixels from the read framebuffer into a newly allocated `Vec`
+///
+/// The `Vec` is sized from the format, type and the current pack state, see `PixelLayout::pack`.
+/// Any buffer bound to `Target::PixelPackBuffer` is unbound for the read, and bound again after
+///
+/// # Examples
+/// ```rust,no_run
+/// let (x, y, width, height) = rgl::get_viewport();
+/// let screenshot = rgl::read_pixels(x, y, width, height, rgl::PixelFormat::Rgba, rgl::PixelType::UnsignedByte);
+/// ```
+///
+/// # Panics
+/// If the pixels would not fit in memory
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glReadPixels.xhtml
+pub fn read_pixels(
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+) -> Vec<u8> {
+    let size = textures::PixelLayout::pack()
+        .data_size(width, height, format, type_)
+        .expect("read_pixels would not fit in memory");
+    let mut pixels = vec![0; size];
+    textures::without_pack_buffer(|| {
+        gl_call!(read_pixels, gl::ReadPixels(x, y, width, height, format as GLenum, type_ as GLenum, pixels.as_mut_ptr() as *mut c_void));
+    });
+    pixels
+}
+
+/// Reads a block of pixels from the read framebuffer into `out`, failing if it is too small
+///
+/// The size needed depends on the current pack state like `read_pixels`, which also unbinds any
+/// pack buffer for the read
+///
+/// # Examples
+/// ```rust,no_run
+/// // Read back the object id under the cursor, reusing the same buffer every frame
+/// let mut id = [0u8; 4];
+/// rgl::read_pixels_into(320, 240, 1, 1, rgl::PixelFormat::Rgba, rgl::PixelType::UnsignedByte, &mut id).unwrap();
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glReadPixels.xhtml
+pub fn read_pixels_into(
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+    out: &mut [u8],
+) -> Result<(), SizeError> {
+    let required = textures::PixelLayout::pack()
+        .data_size(width, height, format, type_)
+        .unwrap_or(usize::MAX);
+    if out.len() < required {
+        return Err(SizeError {
+            required,
+            actual: out.len(),
+        });
+    }
+
+    textures::without_pack_buffer(|| {
+        gl_call!(read_pixels_into, gl::ReadPixels(x, y, width, height, format as GLenum, type_ as GLenum, out.as_mut_ptr() as *mut c_void));
+    });
+    Ok(())
+}
@@ -0,0 +1,373 @@
+use gl;
+use gl::types::*;
+use std::error;
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "gles2")]
+use super::buffers;
+use super::enums;
+use super::shaders;
+use super::state;
+use super::textures;
+
+/// Returns a string describing the current context
+///
+/// Returns an empty string if GL returns no string, which happens when no context is current
+///
+/// # Examples
+/// ```rust,no_run
+/// println!(
+///     "{} on {} ({})",
+///     rgl::get_string(rgl::StringName::Version),
+///     rgl::get_string(rgl::StringName::Renderer),
+///     rgl::get_string(rgl::StringName::Vendor),
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetString.xhtml
+pub fn get_string(name: enums::StringName) -> String {
+    string_from_gl(gl_call!(get_string, gl::GetString(name as GLenum)))
+}
+
+/// Returns the names of every extension supported by the context
+///
+/// With the `gles2` feature the names are split from the single `GL_EXTENSIONS` string, as ES 2
+/// has no `glGetStringi`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetString.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn get_extensions() -> Vec<String> {
+    let mut count = 0;
+    gl_call!(get_extensions, gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count));
+
+    (0..count.max(0) as GLuint)
+        .map(|index| string_from_gl(gl_call!(get_extensions, gl::GetStringi(gl::EXTENSIONS, index))))
+        .collect()
+}
+
+#[cfg(feature = "gles2")]
+pub fn get_extensions() -> Vec<String> {
+    string_from_gl(gl_call!(get_extensions, gl::GetString(gl::EXTENSIONS)))
+        .split_whitespace()
+        .map(String::from)
+        .collect()
+}
+
+/// Returns whether the context supports an extension, for example `"GL_ARB_bindless_texture"`
+///
+/// This queries every extension name, so check once and keep the result
+pub fn has_extension(name: &str) -> bool {
+    get_extensions().iter().any(|extension| extension == name)
+}
+
+/// Returns the `(major, minor)` version of the context
+///
+/// With the `gles2` feature the version is parsed from `StringName::Version`, as ES 2 has no
+/// `GL_MAJOR_VERSION`, and `(0, 0)` is returned if it cannot be parsed
+///
+/// # Examples
+/// ```rust,no_run
+/// if rgl::get_version() >= (4, 3) {
+///     //...use compute shaders
+/// }
+/// ```
+#[cfg(not(feature = "gles2"))]
+pub fn get_version() -> (GLint, GLint) {
+    let mut major = 0;
+    let mut minor = 0;
+    gl_call!(get_version, gl::GetIntegerv(gl::MAJOR_VERSION, &mut major));
+    gl_call!(get_version, gl::GetIntegerv(gl::MINOR_VERSION, &mut minor));
+    (major, minor)
+}
+
+#[cfg(feature = "gles2")]
+pub fn get_version() -> (GLint, GLint) {
+    parse_version(&get_string(enums::StringName::Version)).unwrap_or((0, 0))
+}
+
+//ES version strings look like "OpenGL ES 2.0 Mesa 23.0", desktop ones like "4.6.0 NVIDIA 535.54"
+#[cfg(feature = "gles2")]
+fn parse_version(version: &str) -> Option<(GLint, GLint)> {
+    let number = version.split_whitespace().find(|word| word.starts_with(|c: char| c.is_ascii_digit()))?;
+    let mut parts = number.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.chars().take_while(|c| c.is_ascii_digit()).collect::<String>().parse().ok()?;
+    Some((major, minor))
+}
+
+fn string_from_gl(string: *const GLubyte) -> String {
+    if string.is_null() {
+        return String::new();
+    }
+
+    unsafe { CStr::from_ptr(string as *const c_char) }.to_string_lossy().into_owned()
+}
+
+/// Returns the value of an integer parameter
+///
+/// # Examples
+/// ```rust,no_run
+/// let max_texture_size = rgl::get_integer(rgl::Parameter::MaxTextureSize);
+/// let max_attributes = rgl::get_integer(rgl::Parameter::MaxVertexAttribs);
+/// println!("textures up to {0}x{0}, {1} vertex attributes", max_texture_size, max_attributes);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGet.xhtml
+pub fn get_integer(parameter: enums::Parameter) -> GLint {
+    get_integer4(parameter)[0]
+}
+
+/// Returns up to 4 values of an integer parameter such as `Parameter::Viewport`, values the
+/// parameter does not have are left as 0
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGet.xhtml
+pub fn get_integer4(parameter: enums::Parameter) -> [GLint; 4] {
+    let mut values = [0; 4];
+    gl_call!(get_integer4, gl::GetIntegerv(parameter as GLenum, values.as_mut_ptr()));
+    values
+}
+
+/// Returns value `index` of an indexed integer parameter
+///
+/// # Examples
+/// ```rust,no_run
+/// let max_groups_x = rgl::get_integer_indexed(rgl::Parameter::MaxComputeWorkGroupCount, 0);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGet.xhtml
+pub fn get_integer_indexed(parameter: enums::Parameter, index: GLuint) -> GLint {
+    let mut value = 0;
+    gl_call!(get_integer_indexed, gl::GetIntegeri_v(parameter as GLenum, index, &mut value));
+    value
+}
+
+/// Returns the value of a float parameter
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGet.xhtml
+pub fn get_float(parameter: enums::Parameter) -> GLfloat {
+    let mut values = [0.0; 4];
+    gl_call!(get_float, gl::GetFloatv(parameter as GLenum, values.as_mut_ptr()));
+    values[0]
+}
+
+/// Returns the 2 values of a float parameter such as `Parameter::PointSizeRange`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGet.xhtml
+pub fn get_float2(parameter: enums::Parameter) -> [GLfloat; 2] {
+    let mut values = [0.0; 4];
+    gl_call!(get_float2, gl::GetFloatv(parameter as GLenum, values.as_mut_ptr()));
+    [values[0], values[1]]
+}
+
+/// Returns the value of a boolean parameter
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGet.xhtml
+pub fn get_boolean(parameter: enums::Parameter) -> bool {
+    let mut values = [gl::FALSE; 4];
+    gl_call!(get_boolean, gl::GetBooleanv(parameter as GLenum, values.as_mut_ptr()));
+    values[0] == gl::TRUE
+}
+
+/// What the current context supports, queried once with `Capabilities::query`
+///
+/// Each feature is available if the context's version has it in core, or the context exposes the
+/// extension it came from, as drivers often expose ARB extensions on lower versions. With the
+/// `gles2` feature only the extensions are checked
+///
+/// # Examples
+#[cfg_attr(not(feature = "gles2"), doc = "```rust,no_run")]
+#[cfg_attr(feature = "gles2", doc = "```rust,ignore")]
+/// let caps = rgl::Capabilities::query();
+///
+/// if caps.buffer_storage {
+///     rgl::buffer_storage_reserve(rgl::Target::ArrayBuffer, 1024, rgl::StorageFlags::DYNAMIC_STORAGE);
+/// } else {
+///     rgl::buffer_reserve(rgl::Target::ArrayBuffer, 1024, rgl::Usage::DynamicDraw);
+/// }
+///
+/// if caps.anisotropic_filtering {
+///     rgl::tex_max_anisotropy(rgl::TextureTarget::Texture2d, caps.max_anisotropy.min(8.0));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Capabilities {
+    /// The `(major, minor)` version, see `get_version`
+    pub version: (GLint, GLint),
+    /// GL 4.4 or `ARB_buffer_storage`
+    pub buffer_storage: bool,
+    /// GL 4.5 or `ARB_direct_state_access`, the functions in `dsa`
+    pub direct_state_access: bool,
+    /// GL 4.3 or `ARB_compute_shader`
+    pub compute_shaders: bool,
+    /// GL 4.2 or `ARB_texture_storage`
+    pub texture_storage: bool,
+    /// GL 4.3 or `ARB_texture_view`
+    pub texture_view: bool,
+    /// GL 4.4 or `ARB_multi_bind`
+    pub multi_bind: bool,
+    /// GL 4.3, `KHR_debug` or `ARB_debug_output`
+    pub debug_output: bool,
+    /// GL 4.6, `ARB_texture_filter_anisotropic` or `EXT_texture_filter_anisotropic`
+    pub anisotropic_filtering: bool,
+    /// `Parameter::MaxSamples`, 0 with the `gles2` feature
+    pub max_samples: GLint,
+    /// The highest anisotropy `tex_max_anisotropy` accepts, 1.0 without anisotropic filtering
+    pub max_anisotropy: GLfloat,
+    /// `Parameter::MaxTextureSize`
+    pub max_texture_size: GLint,
+}
+
+impl Capabilities {
+    /// Queries the capabilities of the current context
+    ///
+    /// Reads every extension name and a few parameters, so query once and keep the result
+    pub fn query() -> Capabilities {
+        let version = get_version();
+        let extensions = get_extensions();
+        let supports = |core: (GLint, GLint), names: &[&str]| {
+            (!cfg!(feature = "gles2") && version >= core)
+                || extensions.iter().any(|extension| names.contains(&extension.as_str()))
+        };
+
+        let anisotropic_filtering =
+            supports((4, 6), &["GL_ARB_texture_filter_anisotropic", "GL_EXT_texture_filter_anisotropic"]);
+        let mut max_anisotropy = 1.0;
+        if anisotropic_filtering {
+            gl_call!(query, gl::GetFloatv(textures::MAX_TEXTURE_MAX_ANISOTROPY, &mut max_anisotropy));
+        }
+
+        Capabilities {
+            version,
+            buffer_storage: supports((4, 4), &["GL_ARB_buffer_storage"]),
+            direct_state_access: supports((4, 5), &["GL_ARB_direct_state_access"]),
+            compute_shaders: supports((4, 3), &["GL_ARB_compute_shader"]),
+            texture_storage: supports((4, 2), &["GL_ARB_texture_storage"]),
+            texture_view: supports((4, 3), &["GL_ARB_texture_view"]),
+            multi_bind: supports((4, 4), &["GL_ARB_multi_bind"]),
+            debug_output: supports((4, 3), &["GL_KHR_debug", "GL_ARB_debug_output"]),
+            anisotropic_filtering,
+            max_samples: if cfg!(feature = "gles2") { 0 } else { get_integer(enums::Parameter::MaxSamples) },
+            max_anisotropy,
+            max_texture_size: get_integer(enums::Parameter::MaxTextureSize),
+        }
+    }
+}
+
+/// Set by `load_with`, see `check_loaded`
+static LOADED: AtomicBool = AtomicBool::new(false);
+
+/// The context `load_with` loaded the functions of
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlInfo {
+    /// The `(major, minor)` version, see `get_version`
+    pub version: (GLint, GLint),
+    /// The full version string, which often includes the driver version
+    pub version_string: String,
+    pub vendor: String,
+    pub renderer: String,
+    pub shading_language_version: String,
+}
+
+/// Reasons `load_with` can fail
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoadError {
+    /// The loader returned null for a function every context has
+    MissingFunction(&'static str),
+    /// The functions loaded, but GL returned no version string, which happens when no context is
+    /// current
+    NoContext,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LoadError::MissingFunction(name) => write!(f, "the loader did not find {}", name),
+            LoadError::NoContext => write!(f, "no GL context is current"),
+        }
+    }
+}
+
+impl error::Error for LoadError {}
+
+/// Loads the GL functions with `loader`, checking that it found the essential ones and that a
+/// context is current
+///
+/// Forwards `loader` to `gl::load_with`, then to the crate's own loaders such as
+/// `load_polygon_offset_clamp`, and with the `gles2` feature `load_oes_vertex_array_object`.
+/// Debug builds panic with the name of the wrapper when one is called before the functions were
+/// loaded, by this or by `gl::load_with`
+///
+/// # Examples
+/// ```rust,ignore
+/// let info = rgl::load_with(|symbol| context.get_proc_address(symbol) as *const _)?;
+/// println!("{} on {}", info.version_string, info.renderer);
+/// ```
+///
+/// A loader that finds nothing is reported rather than crashing later
+///
+/// ```rust
+/// use std::ptr;
+///
+/// assert_eq!(rgl::load_with(|_| ptr::null()).unwrap_err(), rgl::LoadError::MissingFunction("glGetError"));
+/// assert!(!rgl::is_loaded());
+/// ```
+pub fn load_with<F>(mut loader: F) -> Result<GlInfo, LoadError>
+where
+    F: FnMut(&'static str) -> *const c_void,
+{
+    gl::load_with(&mut loader);
+    state::load_polygon_offset_clamp(&mut loader);
+    shaders::load_specialize_shader(&mut loader);
+    #[cfg(feature = "gles2")]
+    buffers::load_oes_vertex_array_object(&mut loader);
+
+    let essential = [
+        ("glGetError", gl::GetError::is_loaded()),
+        ("glGetString", gl::GetString::is_loaded()),
+        ("glGetIntegerv", gl::GetIntegerv::is_loaded()),
+        ("glGenBuffers", gl::GenBuffers::is_loaded()),
+        ("glBindBuffer", gl::BindBuffer::is_loaded()),
+        ("glBufferData", gl::BufferData::is_loaded()),
+        ("glCreateShader", gl::CreateShader::is_loaded()),
+        ("glShaderSource", gl::ShaderSource::is_loaded()),
+        ("glCompileShader", gl::CompileShader::is_loaded()),
+        ("glCreateProgram", gl::CreateProgram::is_loaded()),
+        ("glLinkProgram", gl::LinkProgram::is_loaded()),
+        ("glUseProgram", gl::UseProgram::is_loaded()),
+        ("glDrawArrays", gl::DrawArrays::is_loaded()),
+        ("glClear", gl::Clear::is_loaded()),
+        ("glViewport", gl::Viewport::is_loaded()),
+    ];
+    if let Some(&(name, _)) = essential.iter().find(|&&(_, loaded)| !loaded) {
+        return Err(LoadError::MissingFunction(name));
+    }
+
+    let version_string = get_string(enums::StringName::Version);
+    if version_string.is_empty() {
+        return Err(LoadError::NoContext);
+    }
+    LOADED.store(true, Ordering::SeqCst);
+
+    Ok(GlInfo {
+        version: get_version(),
+        version_string,
+        vendor: get_string(enums::StringName::Vendor),
+        renderer: get_string(enums::StringName::Renderer),
+        shading_language_version: get_string(enums::StringName::ShadingLanguageVersion),
+    })
+}
+
+/// Whether the GL functions are loaded, by `load_with` or by `gl::load_with`
+pub fn is_loaded() -> bool {
+    LOADED.load(Ordering::Relaxed) || gl::GetError::is_loaded()
+}
+
+#[doc(hidden)]
+pub fn check_loaded(function: &'static str) {
+    if !is_loaded() {
+        panic!("{}: called before the GL functions were loaded with rgl::load_with", function);
+    }
+}
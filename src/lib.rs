@@ -1,36 +1,93 @@
 //! # Rusty GL
 //! `rusty_gl` is a wrapper over the C-binding for OpenGL for Rust, gl-rs
 //! The aim of this crate is:
-//!    1. Make OpenGL in Rust easier to use
+//! 1. Make OpenGL in Rust easier to use
 //!
-//!         This has been done by hiding weird (and unsafe!) casts from Rust code to the
-//!         C interface, as well as using rust types over std::os::raw and std::ffi types. For example,
-//!         some functions take in &str rather than std::ffi::CString.
-//!     
-//!    2. Make OpenGL in Rust safer to use
-//!         
-//!         One way I have done this is by enforcing correct enum types when passing to functions.
-//!         The C-Interface allowed for any GLenum to pass into a function where it was needed, but
-//!         it is very easy to pass the wrong one, causing `GL_INVALID_ENUM` errors.
-//!         Instead, I have made specific enum types, and then enforce those types when calling rusty-gl
-//!         functions
-//!         
-//!    3. Make OpenGL in Rust fit along nicely with other Rust code
+//!    This has been done by hiding weird (and unsafe!) casts from Rust code to the
+//!    C interface, as well as using rust types over std::os::raw and std::ffi types. For example,
+//!    some functions take in &str rather than std::ffi::CString.
 //!
-//!         The C interface had camelCase interface, as well as requiring `unsafe` blocks everywhere.
-//!         Rusty GL functions use the more Rust accepted `snake_case` for functions and `PascalCase`
-//!         for types. Also, none of the functions require `unsafe {}` blocks to be used.
+//! 2. Make OpenGL in Rust safer to use
+//!
+//!    One way I have done this is by enforcing correct enum types when passing to functions.
+//!    The C-Interface allowed for any GLenum to pass into a function where it was needed, but
+//!    it is very easy to pass the wrong one, causing `GL_INVALID_ENUM` errors.
+//!    Instead, I have made specific enum types, and then enforce those types when calling rusty-gl
+//!    functions
+//!
+//! 3. Make OpenGL in Rust fit along nicely with other Rust code
+//!
+//!    The C interface had camelCase interface, as well as requiring `unsafe` blocks everywhere.
+//!    Rusty GL functions use the more Rust accepted `snake_case` for functions and `PascalCase`
+//!    for types. Also, none of the functions require `unsafe {}` blocks to be used.
+
+// Most wrappers mirror the argument list of the GL entry point they wrap
+#![allow(clippy::too_many_arguments)]
 
 extern crate gl;
 
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "image")]
+extern crate image;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+
+#[macro_use]
+mod macros;
+
 pub mod buffers;
+pub mod cache;
+pub mod compute;
+pub mod context;
+pub mod debug;
 pub mod drawing;
+#[cfg(not(feature = "gles2"))]
+pub mod dsa;
 pub mod enums;
+pub mod errors;
+pub mod framebuffers;
+pub mod info;
+pub mod owned;
+pub mod queries;
+pub mod samplers;
 pub mod shaders;
+pub mod state;
+#[cfg(not(feature = "gles2"))]
+pub mod streaming;
+pub mod sync;
 pub mod textures;
+pub mod transform_feedback;
+pub mod vertex;
 
 pub use buffers::*;
+pub use compute::*;
+pub use debug::*;
 pub use drawing::*;
+#[cfg(not(feature = "gles2"))]
+pub use dsa::*;
 pub use enums::*;
+pub use errors::*;
+pub use framebuffers::*;
+pub use info::*;
+pub use queries::*;
+pub use samplers::*;
 pub use shaders::*;
+pub use state::*;
+#[cfg(not(feature = "gles2"))]
+pub use streaming::*;
+pub use sync::*;
 pub use textures::*;
+pub use transform_feedback::*;
+pub use vertex::*;
+
+//Items used by the exported macros
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub mod __private {
+    pub use bytemuck::{Pod, Zeroable};
+}
@@ -0,0 +1,203 @@
+/// Calls into GL, all wrappers route through this so that they pick up the debug features
+///
+/// With the `error-check` feature enabled, `glGetError` is checked after the call and any errors
+/// are reported with the name of the wrapper, see `set_error_callback`. With `trace-calls`, the
+/// call is reported with its arguments before it is made, see `set_call_hook`. Debug builds check
+/// that the functions were loaded first
+#[cfg(not(feature = "trace-calls"))]
+macro_rules! gl_call {
+    ($name:ident, $call:expr) => {{
+        #[cfg(debug_assertions)]
+        $crate::info::check_loaded(stringify!($name));
+        let result = unsafe { $call };
+        #[cfg(feature = "error-check")]
+        $crate::errors::check_errors(stringify!($name));
+        result
+    }};
+}
+
+#[cfg(feature = "trace-calls")]
+macro_rules! gl_call {
+    ($name:ident, $($function:ident)::+($($args:tt)*)) => {
+        gl_call!(@split $name, [$($function)::+], [], [], $($args)*)
+    };
+    //Splits the arguments at the top level commas, so each can be matched as tokens below
+    (@split $name:ident, $function:tt, [$($arg:tt)*], [$($args:tt)*], , $($rest:tt)*) => {
+        gl_call!(@split $name, $function, [], [$($args)* [$($arg)*]], $($rest)*)
+    };
+    (@split $name:ident, $function:tt, [$($arg:tt)*], [$($args:tt)*], $token:tt $($rest:tt)*) => {
+        gl_call!(@split $name, $function, [$($arg)* $token], [$($args)*], $($rest)*)
+    };
+    (@split $name:ident, $function:tt, [], [$($args:tt)*],) => {
+        gl_call!(@bind $name, $function, [$($args)*], [], [
+            a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15
+        ])
+    };
+    (@split $name:ident, $function:tt, [$($arg:tt)+], [$($args:tt)*],) => {
+        gl_call!(@split $name, $function, [], [$($args)* [$($arg)+]],)
+    };
+    //Gives each argument a name, so it can be both reported and passed on after being evaluated
+    //once. Enums cast to GL types and the handles and bitfields unwrapped to their raw values are
+    //reported as the typed value the wrapper was given
+    (@bind $name:ident, $function:tt, [[$($value:ident)::+ as $type_:ty] $($rest:tt)*], [$($bound:tt)*], [$next:ident $($names:ident)*]) => {
+        gl_call!(@bind $name, $function, [$($rest)*], [$($bound)* [$next = ($($value)::+) => ($next as $type_)]], [$($names)*])
+    };
+    (@bind $name:ident, $function:tt, [[$value:ident . 0] $($rest:tt)*], [$($bound:tt)*], [$next:ident $($names:ident)*]) => {
+        gl_call!(@bind $name, $function, [$($rest)*], [$($bound)* [$next = ($value) => ($next.0)]], [$($names)*])
+    };
+    (@bind $name:ident, $function:tt, [[$value:ident . bits()] $($rest:tt)*], [$($bound:tt)*], [$next:ident $($names:ident)*]) => {
+        gl_call!(@bind $name, $function, [$($rest)*], [$($bound)* [$next = ($value) => ($next.bits())]], [$($names)*])
+    };
+    (@bind $name:ident, $function:tt, [[$($arg:tt)*] $($rest:tt)*], [$($bound:tt)*], [$next:ident $($names:ident)*]) => {
+        gl_call!(@bind $name, $function, [$($rest)*], [$($bound)* [$next = ($($arg)*) => ($next)]], [$($names)*])
+    };
+    (@bind $name:ident, [$($function:ident)::+], [], [$([$bound:ident = ($($value:tt)*) => ($($pass:tt)*)])*], [$($names:ident)*]) => {{
+        #[cfg(debug_assertions)]
+        $crate::info::check_loaded(stringify!($name));
+        //A match rather than lets, so temporaries in the arguments live until the call like before
+        let result = unsafe {
+            match ($($($value)*,)*) {
+                ($($bound,)*) => {
+                    $crate::debug::trace_call(stringify!($name), &[$(&$bound),*]);
+                    $($function)::+($($($pass)*),*)
+                }
+            }
+        };
+        #[cfg(feature = "error-check")]
+        $crate::errors::check_errors(stringify!($name));
+        result
+    }};
+}
+
+/// Defines an enum of GL tokens, along with conversions to and from raw `GLenum`s
+///
+/// Converting an unknown value fails with `UnknownEnum`. The documentation of the conversion holds
+/// a round trip test of every variant
+macro_rules! gl_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident = $value:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(u32)]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant = $value,
+            )*
+        }
+
+        impl From<$name> for ::gl::types::GLenum {
+            fn from(value: $name) -> ::gl::types::GLenum {
+                value as ::gl::types::GLenum
+            }
+        }
+
+        #[doc = concat!(
+            "Converts a raw token, failing for values that are not a variant of `", stringify!($name), "`\n\n",
+            "# Examples\n",
+            "```rust\n",
+            "extern crate rgl;\n\n",
+            "use rgl::", stringify!($name), ";\n",
+            "use std::convert::TryFrom;\n\n",
+            $(
+                "assert!(matches!(", stringify!($name), "::try_from(", stringify!($name), "::", stringify!($variant),
+                " as u32), Ok(", stringify!($name), "::", stringify!($variant), ")));\n",
+            )*
+            "assert!(", stringify!($name), "::try_from(0xFFFF_FFFF).is_err());\n",
+            "```",
+        )]
+        impl ::std::convert::TryFrom<::gl::types::GLenum> for $name {
+            type Error = $crate::errors::UnknownEnum;
+
+            fn try_from(value: ::gl::types::GLenum) -> Result<$name, $crate::errors::UnknownEnum> {
+                match value {
+                    $(
+                        value if value == $name::$variant as ::gl::types::GLenum => Ok($name::$variant),
+                    )*
+                    value => Err($crate::errors::UnknownEnum {
+                        name: stringify!($name),
+                        value,
+                    }),
+                }
+            }
+        }
+    };
+}
+
+/// Defines a bitfield newtype with named flags that can be combined with `|`
+macro_rules! gl_bitfield {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$flag_attr:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $name(pub ::gl::types::GLbitfield);
+
+        impl $name {
+            $(
+                $(#[$flag_attr])*
+                pub const $flag: $name = $name($value);
+            )*
+
+            /// No flags set
+            pub const fn empty() -> $name {
+                $name(0)
+            }
+
+            /// The raw bits of the flags
+            pub const fn bits(self) -> ::gl::types::GLbitfield {
+                self.0
+            }
+
+            /// Combination of the flags of `self` and `other`, usable in constants
+            pub const fn union(self, other: $name) -> $name {
+                $name(self.0 | other.0)
+            }
+
+            /// Whether every flag of `other` is also set in `self`
+            pub const fn contains(self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Whether no flags are set
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl ::std::ops::BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, other: $name) -> $name {
+                self.union(other)
+            }
+        }
+
+        impl ::std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, other: $name) {
+                self.0 |= other.0;
+            }
+        }
+
+        impl ::std::ops::BitAnd for $name {
+            type Output = $name;
+
+            fn bitand(self, other: $name) -> $name {
+                $name(self.0 & other.0)
+            }
+        }
+    };
+}
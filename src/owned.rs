@@ -0,0 +1,132 @@
+//! Handles that delete their GL object when dropped
+//!
+//! The handles in the rest of the crate are `Copy` and never delete anything by themselves, which
+//! makes it easy to leak objects, for example when returning early with `?`. The types here own
+//! a handle instead. They can be passed by reference to the bind functions, such as `bind_buffer`,
+//! and dereference to the handle for the free functions taking it directly.
+//!
+//! Dropping an owned handle calls into GL, so it must happen while the context that created the
+//! object is current on the dropping thread.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use rgl::owned::{OwnedBuffer, OwnedVao};
+//!
+//! fn setup(vertices: &[f32]) -> Result<(OwnedVao, OwnedBuffer), rgl::ShaderError> {
+//!     let vao = OwnedVao::from_raw(rgl::gen_vertex_array());
+//!     let vbo = OwnedBuffer::from_raw(rgl::gen_buffer());
+//!
+//!     rgl::bind_vertex_array(&vao);
+//!     rgl::bind_buffer(rgl::Target::ArrayBuffer, &vbo);
+//!     rgl::buffer_data(rgl::Target::ArrayBuffer, vertices, rgl::Usage::StaticDraw);
+//!
+//!     //If this fails, both the VAO and the buffer are deleted
+//!     let shader = rgl::create_shader(rgl::ShaderType::Vertex);
+//!     rgl::compile_shader_checked(shader)?;
+//!
+//!     Ok((vao, vbo))
+//! }
+//! ```
+
+use super::buffers::{self, Buffer, VAO};
+use super::framebuffers::{self, Framebuffer, Renderbuffer};
+use super::queries::{self, Query};
+use super::samplers::{self, Sampler};
+use super::shaders::{self, Program, ProgramPipeline, Shader};
+use super::textures::{self, Texture};
+use super::transform_feedback::{self, TransformFeedback};
+
+use std::mem;
+use std::ops::Deref;
+
+macro_rules! owned_handle {
+    ($(#[$attr:meta])* $owned:ident, $raw:ident, $delete:path) => {
+        $(#[$attr])*
+        pub struct $owned($raw);
+
+        impl $owned {
+            /// Takes ownership of a handle, it is deleted when the returned value is dropped
+            pub fn from_raw(handle: $raw) -> $owned {
+                $owned(handle)
+            }
+
+            /// Gives up ownership of the handle without deleting it
+            pub fn into_raw(self) -> $raw {
+                let handle = self.0;
+                mem::forget(self);
+                handle
+            }
+        }
+
+        impl Deref for $owned {
+            type Target = $raw;
+
+            fn deref(&self) -> &$raw {
+                &self.0
+            }
+        }
+
+        impl AsRef<$raw> for $owned {
+            fn as_ref(&self) -> &$raw {
+                &self.0
+            }
+        }
+
+        impl<'a> From<&'a $owned> for $raw {
+            fn from(owned: &'a $owned) -> $raw {
+                owned.0
+            }
+        }
+
+        impl Drop for $owned {
+            fn drop(&mut self) {
+                $delete(self.0);
+            }
+        }
+    };
+}
+
+owned_handle!(
+    /// A `Buffer` deleted with `delete_buffer` when dropped
+    OwnedBuffer, Buffer, buffers::delete_buffer
+);
+owned_handle!(
+    /// A `VAO` deleted with `delete_vertex_array` when dropped
+    OwnedVao, VAO, buffers::delete_vertex_array
+);
+owned_handle!(
+    /// A `Texture` deleted with `delete_texture` when dropped
+    OwnedTexture, Texture, textures::delete_texture
+);
+owned_handle!(
+    /// A `Sampler` deleted with `delete_sampler` when dropped
+    OwnedSampler, Sampler, samplers::delete_sampler
+);
+owned_handle!(
+    /// A `Shader` deleted with `delete_shader` when dropped
+    OwnedShader, Shader, shaders::delete_shader
+);
+owned_handle!(
+    /// A `Program` deleted with `delete_program` when dropped
+    OwnedProgram, Program, shaders::delete_program
+);
+owned_handle!(
+    /// A `ProgramPipeline` deleted with `delete_program_pipeline` when dropped
+    OwnedProgramPipeline, ProgramPipeline, shaders::delete_program_pipeline
+);
+owned_handle!(
+    /// A `Framebuffer` deleted with `delete_framebuffer` when dropped
+    OwnedFramebuffer, Framebuffer, framebuffers::delete_framebuffer
+);
+owned_handle!(
+    /// A `Renderbuffer` deleted with `delete_renderbuffer` when dropped
+    OwnedRenderbuffer, Renderbuffer, framebuffers::delete_renderbuffer
+);
+owned_handle!(
+    /// A `Query` deleted with `delete_query` when dropped
+    OwnedQuery, Query, queries::delete_query
+);
+owned_handle!(
+    /// A `TransformFeedback` deleted with `delete_transform_feedback` when dropped
+    OwnedTransformFeedback, TransformFeedback, transform_feedback::delete_transform_feedback
+);
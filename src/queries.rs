@@ -0,0 +1,269 @@
+use super::enums;
+
+use gl;
+use gl::types::*;
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct Query(pub GLuint);
+
+/// Generates query objects, one for every element of the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenQueries.xhtml
+pub fn gen_queries(queries: &mut [Query]) {
+    gl_call!(gen_queries, gl::GenQueries(queries.len() as GLsizei, queries.as_mut_ptr() as *mut GLuint));
+}
+
+/// Generates a single query object
+pub fn gen_query() -> Query {
+    let mut query = [Query(0)];
+    gen_queries(&mut query);
+    query[0]
+}
+
+/// Generates `count` query objects with a single call, see `gen_queries`
+///
+/// A `count` of 0 returns an empty Vec without calling GL
+pub fn gen_queries_vec(count: usize) -> Vec<Query> {
+    let mut queries = vec![Query(0); count];
+    if count > 0 {
+        gen_queries(&mut queries);
+    }
+    queries
+}
+
+/// Deletes every query object in the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteQueries.xhtml
+pub fn delete_queries(queries: &[Query]) {
+    gl_call!(delete_queries, gl::DeleteQueries(queries.len() as GLsizei, queries.as_ptr() as *const GLuint));
+}
+
+pub fn delete_query(query: Query) {
+    delete_queries(&[query]);
+}
+
+/// Whether `query` names a query object, which only happens once it has been used with `begin_query`
+/// or `query_counter`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsQuery.xhtml
+pub fn is_query(query: Query) -> bool {
+    gl_call!(is_query, gl::IsQuery(query.0)) == gl::TRUE
+}
+
+/// Starts measuring `target` with `query`, until the matching `end_query`
+///
+/// # Examples
+/// ```rust,no_run
+/// //Occlusion culling: draw the portal's bounds, and draw the room behind it only if any of
+/// //them were visible. The result is read a frame later so waiting on it never stalls
+/// let query = rgl::gen_query();
+/// let mut room_visible = true;
+///
+/// loop {
+///     if rgl::query_result_available(query) {
+///         room_visible = rgl::get_query_result(query) != 0;
+///     }
+///
+///     if room_visible {
+///         //...draw the room
+///     }
+///
+///     rgl::depth_mask(false);
+///     rgl::begin_query(rgl::QueryTarget::AnySamplesPassedConservative, query);
+///     //...draw the portal's bounds
+///     rgl::end_query(rgl::QueryTarget::AnySamplesPassedConservative);
+///     rgl::depth_mask(true);
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBeginQuery.xhtml
+pub fn begin_query(target: enums::QueryTarget, query: Query) {
+    gl_call!(begin_query, gl::BeginQuery(target as GLenum, query.0));
+}
+
+/// Ends the active query of `target`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBeginQuery.xhtml
+pub fn end_query(target: enums::QueryTarget) {
+    gl_call!(end_query, gl::EndQuery(target as GLenum));
+}
+
+/// Whether the result of `query` can be read without waiting for the GPU
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetQueryObject.xhtml
+pub fn query_result_available(query: Query) -> bool {
+    let mut available = 0;
+    gl_call!(query_result_available, gl::GetQueryObjectuiv(query.0, gl::QUERY_RESULT_AVAILABLE, &mut available));
+    available == gl::TRUE as GLuint
+}
+
+/// Draws the commands until `end_conditional_render` only if the occlusion query `query` found
+/// samples that passed, without reading the result back to the CPU
+///
+/// `query` must have ended, and been a `SamplesPassed` or `AnySamplesPassed*` query
+///
+/// # Examples
+/// ```rust,no_run
+/// //Occlusion culling without stalls or a frame of latency: draw the cheap bounding box into the
+/// //query, and let the GPU skip the expensive mesh if none of it was visible
+/// let query = rgl::gen_query();
+///
+/// rgl::color_mask(false, false, false, false);
+/// rgl::depth_mask(false);
+/// rgl::begin_query(rgl::QueryTarget::AnySamplesPassed, query);
+/// //...draw the mesh's bounding box
+/// rgl::end_query(rgl::QueryTarget::AnySamplesPassed);
+/// rgl::color_mask(true, true, true, true);
+/// rgl::depth_mask(true);
+///
+/// rgl::begin_conditional_render(query, rgl::ConditionalRenderMode::QueryByRegionWait);
+/// //...draw the mesh
+/// rgl::end_conditional_render();
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBeginConditionalRender.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn begin_conditional_render(query: Query, mode: enums::ConditionalRenderMode) {
+    gl_call!(begin_conditional_render, gl::BeginConditionalRender(query.0, mode as GLenum));
+}
+
+/// Ends the conditional rendering started with `begin_conditional_render`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBeginConditionalRender.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn end_conditional_render() {
+    gl_call!(end_conditional_render, gl::EndConditionalRender());
+}
+
+/// Returns the result of `query` as a u32, enough for sample and primitive counts
+///
+/// Waits for the GPU like `get_query_result_u64`, use that for `TimeElapsed` and timestamps, which
+/// can overflow a u32
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetQueryObject.xhtml
+pub fn get_query_result(query: Query) -> GLuint {
+    let mut result = 0;
+    gl_call!(get_query_result, gl::GetQueryObjectuiv(query.0, gl::QUERY_RESULT, &mut result));
+    result
+}
+
+/// Returns the result of `query`
+///
+/// Waits for the GPU to finish the queried commands if the result is not yet available, which
+/// stalls the pipeline, so check `query_result_available` first
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetQueryObject.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn get_query_result_u64(query: Query) -> u64 {
+    let mut result = 0;
+    gl_call!(get_query_result_u64, gl::GetQueryObjectui64v(query.0, gl::QUERY_RESULT, &mut result));
+    result
+}
+
+/// Records the GPU time in nanoseconds into `query` once all previous commands have completed
+///
+/// The result is read like any other query's, with `get_query_result_u64`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glQueryCounter.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn query_counter(query: Query) {
+    gl_call!(query_counter, gl::QueryCounter(query.0, gl::TIMESTAMP));
+}
+
+/// Measures the GPU time of frames without stalling, using a ring of timestamp query pairs
+///
+/// Results arrive a few frames late, and frames whose results have not arrived by the time the ring
+/// wraps around are dropped, so size the ring to the number of frames the GPU may lag behind
+///
+/// # Examples
+/// ```rust,no_run
+/// let mut timer = rgl::GpuTimer::new(4);
+///
+/// loop {
+///     timer.begin_frame();
+///     //...draw the frame
+///     timer.end_frame();
+///
+///     if let Some(ms) = timer.latest_ms() {
+///         println!("GPU frame time: {:.2}ms", ms);
+///     }
+/// }
+/// ```
+#[cfg(not(feature = "gles2"))]
+pub struct GpuTimer {
+    queries: Vec<(Query, Query)>,
+    pending: Vec<bool>,
+    current: usize,
+    latest: Option<f64>,
+}
+
+#[cfg(not(feature = "gles2"))]
+impl GpuTimer {
+    /// Creates a timer with a ring of `frames` query pairs
+    ///
+    /// # Panics
+    /// If `frames` is 0
+    pub fn new(frames: usize) -> GpuTimer {
+        assert!(frames > 0, "GpuTimer needs at least one frame");
+
+        let mut queries = vec![Query(0); frames * 2];
+        gen_queries(&mut queries);
+
+        GpuTimer {
+            queries: queries.chunks(2).map(|pair| (pair[0], pair[1])).collect(),
+            pending: vec![false; frames],
+            current: 0,
+            latest: None,
+        }
+    }
+
+    /// Records the start of a frame
+    pub fn begin_frame(&mut self) {
+        self.pending[self.current] = false;
+        query_counter(self.queries[self.current].0);
+    }
+
+    /// Records the end of the frame started with `begin_frame`
+    pub fn end_frame(&mut self) {
+        query_counter(self.queries[self.current].1);
+        self.pending[self.current] = true;
+        self.current = (self.current + 1) % self.queries.len();
+    }
+
+    /// GPU time of the most recent frame whose results have arrived, in milliseconds
+    ///
+    /// Only reads results that are available, so this never stalls
+    pub fn latest_ms(&mut self) -> Option<f64> {
+        let frames = self.queries.len();
+        let oldest = self.current;
+
+        //Oldest frame first, as the GPU finishes frames in order
+        for i in (0..frames).map(|i| (oldest + i) % frames) {
+            if !self.pending[i] {
+                continue;
+            }
+
+            let (start, end) = self.queries[i];
+            if !query_result_available(end) {
+                break;
+            }
+
+            let nanoseconds = get_query_result_u64(end).saturating_sub(get_query_result_u64(start));
+            self.latest = Some(nanoseconds as f64 / 1_000_000.0);
+            self.pending[i] = false;
+        }
+
+        self.latest
+    }
+
+    /// Deletes the timer's query objects
+    pub fn delete(self) {
+        let mut queries = Vec::with_capacity(self.queries.len() * 2);
+        for &(start, end) in &self.queries {
+            queries.push(start);
+            queries.push(end);
+        }
+        delete_queries(&queries);
+    }
+}
@@ -0,0 +1,212 @@
+use super::enums;
+use super::textures;
+
+use gl;
+use gl::types::*;
+
+/// Sampling state that overrides the state of the textures bound to the same unit
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct Sampler(pub GLuint);
+
+impl Sampler {
+    /// The name 0, which binds no sampler
+    pub const NONE: Sampler = Sampler(0);
+}
+
+/// Generates sampler objects, one for every element of the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenSamplers.xhtml
+pub fn gen_samplers(samplers: &mut [Sampler]) {
+    gl_call!(gen_samplers, gl::GenSamplers(samplers.len() as GLsizei, samplers.as_mut_ptr() as *mut GLuint));
+}
+
+/// Generates a single sampler
+pub fn gen_sampler() -> Sampler {
+    let mut sampler = [Sampler(0)];
+    gen_samplers(&mut sampler);
+    sampler[0]
+}
+
+/// Generates `count` sampler objects with a single call, see `gen_samplers`
+///
+/// A `count` of 0 returns an empty Vec without calling GL
+pub fn gen_samplers_vec(count: usize) -> Vec<Sampler> {
+    let mut samplers = vec![Sampler(0); count];
+    if count > 0 {
+        gen_samplers(&mut samplers);
+    }
+    samplers
+}
+
+/// Creates sampler objects, one for every element of the slice
+///
+/// Unlike `gen_samplers`, the objects exist straight away without binding them first.
+/// Requires GL 4.5 or `ARB_direct_state_access`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateSamplers.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn create_samplers(samplers: &mut [Sampler]) {
+    gl_call!(create_samplers, gl::CreateSamplers(samplers.len() as GLsizei, samplers.as_mut_ptr() as *mut GLuint));
+}
+
+/// Creates a single sampler, see `create_samplers`
+#[cfg(not(feature = "gles2"))]
+pub fn create_sampler() -> Sampler {
+    let mut sampler = [Sampler(0)];
+    create_samplers(&mut sampler);
+    sampler[0]
+}
+
+/// Deletes every sampler object in the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteSamplers.xhtml
+pub fn delete_samplers(samplers: &[Sampler]) {
+    gl_call!(delete_samplers, gl::DeleteSamplers(samplers.len() as GLsizei, samplers.as_ptr() as *const GLuint));
+}
+
+pub fn delete_sampler(sampler: Sampler) {
+    delete_samplers(&[sampler]);
+}
+
+/// Whether `sampler` names a sampler object, see `is_buffer`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsSampler.xhtml
+pub fn is_sampler(sampler: Sampler) -> bool {
+    gl_call!(is_sampler, gl::IsSampler(sampler.0)) == gl::TRUE
+}
+
+/// Binds a sampler to a texture unit, `Sampler(0)` restores the texture's own sampling state
+///
+/// # Examples
+/// ```rust,no_run
+/// //Sample the same texture both pixelated and smoothed
+/// let nearest = rgl::gen_sampler();
+/// rgl::sampler_min_filter(nearest, rgl::MinFilter::Nearest);
+/// rgl::sampler_mag_filter(nearest, rgl::MagFilter::Nearest);
+///
+/// let linear = rgl::gen_sampler();
+/// rgl::sampler_min_filter(linear, rgl::MinFilter::Linear);
+/// rgl::sampler_mag_filter(linear, rgl::MagFilter::Linear);
+///
+/// let texture = rgl::gen_texture();
+/// rgl::bind_texture_unit(0, rgl::TextureTarget::Texture2d, texture);
+/// rgl::bind_texture_unit(1, rgl::TextureTarget::Texture2d, texture);
+/// rgl::bind_sampler(0, nearest);
+/// rgl::bind_sampler(1, linear);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindSampler.xhtml
+pub fn bind_sampler<H: Into<Sampler>>(unit: GLuint, sampler: H) {
+    let sampler: Sampler = sampler.into();
+    gl_call!(bind_sampler, gl::BindSampler(unit, sampler.0));
+}
+
+/// Unbinds the sampler of texture unit `unit`, so the unit samples with its texture's own
+/// parameters again
+pub fn unbind_sampler(unit: GLuint) {
+    gl_call!(unbind_sampler, gl::BindSampler(unit, 0));
+}
+
+/// Binds samplers to consecutive texture units starting at `first_unit`, `None` unbinds that unit
+///
+/// Uses a single `glBindSamplers` call on GL 4.4 and `ARB_multi_bind`, and falls back to a
+/// `bind_sampler` per unit otherwise
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindSamplers.xhtml
+pub fn bind_samplers(first_unit: GLuint, samplers: &[Option<Sampler>]) {
+    if !gl::BindSamplers::is_loaded() {
+        for (unit, sampler) in (first_unit..).zip(samplers) {
+            bind_sampler(unit, sampler.unwrap_or(Sampler(0)));
+        }
+        return;
+    }
+
+    let names: Vec<GLuint> = samplers.iter().map(|sampler| sampler.map_or(0, |sampler| sampler.0)).collect();
+    gl_call!(bind_samplers, gl::BindSamplers(first_unit, names.len() as GLsizei, names.as_ptr()));
+}
+
+/// Sets an integer parameter of a sampler
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml
+pub fn sampler_parameteri(sampler: Sampler, param_name: enums::TextureParameter, param: GLint) {
+    gl_call!(sampler_parameteri, gl::SamplerParameteri(sampler.0, param_name as GLenum, param));
+}
+
+/// Sets a float parameter of a sampler
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glSamplerParameter.xhtml
+pub fn sampler_parameterf(sampler: Sampler, param_name: enums::TextureParameter, param: GLfloat) {
+    gl_call!(sampler_parameterf, gl::SamplerParameterf(sampler.0, param_name as GLenum, param));
+}
+
+/// Sets the minifying filter of a sampler
+pub fn sampler_min_filter(sampler: Sampler, filter: enums::MinFilter) {
+    sampler_parameteri(sampler, enums::TextureParameter::MinFilter, filter as GLint);
+}
+
+/// Sets the magnification filter of a sampler
+pub fn sampler_mag_filter(sampler: Sampler, filter: enums::MagFilter) {
+    sampler_parameteri(sampler, enums::TextureParameter::MagFilter, filter as GLint);
+}
+
+/// Sets the anisotropy of a sampler, like `tex_max_anisotropy`
+///
+/// # Panics
+/// If `anisotropy` is less than 1.0
+pub fn sampler_max_anisotropy(sampler: Sampler, anisotropy: GLfloat) {
+    assert!(anisotropy >= 1.0, "anisotropy must be at least 1.0, got {}", anisotropy);
+    if textures::supports_anisotropy() {
+        sampler_parameterf(sampler, enums::TextureParameter::MaxAnisotropy, anisotropy);
+    }
+}
+
+/// Sets the wrap mode of the s (x) texture coordinate of a sampler
+pub fn sampler_wrap_s(sampler: Sampler, mode: enums::WrapMode) {
+    sampler_parameteri(sampler, enums::TextureParameter::WrapS, mode as GLint);
+}
+
+/// Sets the wrap mode of the t (y) texture coordinate of a sampler
+pub fn sampler_wrap_t(sampler: Sampler, mode: enums::WrapMode) {
+    sampler_parameteri(sampler, enums::TextureParameter::WrapT, mode as GLint);
+}
+
+/// Sets the wrap mode of the r (z) texture coordinate of a sampler
+pub fn sampler_wrap_r(sampler: Sampler, mode: enums::WrapMode) {
+    sampler_parameteri(sampler, enums::TextureParameter::WrapR, mode as GLint);
+}
+
+/// Sets the colour sampled outside of the texture when wrapping with `WrapMode::ClampToBorder`
+pub fn sampler_border_color(sampler: Sampler, color: [GLfloat; 4]) {
+    gl_call!(sampler_border_color, gl::SamplerParameterfv(
+        sampler.0,
+        enums::TextureParameter::BorderColor as GLenum,
+        color.as_ptr()
+    ));
+}
+
+/// Sets whether depth textures sampled through a sampler are compared against a reference value
+pub fn sampler_compare_mode(sampler: Sampler, mode: enums::CompareMode) {
+    sampler_parameteri(sampler, enums::TextureParameter::CompareMode, mode as GLint);
+}
+
+/// Sets the comparison used with `CompareMode::CompareRefToTexture`
+pub fn sampler_compare_func(sampler: Sampler, func: enums::CompareFunc) {
+    sampler_parameteri(sampler, enums::TextureParameter::CompareFunc, func as GLint);
+}
+
+/// Sets the lowest level of detail a sampler selects
+pub fn sampler_min_lod(sampler: Sampler, lod: GLfloat) {
+    sampler_parameterf(sampler, enums::TextureParameter::MinLod, lod);
+}
+
+/// Sets the highest level of detail a sampler selects
+pub fn sampler_max_lod(sampler: Sampler, lod: GLfloat) {
+    sampler_parameterf(sampler, enums::TextureParameter::MaxLod, lod);
+}
+
+/// Sets the bias a sampler adds to the level of detail, positive values select blurrier mip levels
+#[cfg(not(feature = "gles2"))]
+pub fn sampler_lod_bias(sampler: Sampler, bias: GLfloat) {
+    sampler_parameterf(sampler, enums::TextureParameter::LodBias, bias);
+}
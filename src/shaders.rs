@@ -1,275 +1,1957 @@
 use super::enums;
+use super::errors::Error;
 
 use gl;
 use gl::types::*;
-use std::ffi::{CStr, CString};
-use std::ptr;
+use std::error;
+use std::ffi::{CString, NulError};
+use std::fmt;
+use std::fs;
+use std::mem;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-#[derive(Clone, Copy)]
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
 pub struct Shader(pub GLuint);
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct Program(pub GLuint);
 
-#[derive(Clone, Copy)]
+impl Program {
+    /// The name 0, which binds no program
+    pub const NONE: Program = Program(0);
+}
+
+#[derive(Clone, Copy, Debug)]
 pub struct UniformLocation(pub GLint);
 
+#[derive(Clone, Copy, Debug)]
+pub struct BlockIndex(pub GLuint);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct ProgramPipeline(pub GLuint);
+
+impl ProgramPipeline {
+    /// The name 0, which binds no program pipeline
+    pub const NONE: ProgramPipeline = ProgramPipeline(0);
+}
+
+/// Error returned by the checked shader functions, carrying the driver's info log
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShaderError {
+    /// A shader failed to compile
+    Compile(String),
+    /// A program failed to link
+    Link(String),
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ShaderError::Compile(ref log) => write!(f, "shader failed to compile: {}", log),
+            ShaderError::Link(ref log) => write!(f, "program failed to link: {}", log),
+        }
+    }
+}
+
+impl error::Error for ShaderError {}
+
+/// Why `resolve_includes` could not expand an `#include`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IncludeErrorKind {
+    /// The resolver has no file of this name
+    NotFound(String),
+    /// The file includes itself, directly or through the files it includes
+    Cycle(String),
+    /// Including this file would nest includes deeper than `MAX_INCLUDE_DEPTH`
+    TooDeep(String),
+    /// The line is not of the form `#include "name"`
+    Malformed,
+}
+
+/// Error returned by `resolve_includes`, with the location of the failing `#include`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncludeError {
+    /// The file containing the `#include`, `None` for the source passed to `resolve_includes`
+    pub file: Option<String>,
+    /// The line of the `#include`, starting at 1
+    pub line: usize,
+    pub kind: IncludeErrorKind,
+}
+
+impl fmt::Display for IncludeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.file.as_ref().map_or("source", |file| file.as_str()), self.line)?;
+        match self.kind {
+            IncludeErrorKind::NotFound(ref name) => write!(f, "included file \"{}\" not found", name),
+            IncludeErrorKind::Cycle(ref name) => write!(f, "\"{}\" includes itself", name),
+            IncludeErrorKind::TooDeep(ref name) => write!(f, "including \"{}\" nests includes too deep", name),
+            IncludeErrorKind::Malformed => write!(f, "expected #include \"name\""),
+        }
+    }
+}
+
+impl error::Error for IncludeError {}
+
+/// Creates an empty program object that shaders can be attached to
+///
+/// # Examples
+/// ```rust,no_run
+/// let vertex = rgl::create_shader(rgl::ShaderType::Vertex);
+/// let fragment = rgl::create_shader(rgl::ShaderType::Fragment);
+/// //...
+/// let program = rgl::create_program();
+/// rgl::attach_shader(program, vertex);
+/// rgl::attach_shader(program, fragment);
+/// rgl::link_program(program);
+///
+/// rgl::detach_shader(program, vertex);
+/// rgl::detach_shader(program, fragment);
+/// rgl::delete_shader(vertex);
+/// rgl::delete_shader(fragment);
+///
+/// rgl::use_program(program);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateProgram.xhtml
 pub fn create_program() -> Program {
-    unsafe { Program(gl::CreateProgram()) }
+    Program(gl_call!(create_program, gl::CreateProgram()))
 }
 
+/// Creates an empty shader object of the given stage
+///
+/// # Examples
+/// ```rust,no_run
+/// let shader = rgl::create_shader(rgl::ShaderType::Vertex);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateShader.xhtml
 pub fn create_shader(type_: enums::ShaderType) -> Shader {
-    unsafe { Shader(gl::CreateShader(type_ as GLenum)) }
+    Shader(gl_call!(create_shader, gl::CreateShader(type_ as GLenum)))
 }
 
-/// TODO: MISSING TWO PARAMS (to do with count)
+/// Replaces the source code of a shader
+///
+/// The length of `source` is passed along with it, so it does not need to be NUL terminated
+///
+/// # Examples
+/// ```rust,no_run
+/// let source = "#version 330 core
+/// layout (location = 0) in vec3 position;
+///
+/// void main() {
+///     gl_Position = vec4(position, 1.0);
+/// }";
+///
+/// let shader = rgl::create_shader(rgl::ShaderType::Vertex);
+/// rgl::shader_source(shader, source);
+/// rgl::compile_shader(shader);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glShaderSource.xhtml
 pub fn shader_source(shader: Shader, source: &str) {
-    unsafe {
-        gl::ShaderSource(
-            shader.0,
-            1,
-            &CString::new(source).unwrap().as_ptr(),
-            ptr::null(),
-        );
-    }
+    gl_call!(shader_source, gl::ShaderSource(
+        shader.0,
+        1,
+        &(source.as_ptr() as *const GLchar),
+        &(source.len() as GLint),
+    ));
 }
 
+/// Replaces the source code of a shader with the concatenation of `sources`
+///
+/// Saves joining the strings to prepend a `#version` line or `#define`s to a shared body. GL copies
+/// the strings before returning, and their lengths are passed along, so none of them need to be NUL
+/// terminated
+///
+/// # Examples
+/// ```rust
+/// extern crate gl;
+/// extern crate rgl;
+///
+/// use gl::types::*;
+/// use std::os::raw::c_void;
+/// use std::ptr;
+/// use std::sync::Mutex;
+///
+/// static SOURCE: Mutex<String> = Mutex::new(String::new());
+///
+/// extern "system" fn shader_source(_shader: GLuint, count: GLsizei, strings: *const *const GLchar, lengths: *const GLint) {
+///     let mut source = SOURCE.lock().unwrap();
+///     for i in 0..count as usize {
+///         let string = unsafe { std::slice::from_raw_parts(*strings.add(i) as *const u8, *lengths.add(i) as usize) };
+///         source.push_str(std::str::from_utf8(string).unwrap());
+///     }
+/// }
+///
+/// extern "system" fn get_error() -> GLenum {
+///     gl::NO_ERROR
+/// }
+///
+/// # fn main() {
+/// //Capture the source GL receives, with stand ins for the driver's functions
+/// gl::load_with(|symbol| match symbol {
+///     "glShaderSource" => shader_source as *const c_void,
+///     "glGetError" => get_error as *const c_void,
+///     _ => ptr::null(),
+/// });
+///
+/// let body = "void main() {}";
+/// let fog = true;
+/// let defines = if fog { "#define USE_FOG 1\n" } else { "" };
+///
+/// rgl::shader_source_strings(rgl::Shader(1), &["#version 330 core\n", defines, body]);
+/// assert_eq!(*SOURCE.lock().unwrap(), "#version 330 core\n#define USE_FOG 1\nvoid main() {}");
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glShaderSource.xhtml
+pub fn shader_source_strings(shader: Shader, sources: &[&str]) {
+    let pointers: Vec<*const GLchar> = sources.iter().map(|source| source.as_ptr() as *const GLchar).collect();
+    let lengths: Vec<GLint> = sources.iter().map(|source| source.len() as GLint).collect();
+
+    gl_call!(shader_source_strings, gl::ShaderSource(
+        shader.0,
+        sources.len() as GLsizei,
+        pointers.as_ptr(),
+        lengths.as_ptr(),
+    ));
+}
+
+/// Compiles the source code of a shader
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCompileShader.xhtml
 pub fn compile_shader(shader: Shader) {
-    unsafe {
-        gl::CompileShader(shader.0);
-    }
+    gl_call!(compile_shader, gl::CompileShader(shader.0));
 }
 
+/// Queries a parameter of a shader object
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetShader.xhtml
 pub fn get_shader_iv(shader: Shader, parameter: enums::ShaderInfoParam, status: &mut GLint) {
-    unsafe {
-        gl::GetShaderiv(shader.0, parameter as GLenum, status);
-    }
+    gl_call!(get_shader_iv, gl::GetShaderiv(shader.0, parameter as GLenum, status));
 }
 
-pub fn get_shader_info_log(
-    shader: Shader,
-    buffer_size: GLsizei,
-    length: &mut GLsizei,
-    info_log: &mut Vec<GLchar>,
-) {
-    unsafe {
-        gl::GetShaderInfoLog(
-            shader.0,
-            buffer_size,
-            length,
-            info_log.as_mut_ptr() as *mut GLchar,
-        );
+/// Returns whether the last compile of a shader was successful
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetShader.xhtml
+pub fn get_shader_compile_status(shader: Shader) -> bool {
+    let mut status = 0;
+    get_shader_iv(shader, enums::ShaderInfoParam::CompileStatus, &mut status);
+    status == gl::TRUE as GLint
+}
+
+/// Returns the info log of a shader, which is where the driver reports compile errors
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetShaderInfoLog.xhtml
+pub fn get_shader_info_log(shader: Shader) -> String {
+    let mut length = 0;
+    get_shader_iv(shader, enums::ShaderInfoParam::InfoLogLength, &mut length);
+
+    read_info_log(length, |buffer_size, written, buffer| {
+        gl_call!(get_shader_info_log, gl::GetShaderInfoLog(shader.0, buffer_size, written, buffer));
+    })
+}
+
+/// Compiles a shader, returning the info log as an error if compilation failed
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn compile() -> Result<(), rgl::ShaderError> {
+/// let shader = rgl::create_shader(rgl::ShaderType::Fragment);
+/// rgl::shader_source(shader, "#version 330 core\nvoid main() {}");
+/// rgl::compile_shader_checked(shader)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn compile_shader_checked(shader: Shader) -> Result<(), ShaderError> {
+    compile_shader(shader);
+
+    if get_shader_compile_status(shader) {
+        Ok(())
+    } else {
+        Err(ShaderError::Compile(get_shader_info_log(shader)))
     }
 }
 
+/// Attaches a shader to a program, so it is included when the program is linked
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glAttachShader.xhtml
 pub fn attach_shader(program: Program, shader: Shader) {
-    unsafe {
-        gl::AttachShader(program.0, shader.0);
-    }
+    gl_call!(attach_shader, gl::AttachShader(program.0, shader.0));
 }
 
+/// Links the shaders attached to a program into an executable
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glLinkProgram.xhtml
 pub fn link_program(program: Program) {
-    unsafe {
-        gl::LinkProgram(program.0);
+    gl_call!(link_program, gl::LinkProgram(program.0));
+}
+
+/// Queries a parameter of a program object
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetProgram.xhtml
+pub fn get_program_iv(program: Program, parameter: enums::ProgramInfoParam, status: &mut GLint) {
+    gl_call!(get_program_iv, gl::GetProgramiv(program.0, parameter as GLenum, status));
+}
+
+/// Returns whether the last link of a program was successful
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetProgram.xhtml
+pub fn get_program_link_status(program: Program) -> bool {
+    let mut status = 0;
+    get_program_iv(program, enums::ProgramInfoParam::LinkStatus, &mut status);
+    status == gl::TRUE as GLint
+}
+
+/// Returns the info log of a program, which is where the driver reports link errors
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetProgramInfoLog.xhtml
+pub fn get_program_info_log(program: Program) -> String {
+    let mut length = 0;
+    get_program_iv(program, enums::ProgramInfoParam::InfoLogLength, &mut length);
+
+    read_info_log(length, |buffer_size, written, buffer| {
+        gl_call!(get_program_info_log, gl::GetProgramInfoLog(program.0, buffer_size, written, buffer));
+    })
+}
+
+/// Links a program, returning the info log as an error if linking failed
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn link(vertex: rgl::Shader, fragment: rgl::Shader) -> Result<(), rgl::ShaderError> {
+/// let program = rgl::create_program();
+/// rgl::attach_shader(program, vertex);
+/// rgl::attach_shader(program, fragment);
+/// rgl::link_program_checked(program)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn link_program_checked(program: Program) -> Result<(), ShaderError> {
+    link_program(program);
+
+    if get_program_link_status(program) {
+        Ok(())
+    } else {
+        Err(ShaderError::Link(get_program_info_log(program)))
+    }
+}
+
+//Loading
+
+/// Creates and compiles a shader from `source`, deleting it again if compilation fails
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn load() -> Result<(), rgl::Error> {
+/// let shader = rgl::shader_from_source(rgl::ShaderType::Fragment, "#version 330 core\nvoid main() {}")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn shader_from_source(type_: enums::ShaderType, source: &str) -> Result<Shader, Error> {
+    let shader = create_shader(type_);
+    shader_source(shader, source);
+
+    match compile_shader_checked(shader) {
+        Ok(()) => Ok(shader),
+        Err(error) => {
+            delete_shader(shader);
+            Err(error.into())
+        }
     }
 }
 
-pub fn use_program(program: Program) {
-    unsafe {
-        gl::UseProgram(program.0);
+/// Like `shader_from_source`, reading the source from the file at `path`
+///
+/// `#include` lines are not expanded, read the file and use `resolve_includes` for that
+pub fn shader_from_file(type_: enums::ShaderType, path: &Path) -> Result<Shader, Error> {
+    let source = fs::read_to_string(path).map_err(|error| Error::Io(path.to_path_buf(), error.kind()))?;
+    shader_from_source(type_, &source)
+}
+
+/// Creates a program from compiled shaders, linking it and detaching the shaders again
+///
+/// The shaders are not deleted, so they can be shared between programs. The program is deleted
+/// if linking fails
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::path::Path;
+///
+/// # fn load() -> Result<(), rgl::Error> {
+/// let vertex = rgl::shader_from_file(rgl::ShaderType::Vertex, Path::new("shaders/mesh.vert"))?;
+/// let fragment = rgl::shader_from_file(rgl::ShaderType::Fragment, Path::new("shaders/mesh.frag"))?;
+///
+/// let program = rgl::program_from_shaders(&[vertex, fragment])?;
+/// rgl::delete_shader(vertex);
+/// rgl::delete_shader(fragment);
+/// # Ok(())
+/// # }
+/// ```
+pub fn program_from_shaders(shaders: &[Shader]) -> Result<Program, Error> {
+    let program = create_program();
+    for shader in shaders {
+        attach_shader(program, *shader);
+    }
+
+    let linked = link_program_checked(program);
+    for shader in shaders {
+        detach_shader(program, *shader);
+    }
+
+    match linked {
+        Ok(()) => Ok(program),
+        Err(error) => {
+            delete_program(program);
+            Err(error.into())
+        }
     }
 }
 
-pub fn delete_program(program: Program) {
-    unsafe {
-        gl::DeleteProgram(program.0);
+/// How deeply `resolve_includes` lets included files include others
+pub const MAX_INCLUDE_DEPTH: usize = 32;
+
+/// Expands every `#include "name"` line of `source` with the file `resolver` returns for `name`,
+/// recursively, so files can be shared between shaders without `ARB_shading_language_include`
+///
+/// Included files are expanded in place, so line numbers in compile errors are those of the
+/// expanded source. Files are not included once only, guard them with `#ifndef` if they may be
+/// included twice
+///
+/// # Examples
+/// ```rust
+/// let common = "float luminance(vec3 colour) {\n    return dot(colour, vec3(0.2126, 0.7152, 0.0722));\n}";
+/// let source = "#version 330 core\n#include \"common.glsl\"\nvoid main() {}";
+///
+/// let expanded = rgl::resolve_includes(source, |name| match name {
+///     "common.glsl" => Some(common.to_owned()),
+///     _ => None,
+/// })
+/// .unwrap();
+/// assert_eq!(expanded, format!("#version 330 core\n{}\nvoid main() {{}}\n", common));
+/// ```
+///
+/// Errors point at the failing `#include`
+///
+/// ```rust
+/// use rgl::IncludeErrorKind;
+///
+/// let files = |name: &str| match name {
+///     "a.glsl" => Some(String::from("#include \"b.glsl\"")),
+///     "b.glsl" => Some(String::from("//b\n#include \"a.glsl\"")),
+///     _ => None,
+/// };
+///
+/// let error = rgl::resolve_includes("#include \"a.glsl\"", files).unwrap_err();
+/// assert_eq!(error.file.as_ref().map(String::as_str), Some("b.glsl"));
+/// assert_eq!(error.line, 2);
+/// assert_eq!(error.kind, IncludeErrorKind::Cycle(String::from("a.glsl")));
+///
+/// let error = rgl::resolve_includes("void f();\n#include \"missing.glsl\"", files).unwrap_err();
+/// assert_eq!(error.to_string(), "source:2: included file \"missing.glsl\" not found");
+/// ```
+pub fn resolve_includes<F: Fn(&str) -> Option<String>>(source: &str, resolver: F) -> Result<String, IncludeError> {
+    let mut expanded = String::with_capacity(source.len());
+    let mut stack = Vec::new();
+    expand_includes(source, None, &resolver, &mut stack, &mut expanded)?;
+    Ok(expanded)
+}
+
+fn expand_includes<F: Fn(&str) -> Option<String>>(
+    source: &str,
+    file: Option<&str>,
+    resolver: &F,
+    stack: &mut Vec<String>,
+    expanded: &mut String,
+) -> Result<(), IncludeError> {
+    for (i, line) in source.lines().enumerate() {
+        let error = |kind| IncludeError {
+            file: file.map(String::from),
+            line: i + 1,
+            kind,
+        };
+
+        let name = match include_name(line) {
+            None => {
+                expanded.push_str(line);
+                expanded.push('\n');
+                continue;
+            }
+            Some(None) => return Err(error(IncludeErrorKind::Malformed)),
+            Some(Some(name)) => name,
+        };
+
+        if stack.iter().any(|included| included == name) {
+            return Err(error(IncludeErrorKind::Cycle(name.to_owned())));
+        }
+        if stack.len() >= MAX_INCLUDE_DEPTH {
+            return Err(error(IncludeErrorKind::TooDeep(name.to_owned())));
+        }
+        let included = match resolver(name) {
+            Some(included) => included,
+            None => return Err(error(IncludeErrorKind::NotFound(name.to_owned()))),
+        };
+
+        stack.push(name.to_owned());
+        expand_includes(&included, Some(name), resolver, stack, expanded)?;
+        stack.pop();
     }
+
+    Ok(())
 }
 
-pub fn delete_shader(shader: Shader) {
-    unsafe {
-        gl::DeleteShader(shader.0);
+//None if the line is not an #include, Some(None) if it is one without a quoted name
+fn include_name(line: &str) -> Option<Option<&str>> {
+    let directive = line.trim_start().strip_prefix('#')?.trim_start().strip_prefix("include")?;
+    if !directive.starts_with(|c: char| c.is_whitespace() || c == '"') {
+        return None;
+    }
+
+    let quoted = directive.trim();
+    if quoted.len() >= 2 && quoted.starts_with('"') && quoted.ends_with('"') {
+        Some(Some(&quoted[1..quoted.len() - 1]))
+    } else {
+        Some(None)
     }
 }
 
+//Program binaries
+
+/// Asks the driver to keep the binary of a program retrievable with `get_program_binary`
+///
+/// Must be set before the program is linked. Requires GL 4.1 or `ARB_get_program_binary`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramParameter.xhtml
+pub fn program_parameter_retrievable_hint(program: Program, retrievable: bool) {
+    gl_call!(program_parameter_retrievable_hint, gl::ProgramParameteri(
+        program.0,
+        gl::PROGRAM_BINARY_RETRIEVABLE_HINT,
+        retrievable as GLint
+    ));
+}
+
+/// Returns the binary of a linked program and the driver specific format it is in
+///
+/// Returns `None` if the driver has no binary for the program. The binary can only be loaded back
+/// by the same driver, so caches should be keyed on the vendor, renderer and version strings
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetProgramBinary.xhtml
+pub fn get_program_binary(program: Program) -> Option<(Vec<u8>, GLenum)> {
+    let mut length = 0;
+    get_program_iv(program, enums::ProgramInfoParam::ProgramBinaryLength, &mut length);
+    if length <= 0 {
+        return None;
+    }
+
+    let mut binary = vec![0u8; length as usize];
+    let mut written = 0;
+    let mut format = 0;
+    gl_call!(get_program_binary, gl::GetProgramBinary(
+        program.0,
+        length,
+        &mut written,
+        &mut format,
+        binary.as_mut_ptr() as *mut c_void
+    ));
+    binary.truncate(written.max(0) as usize);
+
+    Some((binary, format))
+}
+
+/// Loads a binary returned by `get_program_binary` into a program, returning an error if the
+/// driver rejected it
+///
+/// Drivers reject binaries after updates, so callers should fall back to compiling from source
+///
+/// # Examples
+/// ```rust,no_run
+/// fn load_or_compile(cached: Option<(Vec<u8>, u32)>) -> Result<rgl::Program, rgl::ShaderError> {
+///     let program = rgl::create_program();
+///     if let Some((binary, format)) = cached {
+///         if rgl::program_binary(program, format, &binary).is_ok() {
+///             return Ok(program);
+///         }
+///     }
+///
+///     //...compile and attach the shaders
+///     rgl::program_parameter_retrievable_hint(program, true);
+///     rgl::link_program_checked(program)?;
+///     if rgl::num_program_binary_formats() > 0 {
+///         if let Some((_binary, _format)) = rgl::get_program_binary(program) {
+///             //...store the binary and format for the next run
+///         }
+///     }
+///     Ok(program)
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramBinary.xhtml
+pub fn program_binary(program: Program, format: GLenum, data: &[u8]) -> Result<(), ShaderError> {
+    gl_call!(program_binary, gl::ProgramBinary(
+        program.0,
+        format,
+        data.as_ptr() as *const c_void,
+        data.len() as GLsizei
+    ));
+
+    if get_program_link_status(program) {
+        Ok(())
+    } else {
+        Err(ShaderError::Link(get_program_info_log(program)))
+    }
+}
+
+/// The number of program binary formats the driver supports, 0 means binaries cannot be cached
+pub fn num_program_binary_formats() -> GLint {
+    let mut formats = 0;
+    gl_call!(num_program_binary_formats, gl::GetIntegerv(gl::NUM_PROGRAM_BINARY_FORMATS, &mut formats));
+    formats
+}
+
+//Shader binaries and SPIR-V
+
+/// Loads the same precompiled shader binary into every shader in `shaders`
+///
+/// With `ShaderBinaryFormat::SpirV`, each shader then needs `specialize_shader` to pick its entry
+/// point, which takes the place of compiling it
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn load() -> Result<rgl::Program, rgl::Error> {
+/// //Link a program from SPIR-V compiled offline, without any GLSL at runtime
+/// let vertex = rgl::create_shader(rgl::ShaderType::Vertex);
+/// rgl::shader_binary(&[vertex], rgl::ShaderBinaryFormat::SpirV, &std::fs::read("mesh.vert.spv").unwrap());
+/// rgl::specialize_shader(vertex, "main", &[], &[])?;
+///
+/// let fragment = rgl::create_shader(rgl::ShaderType::Fragment);
+/// rgl::shader_binary(&[fragment], rgl::ShaderBinaryFormat::SpirV, &std::fs::read("mesh.frag.spv").unwrap());
+/// //Specialization constant 0 turns the fog on
+/// rgl::specialize_shader(fragment, "main", &[0], &[1])?;
+///
+/// let program = rgl::program_from_shaders(&[vertex, fragment])?;
+/// # Ok(program)
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glShaderBinary.xhtml
+pub fn shader_binary(shaders: &[Shader], format: enums::ShaderBinaryFormat, data: &[u8]) {
+    gl_call!(shader_binary, gl::ShaderBinary(
+        shaders.len() as GLsizei,
+        shaders.as_ptr() as *const GLuint,
+        format as GLenum,
+        data.as_ptr() as *const c_void,
+        data.len() as GLsizei,
+    ));
+}
+
+/// The number of shader binary formats the driver supports
+pub fn num_shader_binary_formats() -> GLint {
+    let mut formats = 0;
+    gl_call!(num_shader_binary_formats, gl::GetIntegerv(gl::NUM_SHADER_BINARY_FORMATS, &mut formats));
+    formats
+}
+
+/// Returns the raw tokens of every shader binary format the driver supports
+///
+/// # Examples
+/// ```rust,no_run
+/// let spirv = rgl::get_shader_binary_formats().contains(&(rgl::ShaderBinaryFormat::SpirV as u32));
+/// ```
+pub fn get_shader_binary_formats() -> Vec<GLenum> {
+    let mut formats = vec![0; num_shader_binary_formats().max(0) as usize];
+    if !formats.is_empty() {
+        gl_call!(get_shader_binary_formats, gl::GetIntegerv(gl::SHADER_BINARY_FORMATS, formats.as_mut_ptr()));
+    }
+    formats.into_iter().map(|format| format as GLenum).collect()
+}
+
+type SpecializeShader = unsafe extern "system" fn(GLuint, *const GLchar, GLuint, *const GLuint, *const GLuint);
+
+/// Address of `glSpecializeShader`, which the GL 4.5 bindings do not have, 0 until loaded
+static SPECIALIZE_SHADER: AtomicUsize = AtomicUsize::new(0);
+
+/// Loads `specialize_shader` from GL 4.6, or from `ARB_gl_spirv` on older contexts
+///
+/// `loader` is the same function given to `gl::load_with`. Returns whether either was found
+///
+/// # Examples
+/// ```rust,ignore
+/// gl::load_with(|symbol| context.get_proc_address(symbol) as *const _);
+/// rgl::load_specialize_shader(|symbol| context.get_proc_address(symbol) as *const _);
+/// ```
+pub fn load_specialize_shader<F>(mut loader: F) -> bool
+where
+    F: FnMut(&'static str) -> *const c_void,
+{
+    let mut address = loader("glSpecializeShader");
+    if address.is_null() {
+        address = loader("glSpecializeShaderARB");
+    }
+    SPECIALIZE_SHADER.store(address as usize, Ordering::SeqCst);
+    !address.is_null()
+}
+
+/// Whether `specialize_shader` was loaded by `load_specialize_shader`
+pub fn supports_specialize_shader() -> bool {
+    SPECIALIZE_SHADER.load(Ordering::SeqCst) != 0
+}
+
+/// Picks the entry point of a shader loaded from SPIR-V with `shader_binary` and sets its
+/// specialization constants, returning the info log as an error if that failed
+///
+/// Constant `constant_indices[i]` is set to `constant_values[i]`, the bits of which are
+/// reinterpreted for float constants. Constants that are not set keep their default values
+///
+/// # Panics
+/// If `constant_indices` and `constant_values` are of different lengths
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glSpecializeShader.xhtml
+pub fn specialize_shader(
+    shader: Shader,
+    entry_point: &str,
+    constant_indices: &[GLuint],
+    constant_values: &[GLuint],
+) -> Result<(), ShaderError> {
+    assert_eq!(
+        constant_indices.len(),
+        constant_values.len(),
+        "specialize_shader needs a value for every constant index"
+    );
+
+    let address = SPECIALIZE_SHADER.load(Ordering::SeqCst);
+    if address == 0 {
+        return Err(ShaderError::Compile(String::from("glSpecializeShader is not loaded, see load_specialize_shader")));
+    }
+    let entry_point = match CString::new(entry_point) {
+        Ok(entry_point) => entry_point,
+        Err(_) => return Err(ShaderError::Compile(String::from("entry point contains a NUL byte"))),
+    };
+
+    let function: SpecializeShader = unsafe { mem::transmute(address) };
+    gl_call!(specialize_shader, function(
+        shader.0,
+        entry_point.as_ptr(),
+        constant_indices.len() as GLuint,
+        constant_indices.as_ptr(),
+        constant_values.as_ptr(),
+    ));
+
+    if get_shader_compile_status(shader) {
+        Ok(())
+    } else {
+        Err(ShaderError::Compile(get_shader_info_log(shader)))
+    }
+}
+
+/// Reads an info log or name of the given length (including the NUL), the driver may report 0 for none
+fn read_info_log<F>(length: GLint, read: F) -> String
+where
+    F: FnOnce(GLsizei, *mut GLsizei, *mut GLchar),
+{
+    if length <= 0 {
+        return String::new();
+    }
+
+    let mut buffer = vec![0u8; length as usize];
+    let mut written = 0;
+    read(length, &mut written, buffer.as_mut_ptr() as *mut GLchar);
+    buffer.truncate(written.max(0) as usize);
+
+    String::from_utf8_lossy(&buffer).into_owned()
+}
+
+/// Installs a program as part of the current rendering state
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUseProgram.xhtml
+pub fn use_program<H: Into<Program>>(program: H) {
+    let program: Program = program.into();
+    gl_call!(use_program, gl::UseProgram(program.0));
+}
+
+/// Uninstalls the current program
+///
+/// Rendering then uses the bound program pipeline, and is undefined if there is none
+pub fn unuse_program() {
+    gl_call!(unuse_program, gl::UseProgram(0));
+}
+
+/// Deletes a program object, this is deferred until it is no longer in use
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteProgram.xhtml
+pub fn delete_program(program: Program) {
+    gl_call!(delete_program, gl::DeleteProgram(program.0));
+}
+
+/// Whether `program` names a program object
+///
+/// A deleted program that is still in use stays a program until it is no longer used
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsProgram.xhtml
+pub fn is_program(program: Program) -> bool {
+    gl_call!(is_program, gl::IsProgram(program.0)) == gl::TRUE
+}
+
+/// Deletes a shader object, this is deferred until it is no longer attached to any program
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteShader.xhtml
+pub fn delete_shader(shader: Shader) {
+    gl_call!(delete_shader, gl::DeleteShader(shader.0));
+}
+
+/// Whether `shader` names a shader object
+///
+/// A deleted shader that is still attached to a program stays a shader until it is detached
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsShader.xhtml
+pub fn is_shader(shader: Shader) -> bool {
+    gl_call!(is_shader, gl::IsShader(shader.0)) == gl::TRUE
+}
+
+/// Detaches a shader from a program, after linking this allows the shader to be deleted
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDetachShader.xhtml
 pub fn detach_shader(program: Program, shader: Shader) {
-    unsafe {
-        gl::DetachShader(program.0, shader.0);
+    gl_call!(detach_shader, gl::DetachShader(program.0, shader.0));
+}
+
+//Vertex attributes
+
+/// Returns the index of the vertex attribute called `name` in a linked program
+///
+/// Returns `None` if the program has no active attribute called `name`, or if `name` contains a NUL
+///
+/// # Examples
+/// ```rust,no_run
+/// let program = rgl::create_program();
+/// //...
+/// if let Some(normal) = rgl::get_attrib_location(program, "normal") {
+///     rgl::enable_vertex_attrib_array(normal);
+///     rgl::vertex_attrib_pointer(normal, 3, rgl::Type::Float, false, 24, 12);
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetAttribLocation.xhtml
+pub fn get_attrib_location(program: Program, name: &str) -> Option<GLuint> {
+    let name = CString::new(name).ok()?;
+    let location = gl_call!(get_attrib_location, gl::GetAttribLocation(program.0, name.as_ptr()));
+
+    if location == -1 {
+        None
+    } else {
+        Some(location as GLuint)
+    }
+}
+
+/// Like `get_attrib_location`, returning why the lookup failed as an error
+pub fn get_attrib_location_checked(program: Program, name: &str) -> Result<GLuint, Error> {
+    let c_name = CString::new(name)?;
+    match gl_call!(get_attrib_location_checked, gl::GetAttribLocation(program.0, c_name.as_ptr())) {
+        -1 => Err(Error::NotFound(name.to_owned())),
+        location => Ok(location as GLuint),
     }
 }
 
-///Shader uniforms
+/// Assigns the vertex attribute called `name` to index `index`, taking effect at the next link
+///
+/// Calling this after `link_program` does nothing until the program is linked again. Explicit
+/// `layout(location = ...)` qualifiers in the shader take precedence over it. Fails without calling
+/// GL if `name` contains a NUL
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn bind() -> Result<(), rgl::Error> {
+/// //Give every program the same attribute indices, so one VAO layout works with all of them
+/// let program = rgl::create_program();
+/// //...attach the shaders
+/// rgl::bind_attrib_location(program, 0, "position")?;
+/// rgl::bind_attrib_location(program, 1, "normal")?;
+/// rgl::bind_attrib_location(program, 2, "uv")?;
+/// rgl::link_program(program);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindAttribLocation.xhtml
+pub fn bind_attrib_location(program: Program, index: GLuint, name: &str) -> Result<(), NulError> {
+    let name = CString::new(name)?;
+    gl_call!(bind_attrib_location, gl::BindAttribLocation(program.0, index, name.as_ptr()));
+    Ok(())
+}
 
+//Shader uniforms
 
-pub fn get_uniform_location(program: Program, name: &str) -> UniformLocation {
-    unsafe {
-        UniformLocation(gl::GetUniformLocation(program.0, name.as_ptr() as *const i8))
+/// Returns the location of a uniform variable within a program
+///
+/// Returns `None` if the program has no active uniform called `name`, or if `name` contains a NUL
+///
+/// # Examples
+/// ```rust,no_run
+/// let program = rgl::create_program();
+/// //...
+/// let colour = rgl::get_uniform_location(program, "u_color").expect("no u_color uniform");
+///
+/// //Every frame
+/// rgl::use_program(program);
+/// rgl::uniform4f(colour, 1.0, 0.5, 0.2, 1.0);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetUniformLocation.xhtml
+pub fn get_uniform_location(program: Program, name: &str) -> Option<UniformLocation> {
+    let name = CString::new(name).ok()?;
+    let location = gl_call!(get_uniform_location, gl::GetUniformLocation(program.0, name.as_ptr()));
+
+    if location == -1 {
+        None
+    } else {
+        Some(UniformLocation(location))
     }
 }
 
+/// Like `get_uniform_location`, returning why the lookup failed as an error
+pub fn get_uniform_location_checked(program: Program, name: &str) -> Result<UniformLocation, Error> {
+    let c_name = CString::new(name)?;
+    match gl_call!(get_uniform_location_checked, gl::GetUniformLocation(program.0, c_name.as_ptr())) {
+        -1 => Err(Error::NotFound(name.to_owned())),
+        location => Ok(UniformLocation(location)),
+    }
+}
 
 //Only going to use commonly used ones for now, may add the rest later
 pub fn uniform1f(location: UniformLocation, v0: GLfloat) {
-    unsafe {
-        gl::Uniform1f(location.0, v0);
-    }
+    gl_call!(uniform1f, gl::Uniform1f(location.0, v0));
 }
 
 pub fn uniform2f(location: UniformLocation, v0: GLfloat, v1: GLfloat) {
-    unsafe {
-        gl::Uniform2f(location.0, v0, v1);
-    }
+    gl_call!(uniform2f, gl::Uniform2f(location.0, v0, v1));
 }
 
 pub fn uniform3f(location: UniformLocation, v0: GLfloat, v1: GLfloat, v2: GLfloat) {
-    unsafe {
-        gl::Uniform3f(location.0, v0, v1, v2);
-    }
+    gl_call!(uniform3f, gl::Uniform3f(location.0, v0, v1, v2));
 }
 
 pub fn uniform4f(location: UniformLocation, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat) {
-    unsafe {
-        gl::Uniform4f(location.0, v0, v1, v2, v3);
-    }
+    gl_call!(uniform4f, gl::Uniform4f(location.0, v0, v1, v2, v3));
 }
 
 pub fn uniform1i(location: UniformLocation, v0: GLint) {
-    unsafe {
-        gl::Uniform1i(location.0, v0);
-    }
+    gl_call!(uniform1i, gl::Uniform1i(location.0, v0));
 }
 
 pub fn uniform2i(location: UniformLocation, v0: GLint, v1: GLint) {
-    unsafe {
-        gl::Uniform2i(location.0, v0, v1);
-    }
+    gl_call!(uniform2i, gl::Uniform2i(location.0, v0, v1));
 }
 
 pub fn uniform3i(location: UniformLocation, v0: GLint, v1: GLint, v2: GLint) {
-    unsafe {
-        gl::Uniform3i(location.0, v0, v1, v2);
-    }
+    gl_call!(uniform3i, gl::Uniform3i(location.0, v0, v1, v2));
 }
 
 pub fn uniform4i(location: UniformLocation, v0: GLint, v1: GLint, v2: GLint, v3: GLint) {
-    unsafe {
-        gl::Uniform4i(location.0, v0, v1, v2, v3);
-    }
+    gl_call!(uniform4i, gl::Uniform4i(location.0, v0, v1, v2, v3));
 }
 
 pub fn uniform1ui(location: UniformLocation, v0: GLuint) {
-    unsafe {
-        gl::Uniform1ui(location.0, v0);
-    }
+    gl_call!(uniform1ui, gl::Uniform1ui(location.0, v0));
 }
 
 pub fn uniform2ui(location: UniformLocation, v0: GLuint, v1: GLuint) {
-    unsafe {
-        gl::Uniform2ui(location.0, v0, v1);
-    }
+    gl_call!(uniform2ui, gl::Uniform2ui(location.0, v0, v1));
 }
 
 pub fn uniform3ui(location: UniformLocation, v0: GLuint, v1: GLuint, v2: GLuint) {
-    unsafe {
-        gl::Uniform3ui(location.0, v0, v1, v2);
-    }
+    gl_call!(uniform3ui, gl::Uniform3ui(location.0, v0, v1, v2));
 }
 
 pub fn uniform4ui(location: UniformLocation, v0: GLuint, v1: GLuint, v2: GLuint, v3: GLuint) {
-    unsafe {
-        gl::Uniform4ui(location.0, v0, v1, v2, v3);
-    }
+    gl_call!(uniform4ui, gl::Uniform4ui(location.0, v0, v1, v2, v3));
+}
+
+/// Sets a `double` uniform of the program in use
+///
+/// The double uniform setters require GL 4.0 or `ARB_gpu_shader_fp64`, which can be checked with
+/// `has_extension("GL_ARB_gpu_shader_fp64")` on older contexts
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn uniform1d(location: UniformLocation, v0: GLdouble) {
+    gl_call!(uniform1d, gl::Uniform1d(location.0, v0));
 }
 
+#[cfg(not(feature = "gles2"))]
+pub fn uniform2d(location: UniformLocation, v0: GLdouble, v1: GLdouble) {
+    gl_call!(uniform2d, gl::Uniform2d(location.0, v0, v1));
+}
 
-pub fn uniform1fv(location: UniformLocation, count: GLsizei, value: *const GLfloat) {
-    unsafe {
-        gl::Uniform1fv(location.0, count, value);
-    }
+/// Sets a `dvec3` uniform of the program in use
+///
+/// # Examples
+/// ```rust,no_run
+/// //Large world coordinates lose precision as floats, so the camera's position is subtracted
+/// //in double precision on the GPU
+/// let program = rgl::create_program();
+/// //...
+/// let camera = rgl::get_uniform_location(program, "u_camera_position").unwrap();
+/// let position: [f64; 3] = [4_503_599.25, 12.5, 6_755_399.75];
+/// rgl::use_program(program);
+/// rgl::uniform3d(camera, position[0], position[1], position[2]);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn uniform3d(location: UniformLocation, v0: GLdouble, v1: GLdouble, v2: GLdouble) {
+    gl_call!(uniform3d, gl::Uniform3d(location.0, v0, v1, v2));
 }
 
-pub fn uniform2fv(location: UniformLocation, count: GLsizei, value: *const GLfloat) {
-    unsafe {
-        gl::Uniform2fv(location.0, count, value);
-    }
+#[cfg(not(feature = "gles2"))]
+pub fn uniform4d(location: UniformLocation, v0: GLdouble, v1: GLdouble, v2: GLdouble, v3: GLdouble) {
+    gl_call!(uniform4d, gl::Uniform4d(location.0, v0, v1, v2, v3));
 }
 
-pub fn uniform3fv(location: UniformLocation, count: GLsizei, value: *const GLfloat) {
-    unsafe {
-        gl::Uniform3fv(location.0, count, value);
-    }
+/// Uploads an array of floats
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform1fv(location: UniformLocation, value: &[GLfloat]) {
+    gl_call!(uniform1fv, gl::Uniform1fv(location.0, value.len() as GLsizei, value.as_ptr()));
 }
 
-pub fn uniform4fv(location: UniformLocation, count: GLsizei, value: *const GLfloat) {
-    unsafe {
-        gl::Uniform4fv(location.0, count, value);
-    }
+/// Uploads an array of vec2s, `value` must hold a multiple of 2 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform2fv(location: UniformLocation, value: &[GLfloat]) {
+    let count = element_count(value, 2);
+    gl_call!(uniform2fv, gl::Uniform2fv(location.0, count, value.as_ptr()));
 }
 
-pub fn uniform1iv(location: UniformLocation, count: GLsizei, value: *const GLint) {
-    unsafe {
-        gl::Uniform1iv(location.0, count, value);
-    }
+/// Uploads an array of vec3s, `value` must hold a multiple of 3 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform3fv(location: UniformLocation, value: &[GLfloat]) {
+    let count = element_count(value, 3);
+    gl_call!(uniform3fv, gl::Uniform3fv(location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of vec4s, `value` must hold a multiple of 4 values
+///
+/// # Examples
+/// ```rust,no_run
+/// //uniform vec4 u_lights[16], uploaded from an array of arrays in one call
+/// let program = rgl::create_program();
+/// //...
+/// let location = rgl::get_uniform_location(program, "u_lights").unwrap();
+/// let lights = [[0.0f32, 10.0, 0.0, 1.0]; 16];
+/// rgl::uniform4fv(location, lights.as_flattened());
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform4fv(location: UniformLocation, value: &[GLfloat]) {
+    let count = element_count(value, 4);
+    gl_call!(uniform4fv, gl::Uniform4fv(location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of ints
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform1iv(location: UniformLocation, value: &[GLint]) {
+    gl_call!(uniform1iv, gl::Uniform1iv(location.0, value.len() as GLsizei, value.as_ptr()));
+}
+
+/// Uploads an array of ivec2s, `value` must hold a multiple of 2 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform2iv(location: UniformLocation, value: &[GLint]) {
+    let count = element_count(value, 2);
+    gl_call!(uniform2iv, gl::Uniform2iv(location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of ivec3s, `value` must hold a multiple of 3 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform3iv(location: UniformLocation, value: &[GLint]) {
+    let count = element_count(value, 3);
+    gl_call!(uniform3iv, gl::Uniform3iv(location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of ivec4s, `value` must hold a multiple of 4 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform4iv(location: UniformLocation, value: &[GLint]) {
+    let count = element_count(value, 4);
+    gl_call!(uniform4iv, gl::Uniform4iv(location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of uints
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform1uiv(location: UniformLocation, value: &[GLuint]) {
+    gl_call!(uniform1uiv, gl::Uniform1uiv(location.0, value.len() as GLsizei, value.as_ptr()));
+}
+
+/// Uploads an array of uvec2s, `value` must hold a multiple of 2 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform2uiv(location: UniformLocation, value: &[GLuint]) {
+    let count = element_count(value, 2);
+    gl_call!(uniform2uiv, gl::Uniform2uiv(location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of uvec3s, `value` must hold a multiple of 3 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform3uiv(location: UniformLocation, value: &[GLuint]) {
+    let count = element_count(value, 3);
+    gl_call!(uniform3uiv, gl::Uniform3uiv(location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of uvec4s, `value` must hold a multiple of 4 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform4uiv(location: UniformLocation, value: &[GLuint]) {
+    let count = element_count(value, 4);
+    gl_call!(uniform4uiv, gl::Uniform4uiv(location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of 2x2 matrices, `value` must hold a multiple of 4 floats
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform_matrix2fv(location: UniformLocation, transpose: bool, value: &[GLfloat]) {
+    let count = element_count(value, 4);
+    gl_call!(uniform_matrix2fv, gl::UniformMatrix2fv(location.0, count, transpose as GLboolean, value.as_ptr()));
+}
+
+/// Uploads an array of 3x3 matrices, `value` must hold a multiple of 9 floats
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform_matrix3fv(location: UniformLocation, transpose: bool, value: &[GLfloat]) {
+    let count = element_count(value, 9);
+    gl_call!(uniform_matrix3fv, gl::UniformMatrix3fv(location.0, count, transpose as GLboolean, value.as_ptr()));
+}
+
+/// Uploads an array of 4x4 matrices, `value` must hold a multiple of 16 floats
+///
+/// # Examples
+/// ```rust,no_run
+/// let program = rgl::create_program();
+/// //...
+/// let bones = rgl::get_uniform_location(program, "u_bones").unwrap();
+/// let matrices = vec![0.0; 16 * 32];
+/// rgl::uniform_matrix4fv(bones, false, &matrices);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+pub fn uniform_matrix4fv(location: UniformLocation, transpose: bool, value: &[GLfloat]) {
+    let count = element_count(value, 16);
+    gl_call!(uniform_matrix4fv, gl::UniformMatrix4fv(location.0, count, transpose as GLboolean, value.as_ptr()));
+}
+
+/// Uploads an array of 4x4 double matrices, `value` must hold a multiple of 16 doubles
+///
+/// Requires GL 4.0 or `ARB_gpu_shader_fp64`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniform.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn uniform_matrix4dv(location: UniformLocation, transpose: bool, value: &[GLdouble]) {
+    let count = element_count(value, 16);
+    gl_call!(uniform_matrix4dv, gl::UniformMatrix4dv(location.0, count, transpose as GLboolean, value.as_ptr()));
 }
 
-pub fn uniform2iv(location: UniformLocation, count: GLsizei, value: *const GLint) {
-    unsafe {
-        gl::Uniform2iv(location.0, count, value);
+fn element_count<T>(value: &[T], elements: usize) -> GLsizei {
+    assert!(
+        value.len().is_multiple_of(elements),
+        "uniform data length {} is not a multiple of {}",
+        value.len(),
+        elements
+    );
+    (value.len() / elements) as GLsizei
+}
+
+//Uniform readback
+
+//Every read goes through a buffer big enough for a mat4, as GL writes as many values as the
+//uniform's type has whatever the caller asked for
+fn get_uniform_fv(program: Program, location: UniformLocation) -> [GLfloat; 16] {
+    let mut values = [0.0; 16];
+    gl_call!(get_uniform_fv, gl::GetUniformfv(program.0, location.0, values.as_mut_ptr()));
+    values
+}
+
+fn get_uniform_iv(program: Program, location: UniformLocation) -> [GLint; 16] {
+    let mut values = [0; 16];
+    gl_call!(get_uniform_iv, gl::GetUniformiv(program.0, location.0, values.as_mut_ptr()));
+    values
+}
+
+fn get_uniform_uiv(program: Program, location: UniformLocation) -> [GLuint; 16] {
+    let mut values = [0; 16];
+    gl_call!(get_uniform_uiv, gl::GetUniformuiv(program.0, location.0, values.as_mut_ptr()));
+    values
+}
+
+/// Reads back the value of a `float` uniform of `program`
+///
+/// The `get_uniform_*` functions read the first components of uniforms with more, and convert
+/// between floats, integers and booleans like `glGetUniform` does. A `location` that is not one of
+/// `program`'s is an error, which the `error-check` feature reports as `InvalidOperation`, and
+/// reads as zeros otherwise
+///
+/// # Examples
+/// ```rust,no_run
+/// # let program = rgl::create_program();
+/// //Check that a material set its uniforms
+/// let roughness = rgl::get_uniform_location(program, "u_roughness").unwrap();
+/// rgl::program_uniform1f(program, roughness, 0.5);
+/// assert_eq!(rgl::get_uniform_f(program, roughness), 0.5);
+///
+/// let tint = rgl::get_uniform_location(program, "u_tint").unwrap();
+/// assert_eq!(rgl::get_uniform_vec4(program, tint), [1.0, 1.0, 1.0, 1.0]);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetUniform.xhtml
+pub fn get_uniform_f(program: Program, location: UniformLocation) -> GLfloat {
+    get_uniform_fv(program, location)[0]
+}
+
+/// Reads back the value of a `vec2` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_vec2(program: Program, location: UniformLocation) -> [GLfloat; 2] {
+    let v = get_uniform_fv(program, location);
+    [v[0], v[1]]
+}
+
+/// Reads back the value of a `vec3` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_vec3(program: Program, location: UniformLocation) -> [GLfloat; 3] {
+    let v = get_uniform_fv(program, location);
+    [v[0], v[1], v[2]]
+}
+
+/// Reads back the value of a `vec4` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_vec4(program: Program, location: UniformLocation) -> [GLfloat; 4] {
+    let v = get_uniform_fv(program, location);
+    [v[0], v[1], v[2], v[3]]
+}
+
+/// Reads back the value of a `mat3` uniform of `program` in column-major order, see `get_uniform_f`
+pub fn get_uniform_mat3(program: Program, location: UniformLocation) -> [GLfloat; 9] {
+    let mut matrix = [0.0; 9];
+    matrix.copy_from_slice(&get_uniform_fv(program, location)[..9]);
+    matrix
+}
+
+/// Reads back the value of a `mat4` uniform of `program` in column-major order, see `get_uniform_f`
+pub fn get_uniform_mat4(program: Program, location: UniformLocation) -> [GLfloat; 16] {
+    get_uniform_fv(program, location)
+}
+
+/// Reads back the value of an `int`, `bool` or sampler uniform of `program`, see `get_uniform_f`
+///
+/// Samplers read as the texture unit they were set to
+pub fn get_uniform_i(program: Program, location: UniformLocation) -> GLint {
+    get_uniform_iv(program, location)[0]
+}
+
+/// Reads back the value of an `ivec2` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_ivec2(program: Program, location: UniformLocation) -> [GLint; 2] {
+    let v = get_uniform_iv(program, location);
+    [v[0], v[1]]
+}
+
+/// Reads back the value of an `ivec3` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_ivec3(program: Program, location: UniformLocation) -> [GLint; 3] {
+    let v = get_uniform_iv(program, location);
+    [v[0], v[1], v[2]]
+}
+
+/// Reads back the value of an `ivec4` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_ivec4(program: Program, location: UniformLocation) -> [GLint; 4] {
+    let v = get_uniform_iv(program, location);
+    [v[0], v[1], v[2], v[3]]
+}
+
+/// Reads back the value of a `uint` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_ui(program: Program, location: UniformLocation) -> GLuint {
+    get_uniform_uiv(program, location)[0]
+}
+
+/// Reads back the value of a `uvec2` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_uvec2(program: Program, location: UniformLocation) -> [GLuint; 2] {
+    let v = get_uniform_uiv(program, location);
+    [v[0], v[1]]
+}
+
+/// Reads back the value of a `uvec3` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_uvec3(program: Program, location: UniformLocation) -> [GLuint; 3] {
+    let v = get_uniform_uiv(program, location);
+    [v[0], v[1], v[2]]
+}
+
+/// Reads back the value of a `uvec4` uniform of `program`, see `get_uniform_f`
+pub fn get_uniform_uvec4(program: Program, location: UniformLocation) -> [GLuint; 4] {
+    let v = get_uniform_uiv(program, location);
+    [v[0], v[1], v[2], v[3]]
+}
+
+/// A 2x2 matrix that can be uploaded with `uniform_mat2`
+pub trait Matrix2 {
+    /// The 4 elements of the matrix in column-major order
+    fn as_column_major(&self) -> &[f32];
+}
+
+/// A 3x3 matrix that can be uploaded with `uniform_mat3`
+pub trait Matrix3 {
+    /// The 9 elements of the matrix in column-major order
+    fn as_column_major(&self) -> &[f32];
+}
+
+/// A 4x4 matrix that can be uploaded with `uniform_mat4`
+///
+/// Implemented for `[f32; 16]`, as well as the matrix types of `glam`, `cgmath` and `nalgebra`
+/// when the cargo feature of the same name is enabled
+pub trait Matrix4 {
+    /// The 16 elements of the matrix in column-major order
+    fn as_column_major(&self) -> &[f32];
+}
+
+/// Uploads a single 2x2 column-major matrix
+pub fn uniform_mat2<M: Matrix2>(location: UniformLocation, matrix: &M) {
+    uniform_matrix2fv(location, false, matrix.as_column_major());
+}
+
+/// Uploads a single 3x3 column-major matrix
+pub fn uniform_mat3<M: Matrix3>(location: UniformLocation, matrix: &M) {
+    uniform_matrix3fv(location, false, matrix.as_column_major());
+}
+
+/// Uploads a single 4x4 column-major matrix
+///
+/// # Examples
+/// ```rust,ignore
+/// // With the `glam` feature enabled
+/// let mvp = projection * view * model;
+/// rgl::uniform_mat4(mvp_location, &mvp);
+/// ```
+pub fn uniform_mat4<M: Matrix4>(location: UniformLocation, matrix: &M) {
+    uniform_matrix4fv(location, false, matrix.as_column_major());
+}
+
+impl Matrix2 for [f32; 4] {
+    fn as_column_major(&self) -> &[f32] {
+        self
     }
 }
 
-pub fn uniform3iv(location: UniformLocation, count: GLsizei, value: *const GLint) {
-    unsafe {
-        gl::Uniform3iv(location.0, count, value);
+impl Matrix3 for [f32; 9] {
+    fn as_column_major(&self) -> &[f32] {
+        self
     }
 }
 
-pub fn uniform4iv(location: UniformLocation, count: GLsizei, value: *const GLint) {
-    unsafe {
-        gl::Uniform4iv(location.0, count, value);
+impl Matrix4 for [f32; 16] {
+    fn as_column_major(&self) -> &[f32] {
+        self
     }
 }
 
-pub fn uniform1uiv(location: UniformLocation, count: GLsizei, value: *const GLuint) {
-    unsafe {
-        gl::Uniform1uiv(location.0, count, value);
+//Uniform blocks
+
+/// Returns the index of a uniform block within a program
+///
+/// Returns `None` if the program has no active uniform block called `name`, or if `name` contains a NUL
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetUniformBlockIndex.xhtml
+pub fn get_uniform_block_index(program: Program, name: &str) -> Option<BlockIndex> {
+    let name = CString::new(name).ok()?;
+    let index = gl_call!(get_uniform_block_index, gl::GetUniformBlockIndex(program.0, name.as_ptr()));
+
+    if index == gl::INVALID_INDEX {
+        None
+    } else {
+        Some(BlockIndex(index))
     }
 }
 
-pub fn uniform2uiv(location: UniformLocation, count: GLsizei, value: *const GLuint) {
-    unsafe {
-        gl::Uniform2uiv(location.0, count, value);
+/// Like `get_uniform_block_index`, returning why the lookup failed as an error
+pub fn get_uniform_block_index_checked(program: Program, name: &str) -> Result<BlockIndex, Error> {
+    let c_name = CString::new(name)?;
+    match gl_call!(get_uniform_block_index_checked, gl::GetUniformBlockIndex(program.0, c_name.as_ptr())) {
+        gl::INVALID_INDEX => Err(Error::NotFound(name.to_owned())),
+        index => Ok(BlockIndex(index)),
     }
 }
 
-pub fn uniform3uiv(location: UniformLocation, count: GLsizei, value: *const GLuint) {
-    unsafe {
-        gl::Uniform3uiv(location.0, count, value);
+/// Sources the uniform block at `block` from the buffer bound to `UniformBuffer` binding point `binding`
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::mem;
+///
+/// //layout(std140) uniform Matrices { mat4 projection; mat4 view; };
+/// #[repr(C)]
+/// struct Matrices {
+///     projection: [f32; 16],
+///     view: [f32; 16],
+/// }
+///
+/// let program = rgl::create_program();
+/// //...
+/// let block = rgl::get_uniform_block_index(program, "Matrices").expect("no Matrices block");
+/// assert_eq!(rgl::get_active_uniform_block_data_size(program, block), mem::size_of::<Matrices>());
+/// rgl::uniform_block_binding(program, block, 0);
+///
+/// let ubo = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::UniformBuffer, ubo);
+/// rgl::buffer_reserve(rgl::Target::UniformBuffer, mem::size_of::<Matrices>(), rgl::Usage::DynamicDraw);
+/// rgl::bind_buffer_base(rgl::IndexedTarget::UniformBuffer, 0, ubo);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUniformBlockBinding.xhtml
+pub fn uniform_block_binding(program: Program, block: BlockIndex, binding: GLuint) {
+    gl_call!(uniform_block_binding, gl::UniformBlockBinding(program.0, block.0, binding));
+}
+
+/// Returns the size in bytes of the buffer storage a uniform block needs
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetActiveUniformBlock.xhtml
+pub fn get_active_uniform_block_data_size(program: Program, block: BlockIndex) -> usize {
+    let mut size = 0;
+    gl_call!(get_active_uniform_block_data_size, gl::GetActiveUniformBlockiv(
+        program.0,
+        block.0,
+        gl::UNIFORM_BLOCK_DATA_SIZE,
+        &mut size
+    ));
+    size as usize
+}
+
+//Program resources
+
+/// Returns the index of the resource called `name` in one of a program's interfaces
+///
+/// Returns `None` if the program has no such active resource, or if `name` contains a NUL.
+/// Requires GL 4.3 or `ARB_program_interface_query`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetProgramResourceIndex.xhtml
+pub fn get_program_resource_index(program: Program, interface: enums::ProgramInterface, name: &str) -> Option<GLuint> {
+    let name = CString::new(name).ok()?;
+    let index = gl_call!(get_program_resource_index, gl::GetProgramResourceIndex(program.0, interface as GLenum, name.as_ptr()));
+
+    if index == gl::INVALID_INDEX {
+        None
+    } else {
+        Some(index)
     }
 }
 
-pub fn uniform4uiv(location: UniformLocation, count: GLsizei, value: *const GLuint) {
-    unsafe {
-        gl::Uniform4uiv(location.0, count, value);
+/// Sources the shader storage block at `index` from the buffer bound to `ShaderStorageBuffer` binding point `binding`
+///
+/// Requires GL 4.3 or `ARB_shader_storage_buffer_object`
+///
+/// # Examples
+/// ```rust,no_run
+/// //buffer Particles { vec4 positions[]; };
+/// let program = rgl::create_program();
+/// //...
+/// let particles = rgl::get_program_resource_index(program, rgl::ProgramInterface::ShaderStorageBlock, "Particles")
+///     .expect("no Particles block");
+/// rgl::shader_storage_block_binding(program, particles, 2);
+///
+/// let ssbo = rgl::gen_buffer();
+/// rgl::bind_buffer_base(rgl::IndexedTarget::ShaderStorageBuffer, 2, ssbo);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glShaderStorageBlockBinding.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn shader_storage_block_binding(program: Program, index: GLuint, binding: GLuint) {
+    gl_call!(shader_storage_block_binding, gl::ShaderStorageBlockBinding(program.0, index, binding));
+}
+
+//Program introspection
+
+/// An active uniform of a program, returned by `get_active_uniform`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActiveUniform {
+    /// Name of the uniform, arrays end in `[0]`
+    pub name: String,
+    /// Number of elements, 1 unless the uniform is an array
+    pub size: GLint,
+    pub type_: enums::UniformType,
+}
+
+/// An active vertex attribute of a program, returned by `get_active_attrib`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActiveAttrib {
+    pub name: String,
+    /// Number of elements, 1 unless the attribute is an array
+    pub size: GLint,
+    pub type_: enums::UniformType,
+}
+
+/// Returns the number of active uniforms of a linked program, including those inside uniform blocks
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetProgram.xhtml
+pub fn get_active_uniform_count(program: Program) -> GLuint {
+    let mut count = 0;
+    get_program_iv(program, enums::ProgramInfoParam::ActiveUniforms, &mut count);
+    count.max(0) as GLuint
+}
+
+/// Returns the number of active vertex attributes of a linked program
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetProgram.xhtml
+pub fn get_active_attrib_count(program: Program) -> GLuint {
+    let mut count = 0;
+    get_program_iv(program, enums::ProgramInfoParam::ActiveAttributes, &mut count);
+    count.max(0) as GLuint
+}
+
+/// Returns the active uniform at `index`, which must be below `get_active_uniform_count`
+///
+/// The indices are not uniform locations, look those up by name with `get_uniform_location`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetActiveUniform.xhtml
+pub fn get_active_uniform(program: Program, index: GLuint) -> ActiveUniform {
+    let mut length = 0;
+    get_program_iv(program, enums::ProgramInfoParam::ActiveUniformMaxLength, &mut length);
+
+    let (mut size, mut type_) = (0, 0);
+    let name = read_info_log(length, |length, written, buffer| {
+        gl_call!(get_active_uniform, gl::GetActiveUniform(program.0, index, length, written, &mut size, &mut type_, buffer));
+    });
+
+    ActiveUniform { name, size, type_: enums::UniformType::from_raw(type_) }
+}
+
+/// Returns the active vertex attribute at `index`, which must be below `get_active_attrib_count`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetActiveAttrib.xhtml
+pub fn get_active_attrib(program: Program, index: GLuint) -> ActiveAttrib {
+    let mut length = 0;
+    get_program_iv(program, enums::ProgramInfoParam::ActiveAttributeMaxLength, &mut length);
+
+    let (mut size, mut type_) = (0, 0);
+    let name = read_info_log(length, |length, written, buffer| {
+        gl_call!(get_active_attrib, gl::GetActiveAttrib(program.0, index, length, written, &mut size, &mut type_, buffer));
+    });
+
+    ActiveAttrib { name, size, type_: enums::UniformType::from_raw(type_) }
+}
+
+/// Iterates over every active uniform of a linked program
+///
+/// # Examples
+/// ```rust,no_run
+/// //List the textures and colours a material exposes in an editor
+/// let program = rgl::create_program();
+/// //...
+/// for uniform in rgl::active_uniforms(program) {
+///     match uniform.type_ {
+///         rgl::UniformType::Sampler2d => println!("texture slot {}", uniform.name),
+///         rgl::UniformType::FloatVec4 => println!("colour {}", uniform.name),
+///         _ => (),
+///     }
+/// }
+/// ```
+pub fn active_uniforms(program: Program) -> impl Iterator<Item = ActiveUniform> {
+    (0..get_active_uniform_count(program)).map(move |index| get_active_uniform(program, index))
+}
+
+/// Iterates over every active vertex attribute of a linked program
+pub fn active_attribs(program: Program) -> impl Iterator<Item = ActiveAttrib> {
+    (0..get_active_attrib_count(program)).map(move |index| get_active_attrib(program, index))
+}
+
+#[cfg(feature = "glam")]
+mod glam_matrices {
+    use glam;
+
+    impl super::Matrix2 for glam::Mat2 {
+        fn as_column_major(&self) -> &[f32] {
+            AsRef::<[f32; 4]>::as_ref(self)
+        }
+    }
+
+    impl super::Matrix3 for glam::Mat3 {
+        fn as_column_major(&self) -> &[f32] {
+            AsRef::<[f32; 9]>::as_ref(self)
+        }
+    }
+
+    impl super::Matrix4 for glam::Mat4 {
+        fn as_column_major(&self) -> &[f32] {
+            AsRef::<[f32; 16]>::as_ref(self)
+        }
     }
 }
 
+#[cfg(feature = "cgmath")]
+mod cgmath_matrices {
+    use cgmath;
 
+    impl super::Matrix2 for cgmath::Matrix2<f32> {
+        fn as_column_major(&self) -> &[f32] {
+            AsRef::<[f32; 4]>::as_ref(self)
+        }
+    }
 
-pub fn uniform_matrix_2fv(location: UniformLocation, count: GLsizei, transpose: GLboolean, value: *const GLfloat) {
-    unsafe {
-        gl::UniformMatrix2fv(location.0, count, transpose, value);
+    impl super::Matrix3 for cgmath::Matrix3<f32> {
+        fn as_column_major(&self) -> &[f32] {
+            AsRef::<[f32; 9]>::as_ref(self)
+        }
+    }
+
+    impl super::Matrix4 for cgmath::Matrix4<f32> {
+        fn as_column_major(&self) -> &[f32] {
+            AsRef::<[f32; 16]>::as_ref(self)
+        }
     }
 }
 
-pub fn uniform_matrix_3fv(location: UniformLocation, count: GLsizei, transpose: GLboolean, value: *const GLfloat) {
-    unsafe {
-        gl::UniformMatrix3fv(location.0, count, transpose, value);
+#[cfg(feature = "nalgebra")]
+mod nalgebra_matrices {
+    use nalgebra;
+
+    impl super::Matrix2 for nalgebra::Matrix2<f32> {
+        fn as_column_major(&self) -> &[f32] {
+            self.as_slice()
+        }
     }
+
+    impl super::Matrix3 for nalgebra::Matrix3<f32> {
+        fn as_column_major(&self) -> &[f32] {
+            self.as_slice()
+        }
+    }
+
+    impl super::Matrix4 for nalgebra::Matrix4<f32> {
+        fn as_column_major(&self) -> &[f32] {
+            self.as_slice()
+        }
+    }
+}
+
+//Separable programs
+
+/// Marks a program as usable in a program pipeline, must be set before the program is linked
+///
+/// Requires GL 4.1 or `ARB_separate_shader_objects`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramParameter.xhtml
+pub fn program_parameter_separable(program: Program, separable: bool) {
+    gl_call!(program_parameter_separable, gl::ProgramParameteri(program.0, gl::PROGRAM_SEPARABLE, separable as GLint));
 }
 
-pub fn uniform_matrix_4fv(location: UniformLocation, count: GLsizei, transpose: GLboolean, value: *const GLfloat) {
-    unsafe {
-        gl::UniformMatrix4fv(location.0, count, transpose, value);
+/// Compiles and links a separable program from the source of a single stage
+///
+/// On failure the program is deleted and the info log, holding both compile and link errors, is
+/// returned. Requires GL 4.1 or `ARB_separate_shader_objects`
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn materials(vertex_source: &str, fragment_sources: &[&str]) -> Result<(), rgl::ShaderError> {
+/// let vertex = rgl::create_shader_program(rgl::ShaderType::Vertex, vertex_source)?;
+/// let mut fragments = Vec::new();
+/// for source in fragment_sources {
+///     fragments.push(rgl::create_shader_program(rgl::ShaderType::Fragment, source)?);
+/// }
+///
+/// let pipeline = rgl::gen_program_pipeline();
+/// rgl::bind_program_pipeline(pipeline);
+/// rgl::use_program_stages(pipeline, rgl::StageMask::VERTEX, vertex);
+/// for fragment in fragments.iter() {
+///     //Swapping the material does not relink anything
+///     rgl::use_program_stages(pipeline, rgl::StageMask::FRAGMENT, *fragment);
+///     //...draw the meshes using the material
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateShaderProgram.xhtml
+pub fn create_shader_program(type_: enums::ShaderType, source: &str) -> Result<Program, ShaderError> {
+    let source = match CString::new(source) {
+        Ok(source) => source,
+        Err(_) => return Err(ShaderError::Compile(String::from("shader source contains a NUL byte"))),
+    };
+    let sources = [source.as_ptr()];
+    let program = Program(gl_call!(create_shader_program, gl::CreateShaderProgramv(type_ as GLenum, 1, sources.as_ptr())));
+    if program.0 == 0 {
+        return Err(ShaderError::Compile(String::from("failed to create the shader program")));
+    }
+
+    if get_program_link_status(program) {
+        Ok(program)
+    } else {
+        let log = get_program_info_log(program);
+        delete_program(program);
+        Err(ShaderError::Link(log))
     }
 }
+
+/// Generates program pipeline objects, one for every element of the slice
+///
+/// Requires GL 4.1 or `ARB_separate_shader_objects`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenProgramPipelines.xhtml
+pub fn gen_program_pipelines(pipelines: &mut [ProgramPipeline]) {
+    gl_call!(gen_program_pipelines, gl::GenProgramPipelines(pipelines.len() as GLsizei, pipelines.as_mut_ptr() as *mut GLuint));
+}
+
+/// Generates a single program pipeline
+pub fn gen_program_pipeline() -> ProgramPipeline {
+    let mut pipeline = [ProgramPipeline(0)];
+    gen_program_pipelines(&mut pipeline);
+    pipeline[0]
+}
+
+/// Binds a program pipeline, which is used for rendering while no program is in use
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindProgramPipeline.xhtml
+pub fn bind_program_pipeline<H: Into<ProgramPipeline>>(pipeline: H) {
+    let pipeline: ProgramPipeline = pipeline.into();
+    gl_call!(bind_program_pipeline, gl::BindProgramPipeline(pipeline.0));
+}
+
+/// Unbinds the current program pipeline, a used program still takes precedence either way
+pub fn unbind_program_pipeline() {
+    gl_call!(unbind_program_pipeline, gl::BindProgramPipeline(0));
+}
+
+/// Deletes every program pipeline object in the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteProgramPipelines.xhtml
+pub fn delete_program_pipelines(pipelines: &[ProgramPipeline]) {
+    gl_call!(delete_program_pipelines, gl::DeleteProgramPipelines(pipelines.len() as GLsizei, pipelines.as_ptr() as *const GLuint));
+}
+
+pub fn delete_program_pipeline(pipeline: ProgramPipeline) {
+    delete_program_pipelines(&[pipeline]);
+}
+
+/// Makes a pipeline use the given stages of a separable program
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glUseProgramStages.xhtml
+pub fn use_program_stages(pipeline: ProgramPipeline, stages: enums::StageMask, program: Program) {
+    gl_call!(use_program_stages, gl::UseProgramStages(pipeline.0, stages.bits(), program.0));
+}
+
+/// Sets the program of a pipeline that the plain `uniform*` setters modify
+///
+/// The `program_uniform*` setters take the program explicitly and do not need this
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glActiveShaderProgram.xhtml
+pub fn active_shader_program(pipeline: ProgramPipeline, program: Program) {
+    gl_call!(active_shader_program, gl::ActiveShaderProgram(pipeline.0, program.0));
+}
+
+//Program uniforms, these take the program explicitly so it does not need to be in use.
+//Requires GL 4.1 or `ARB_separate_shader_objects`
+
+/// Sets a float uniform of `program`, like `uniform1f` without the program being in use
+///
+/// # Examples
+/// ```rust,no_run
+/// //When loading materials, assign the texture units without binding every program
+/// # let programs = [rgl::create_program()];
+/// for program in programs.iter() {
+///     if let Some(albedo) = rgl::get_uniform_location(*program, "u_albedo") {
+///         rgl::program_uniform1i(*program, albedo, 0);
+///     }
+///     if let Some(roughness) = rgl::get_uniform_location(*program, "u_roughness") {
+///         rgl::program_uniform1f(*program, roughness, 0.5);
+///     }
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform1f(program: Program, location: UniformLocation, v0: GLfloat) {
+    gl_call!(program_uniform1f, gl::ProgramUniform1f(program.0, location.0, v0));
+}
+
+pub fn program_uniform2f(program: Program, location: UniformLocation, v0: GLfloat, v1: GLfloat) {
+    gl_call!(program_uniform2f, gl::ProgramUniform2f(program.0, location.0, v0, v1));
+}
+
+pub fn program_uniform3f(program: Program, location: UniformLocation, v0: GLfloat, v1: GLfloat, v2: GLfloat) {
+    gl_call!(program_uniform3f, gl::ProgramUniform3f(program.0, location.0, v0, v1, v2));
+}
+
+pub fn program_uniform4f(program: Program, location: UniformLocation, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat) {
+    gl_call!(program_uniform4f, gl::ProgramUniform4f(program.0, location.0, v0, v1, v2, v3));
+}
+
+pub fn program_uniform1i(program: Program, location: UniformLocation, v0: GLint) {
+    gl_call!(program_uniform1i, gl::ProgramUniform1i(program.0, location.0, v0));
+}
+
+pub fn program_uniform2i(program: Program, location: UniformLocation, v0: GLint, v1: GLint) {
+    gl_call!(program_uniform2i, gl::ProgramUniform2i(program.0, location.0, v0, v1));
+}
+
+pub fn program_uniform3i(program: Program, location: UniformLocation, v0: GLint, v1: GLint, v2: GLint) {
+    gl_call!(program_uniform3i, gl::ProgramUniform3i(program.0, location.0, v0, v1, v2));
+}
+
+pub fn program_uniform4i(program: Program, location: UniformLocation, v0: GLint, v1: GLint, v2: GLint, v3: GLint) {
+    gl_call!(program_uniform4i, gl::ProgramUniform4i(program.0, location.0, v0, v1, v2, v3));
+}
+
+pub fn program_uniform1ui(program: Program, location: UniformLocation, v0: GLuint) {
+    gl_call!(program_uniform1ui, gl::ProgramUniform1ui(program.0, location.0, v0));
+}
+
+pub fn program_uniform2ui(program: Program, location: UniformLocation, v0: GLuint, v1: GLuint) {
+    gl_call!(program_uniform2ui, gl::ProgramUniform2ui(program.0, location.0, v0, v1));
+}
+
+pub fn program_uniform3ui(program: Program, location: UniformLocation, v0: GLuint, v1: GLuint, v2: GLuint) {
+    gl_call!(program_uniform3ui, gl::ProgramUniform3ui(program.0, location.0, v0, v1, v2));
+}
+
+pub fn program_uniform4ui(program: Program, location: UniformLocation, v0: GLuint, v1: GLuint, v2: GLuint, v3: GLuint) {
+    gl_call!(program_uniform4ui, gl::ProgramUniform4ui(program.0, location.0, v0, v1, v2, v3));
+}
+
+/// Uploads an array of floats to `program`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform1fv(program: Program, location: UniformLocation, value: &[GLfloat]) {
+    gl_call!(program_uniform1fv, gl::ProgramUniform1fv(program.0, location.0, value.len() as GLsizei, value.as_ptr()));
+}
+
+/// Uploads an array of vec2s to `program`, `value` must hold a multiple of 2 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform2fv(program: Program, location: UniformLocation, value: &[GLfloat]) {
+    let count = element_count(value, 2);
+    gl_call!(program_uniform2fv, gl::ProgramUniform2fv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of vec3s to `program`, `value` must hold a multiple of 3 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform3fv(program: Program, location: UniformLocation, value: &[GLfloat]) {
+    let count = element_count(value, 3);
+    gl_call!(program_uniform3fv, gl::ProgramUniform3fv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of vec4s to `program`, `value` must hold a multiple of 4 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform4fv(program: Program, location: UniformLocation, value: &[GLfloat]) {
+    let count = element_count(value, 4);
+    gl_call!(program_uniform4fv, gl::ProgramUniform4fv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of ints to `program`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform1iv(program: Program, location: UniformLocation, value: &[GLint]) {
+    gl_call!(program_uniform1iv, gl::ProgramUniform1iv(program.0, location.0, value.len() as GLsizei, value.as_ptr()));
+}
+
+/// Uploads an array of ivec2s to `program`, `value` must hold a multiple of 2 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform2iv(program: Program, location: UniformLocation, value: &[GLint]) {
+    let count = element_count(value, 2);
+    gl_call!(program_uniform2iv, gl::ProgramUniform2iv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of ivec3s to `program`, `value` must hold a multiple of 3 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform3iv(program: Program, location: UniformLocation, value: &[GLint]) {
+    let count = element_count(value, 3);
+    gl_call!(program_uniform3iv, gl::ProgramUniform3iv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of ivec4s to `program`, `value` must hold a multiple of 4 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform4iv(program: Program, location: UniformLocation, value: &[GLint]) {
+    let count = element_count(value, 4);
+    gl_call!(program_uniform4iv, gl::ProgramUniform4iv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of uints to `program`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform1uiv(program: Program, location: UniformLocation, value: &[GLuint]) {
+    gl_call!(program_uniform1uiv, gl::ProgramUniform1uiv(program.0, location.0, value.len() as GLsizei, value.as_ptr()));
+}
+
+/// Uploads an array of uvec2s to `program`, `value` must hold a multiple of 2 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform2uiv(program: Program, location: UniformLocation, value: &[GLuint]) {
+    let count = element_count(value, 2);
+    gl_call!(program_uniform2uiv, gl::ProgramUniform2uiv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of uvec3s to `program`, `value` must hold a multiple of 3 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform3uiv(program: Program, location: UniformLocation, value: &[GLuint]) {
+    let count = element_count(value, 3);
+    gl_call!(program_uniform3uiv, gl::ProgramUniform3uiv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of uvec4s to `program`, `value` must hold a multiple of 4 values
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform4uiv(program: Program, location: UniformLocation, value: &[GLuint]) {
+    let count = element_count(value, 4);
+    gl_call!(program_uniform4uiv, gl::ProgramUniform4uiv(program.0, location.0, count, value.as_ptr()));
+}
+
+/// Uploads an array of 2x2 matrices to `program`, `value` must hold a multiple of 4 floats
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform_matrix2fv(program: Program, location: UniformLocation, transpose: bool, value: &[GLfloat]) {
+    let count = element_count(value, 4);
+    gl_call!(program_uniform_matrix2fv, gl::ProgramUniformMatrix2fv(program.0, location.0, count, transpose as GLboolean, value.as_ptr()));
+}
+
+/// Uploads an array of 3x3 matrices to `program`, `value` must hold a multiple of 9 floats
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform_matrix3fv(program: Program, location: UniformLocation, transpose: bool, value: &[GLfloat]) {
+    let count = element_count(value, 9);
+    gl_call!(program_uniform_matrix3fv, gl::ProgramUniformMatrix3fv(program.0, location.0, count, transpose as GLboolean, value.as_ptr()));
+}
+
+/// Uploads an array of 4x4 matrices to `program`, `value` must hold a multiple of 16 floats
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProgramUniform.xhtml
+pub fn program_uniform_matrix4fv(program: Program, location: UniformLocation, transpose: bool, value: &[GLfloat]) {
+    let count = element_count(value, 16);
+    gl_call!(program_uniform_matrix4fv, gl::ProgramUniformMatrix4fv(program.0, location.0, count, transpose as GLboolean, value.as_ptr()));
+}
+
+/// Uploads a single 4x4 column-major matrix to `program`
+pub fn program_uniform_mat4<M: Matrix4>(program: Program, location: UniformLocation, matrix: &M) {
+    program_uniform_matrix4fv(program, location, false, matrix.as_column_major());
+}
+
+//Limits
+
+/// The largest `max_vertices` a geometry shader can declare
+pub fn max_geometry_output_vertices() -> GLint {
+    let mut vertices = 0;
+    gl_call!(max_geometry_output_vertices, gl::GetIntegerv(gl::MAX_GEOMETRY_OUTPUT_VERTICES, &mut vertices));
+    vertices
+}
+
+/// The largest number of components a geometry shader invocation can emit across all vertices
+pub fn max_geometry_total_output_components() -> GLint {
+    let mut components = 0;
+    gl_call!(max_geometry_total_output_components, gl::GetIntegerv(gl::MAX_GEOMETRY_TOTAL_OUTPUT_COMPONENTS, &mut components));
+    components
+}
+
+/// The largest `invocations` a geometry shader can declare
+pub fn max_geometry_shader_invocations() -> GLint {
+    let mut invocations = 0;
+    gl_call!(max_geometry_shader_invocations, gl::GetIntegerv(gl::MAX_GEOMETRY_SHADER_INVOCATIONS, &mut invocations));
+    invocations
+}
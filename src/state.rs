@@ -0,0 +1,691 @@
+use gl;
+use gl::types::*;
+use std::mem;
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::enums;
+#[cfg(not(feature = "gles2"))]
+use super::errors;
+#[cfg(not(feature = "gles2"))]
+use super::info;
+
+/// Enable a server-side capability
+///
+/// # Examples
+/// ```rust,no_run
+/// rgl::enable(rgl::Capability::DepthTest);
+/// rgl::enable(rgl::Capability::CullFace);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glEnable.xhtml
+pub fn enable(cap: enums::Capability) {
+    gl_call!(enable, gl::Enable(cap as GLenum));
+}
+
+/// Disable a server-side capability
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glEnable.xhtml
+pub fn disable(cap: enums::Capability) {
+    gl_call!(disable, gl::Disable(cap as GLenum));
+}
+
+/// Returns whether a server-side capability is enabled
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsEnabled.xhtml
+pub fn is_enabled(cap: enums::Capability) -> bool {
+    gl_call!(is_enabled, gl::IsEnabled(cap as GLenum)) == gl::TRUE
+}
+
+/// Enable an indexed capability for only draw buffer `index`, like `Capability::Blend`, or viewport
+/// `index` for `Capability::ScissorTest`
+///
+/// `index` must be less than `Parameter::MaxDrawBuffers` (or `MaxViewports`), which the `error-check`
+/// feature reports as an invalid value. Requires GL 4.0 or `ARB_draw_buffers_blend`
+///
+/// # Examples
+/// ```rust,no_run
+/// //G-buffer where only the decal attachment, draw buffer 3, is blended
+/// rgl::disable(rgl::Capability::Blend);
+/// rgl::enable_i(rgl::Capability::Blend, 3);
+/// rgl::blend_func_i(3, rgl::BlendFactor::SrcAlpha, rgl::BlendFactor::OneMinusSrcAlpha);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glEnable.xhtml
+pub fn enable_i(cap: enums::Capability, index: GLuint) {
+    gl_call!(enable_i, gl::Enablei(cap as GLenum, index));
+}
+
+/// Disable an indexed capability for only draw buffer or viewport `index`, see `enable_i`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glEnable.xhtml
+pub fn disable_i(cap: enums::Capability, index: GLuint) {
+    gl_call!(disable_i, gl::Disablei(cap as GLenum, index));
+}
+
+/// Returns whether an indexed capability is enabled for draw buffer or viewport `index`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsEnabled.xhtml
+pub fn is_enabled_i(cap: enums::Capability, index: GLuint) -> bool {
+    gl_call!(is_enabled_i, gl::IsEnabledi(cap as GLenum, index)) == gl::TRUE
+}
+
+/// Enable clipping against user clip distance `index`, which discards the parts of primitives where
+/// the vertex shader wrote a negative `gl_ClipDistance[index]`
+///
+/// The vertex (or last geometry stage) shader must also write `gl_ClipDistance[index]`, otherwise
+/// the distance is undefined and primitives flicker or vanish at random. Fails with
+/// `GlError::InvalidValue`, without calling GL, if `index` is not less than
+/// `Parameter::MaxClipDistances`, which is queried on the first call only
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn clip() -> Result<(), rgl::GlError> {
+/// //Water reflections: render the scene mirrored, clipping away everything below the water, with
+/// //the vertex shader doing `gl_ClipDistance[0] = dot(world_position, clip_plane);`
+/// rgl::enable_clip_distance(0)?;
+/// //...draw the reflected scene
+/// rgl::disable_clip_distance(0)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/gl_ClipDistance.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn enable_clip_distance(index: GLuint) -> Result<(), errors::GlError> {
+    check_clip_distance(index)?;
+    gl_call!(enable_clip_distance, gl::Enable(gl::CLIP_DISTANCE0 + index));
+    Ok(())
+}
+
+/// Disable clipping against user clip distance `index`, see `enable_clip_distance`
+#[cfg(not(feature = "gles2"))]
+pub fn disable_clip_distance(index: GLuint) -> Result<(), errors::GlError> {
+    check_clip_distance(index)?;
+    gl_call!(disable_clip_distance, gl::Disable(gl::CLIP_DISTANCE0 + index));
+    Ok(())
+}
+
+/// `Parameter::MaxClipDistances`, 0 until the first clip distance call queries it
+#[cfg(not(feature = "gles2"))]
+static MAX_CLIP_DISTANCES: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(not(feature = "gles2"))]
+fn check_clip_distance(index: GLuint) -> Result<(), errors::GlError> {
+    let mut max = MAX_CLIP_DISTANCES.load(Ordering::Relaxed);
+    if max == 0 {
+        max = info::get_integer(enums::Parameter::MaxClipDistances).max(0) as usize;
+        MAX_CLIP_DISTANCES.store(max, Ordering::Relaxed);
+    }
+
+    if (index as usize) < max {
+        Ok(())
+    } else {
+        Err(errors::GlError::InvalidValue)
+    }
+}
+
+/// Set the viewport, the rectangle of the framebuffer that normalised device coordinates map to
+///
+/// # Examples
+/// ```rust,no_run
+/// //In a window resize handler
+/// # let (width, height) = (1280, 720);
+/// rgl::viewport(0, 0, width, height);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glViewport.xhtml
+pub fn viewport(x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    debug_assert!(width >= 0 && height >= 0, "viewport size must not be negative");
+    gl_call!(viewport, gl::Viewport(x, y, width, height));
+}
+
+/// Returns the current viewport as `(x, y, width, height)`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGet.xhtml
+pub fn get_viewport() -> (GLint, GLint, GLsizei, GLsizei) {
+    let mut rect = [0; 4];
+    gl_call!(get_viewport, gl::GetIntegerv(gl::VIEWPORT, rect.as_mut_ptr()));
+    (rect[0], rect[1], rect[2], rect[3])
+}
+
+/// Set the scissor box, fragments outside of it are discarded while the scissor test is enabled
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glScissor.xhtml
+pub fn scissor(x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    debug_assert!(width >= 0 && height >= 0, "scissor size must not be negative");
+    gl_call!(scissor, gl::Scissor(x, y, width, height));
+}
+
+/// Set viewport `index`, which geometry shaders select by writing `gl_ViewportIndex`
+///
+/// `viewport` sets every viewport at once. Requires GL 4.1 or `ARB_viewport_array`, there are
+/// `Parameter::MaxViewports` of them
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glViewportIndexed.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn viewport_indexed(index: GLuint, x: GLfloat, y: GLfloat, width: GLfloat, height: GLfloat) {
+    debug_assert!(width >= 0.0 && height >= 0.0, "viewport size must not be negative");
+    gl_call!(viewport_indexed, gl::ViewportIndexedf(index, x, y, width, height));
+}
+
+/// Set consecutive viewports starting at `first`, each rect is `[x, y, width, height]`
+///
+/// Requires GL 4.1 or `ARB_viewport_array`
+///
+/// # Examples
+/// ```rust,no_run
+/// //Stereo rendering in a single pass, the geometry shader is instanced twice and writes its
+/// //invocation id to gl_ViewportIndex
+/// # let (width, height) = (2160.0, 1200.0);
+/// rgl::viewport_array(0, &[
+///     [0.0, 0.0, width / 2.0, height],
+///     [width / 2.0, 0.0, width / 2.0, height],
+/// ]);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glViewportArray.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn viewport_array(first: GLuint, rects: &[[GLfloat; 4]]) {
+    gl_call!(viewport_array, gl::ViewportArrayv(first, rects.len() as GLsizei, rects.as_ptr() as *const GLfloat));
+}
+
+/// Set the scissor box of viewport `index`
+///
+/// Requires GL 4.1 or `ARB_viewport_array`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glScissorIndexed.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn scissor_indexed(index: GLuint, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    debug_assert!(width >= 0 && height >= 0, "scissor size must not be negative");
+    gl_call!(scissor_indexed, gl::ScissorIndexed(index, x, y, width, height));
+}
+
+/// Specify how source and destination colours are weighted when blending
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendFunc.xhtml
+pub fn blend_func(src: enums::BlendFactor, dst: enums::BlendFactor) {
+    gl_call!(blend_func, gl::BlendFunc(src as GLenum, dst as GLenum));
+}
+
+/// Specify how source and destination colours are weighted, separately for RGB and alpha
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendFuncSeparate.xhtml
+pub fn blend_func_separate(
+    src_rgb: enums::BlendFactor,
+    dst_rgb: enums::BlendFactor,
+    src_alpha: enums::BlendFactor,
+    dst_alpha: enums::BlendFactor,
+) {
+    gl_call!(blend_func_separate, gl::BlendFuncSeparate(src_rgb as GLenum, dst_rgb as GLenum, src_alpha as GLenum, dst_alpha as GLenum));
+}
+
+/// Specify how the weighted source and destination colours are combined
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendEquation.xhtml
+pub fn blend_equation(equation: enums::BlendEquation) {
+    gl_call!(blend_equation, gl::BlendEquation(equation as GLenum));
+}
+
+/// Specify how the weighted source and destination colours are combined, separately for RGB and alpha
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendEquationSeparate.xhtml
+pub fn blend_equation_separate(rgb: enums::BlendEquation, alpha: enums::BlendEquation) {
+    gl_call!(blend_equation_separate, gl::BlendEquationSeparate(rgb as GLenum, alpha as GLenum));
+}
+
+/// Like `blend_func`, for only draw buffer `buffer`
+///
+/// Requires GL 4.0 or `ARB_draw_buffers_blend`, see `enable_i`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendFunc.xhtml
+pub fn blend_func_i(buffer: GLuint, src: enums::BlendFactor, dst: enums::BlendFactor) {
+    gl_call!(blend_func_i, gl::BlendFunci(buffer, src as GLenum, dst as GLenum));
+}
+
+/// Like `blend_func_separate`, for only draw buffer `buffer`
+///
+/// Requires GL 4.0 or `ARB_draw_buffers_blend`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendFuncSeparate.xhtml
+pub fn blend_func_separate_i(
+    buffer: GLuint,
+    src_rgb: enums::BlendFactor,
+    dst_rgb: enums::BlendFactor,
+    src_alpha: enums::BlendFactor,
+    dst_alpha: enums::BlendFactor,
+) {
+    gl_call!(blend_func_separate_i, gl::BlendFuncSeparatei(
+        buffer,
+        src_rgb as GLenum,
+        dst_rgb as GLenum,
+        src_alpha as GLenum,
+        dst_alpha as GLenum
+    ));
+}
+
+/// Like `blend_equation`, for only draw buffer `buffer`
+///
+/// Requires GL 4.0 or `ARB_draw_buffers_blend`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendEquation.xhtml
+pub fn blend_equation_i(buffer: GLuint, equation: enums::BlendEquation) {
+    gl_call!(blend_equation_i, gl::BlendEquationi(buffer, equation as GLenum));
+}
+
+/// Like `blend_equation_separate`, for only draw buffer `buffer`
+///
+/// Requires GL 4.0 or `ARB_draw_buffers_blend`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendEquationSeparate.xhtml
+pub fn blend_equation_separate_i(buffer: GLuint, rgb: enums::BlendEquation, alpha: enums::BlendEquation) {
+    gl_call!(blend_equation_separate_i, gl::BlendEquationSeparatei(buffer, rgb as GLenum, alpha as GLenum));
+}
+
+/// Set the colour used by the `Constant*` blend factors
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBlendColor.xhtml
+pub fn blend_color(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+    gl_call!(blend_color, gl::BlendColor(red, green, blue, alpha));
+}
+
+/// Set up blending for colours that are not premultiplied by their alpha
+///
+/// # Examples
+/// ```rust,no_run
+/// rgl::enable(rgl::Capability::Blend);
+/// rgl::blend_straight_alpha();
+/// ```
+pub fn blend_straight_alpha() {
+    blend_equation(enums::BlendEquation::Add);
+    blend_func_separate(
+        enums::BlendFactor::SrcAlpha,
+        enums::BlendFactor::OneMinusSrcAlpha,
+        enums::BlendFactor::One,
+        enums::BlendFactor::OneMinusSrcAlpha,
+    );
+}
+
+/// Set up blending for colours that are premultiplied by their alpha
+pub fn blend_premultiplied_alpha() {
+    blend_equation(enums::BlendEquation::Add);
+    blend_func(enums::BlendFactor::One, enums::BlendFactor::OneMinusSrcAlpha);
+}
+
+/// Set the bitwise operation colours are written with while `Capability::ColorLogicOp` is enabled
+///
+/// Enabling the logic op disables blending, whatever the state of `Capability::Blend`, and it only
+/// applies to normalised integer and integer colour buffers, not float or sRGB ones. Desktop GL
+/// only, ES has no logic ops
+///
+/// # Examples
+/// ```rust,no_run
+/// //Selection rectangle that inverts whatever is behind it, drawn in white, and disappears when
+/// //drawn a second time
+/// rgl::enable(rgl::Capability::ColorLogicOp);
+/// rgl::logic_op(rgl::LogicOp::Xor);
+/// //...draw the rectangle's outline
+/// rgl::disable(rgl::Capability::ColorLogicOp);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glLogicOp.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn logic_op(op: enums::LogicOp) {
+    gl_call!(logic_op, gl::LogicOp(op as GLenum));
+}
+
+/// Specify the comparison used by the depth test
+///
+/// # Examples
+/// ```rust,no_run
+/// rgl::enable(rgl::Capability::DepthTest);
+/// rgl::depth_func(rgl::CompareFunc::Lequal);
+/// rgl::depth_mask(true);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDepthFunc.xhtml
+pub fn depth_func(func: enums::CompareFunc) {
+    gl_call!(depth_func, gl::DepthFunc(func as GLenum));
+}
+
+/// Enable or disable writing into the depth buffer
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDepthMask.xhtml
+pub fn depth_mask(write: bool) {
+    gl_call!(depth_mask, gl::DepthMask(write as GLboolean));
+}
+
+/// Enable or disable writing each component into the colour buffers
+///
+/// # Examples
+/// ```rust,no_run
+/// //Depth prepass: fill the depth buffer without shading anything
+/// rgl::color_mask(false, false, false, false);
+/// rgl::depth_mask(true);
+/// rgl::depth_func(rgl::CompareFunc::Less);
+/// //...draw the opaque geometry with a trivial program
+///
+/// //Then shade only the visible fragments, the depth buffer is already complete
+/// rgl::color_mask(true, true, true, true);
+/// rgl::depth_mask(false);
+/// rgl::depth_func(rgl::CompareFunc::Equal);
+/// //...draw the opaque geometry again with the real materials
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glColorMask.xhtml
+pub fn color_mask(red: bool, green: bool, blue: bool, alpha: bool) {
+    gl_call!(color_mask, gl::ColorMask(red as GLboolean, green as GLboolean, blue as GLboolean, alpha as GLboolean));
+}
+
+/// Like `color_mask`, for only draw buffer `buffer` of a framebuffer with several colour attachments
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glColorMask.xhtml
+pub fn color_mask_i(buffer: GLuint, red: bool, green: bool, blue: bool, alpha: bool) {
+    gl_call!(color_mask_i, gl::ColorMaski(buffer, red as GLboolean, green as GLboolean, blue as GLboolean, alpha as GLboolean));
+}
+
+/// Specify the mapping of depth values from normalised device coordinates to window coordinates
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDepthRange.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn depth_range(near: GLdouble, far: GLdouble) {
+    gl_call!(depth_range, gl::DepthRange(near, far));
+}
+
+/// Specify the mapping of depth values, the single precision version available on ES
+///
+/// Prefer this in code that also targets ES. Requires GL 4.1 or `ARB_ES2_compatibility` on desktop
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDepthRange.xhtml
+pub fn depth_rangef(near: GLfloat, far: GLfloat) {
+    gl_call!(depth_rangef, gl::DepthRangef(near, far));
+}
+
+/// Like `depth_range`, for only viewport `index`
+///
+/// Requires GL 4.1 or `ARB_viewport_array`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDepthRangeIndexed.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn depth_range_indexed(index: GLuint, near: GLdouble, far: GLdouble) {
+    gl_call!(depth_range_indexed, gl::DepthRangeIndexed(index, near, far));
+}
+
+/// Set the fraction of samples of each fragment that are written while `Capability::SampleCoverage`
+/// is enabled, with `invert` writing the other samples instead
+///
+/// Only has an effect on multisampled framebuffers
+///
+/// # Examples
+/// ```rust,no_run
+/// //Cross fade two LODs without blending or sorting, each covering the samples the other leaves
+/// # let fade = 0.25;
+/// rgl::enable(rgl::Capability::SampleCoverage);
+/// rgl::sample_coverage(fade, false);
+/// //...draw the new LOD
+/// rgl::sample_coverage(fade, true);
+/// //...draw the old LOD
+/// rgl::disable(rgl::Capability::SampleCoverage);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glSampleCoverage.xhtml
+pub fn sample_coverage(value: GLfloat, invert: bool) {
+    gl_call!(sample_coverage, gl::SampleCoverage(value, invert as GLboolean));
+}
+
+/// Set the function and reference value of the stencil test
+///
+/// # Examples
+/// ```rust,no_run
+/// use rgl::{CompareFunc, StencilOp};
+///
+/// rgl::enable(rgl::Capability::StencilTest);
+///
+/// //Write 1s wherever the mask mesh is rendered, without touching the colour buffer
+/// rgl::stencil_func(CompareFunc::Always, 1, 0xFF);
+/// rgl::stencil_op(StencilOp::Keep, StencilOp::Keep, StencilOp::Replace);
+/// rgl::stencil_mask(0xFF);
+/// //...draw the mask mesh
+///
+/// //Only draw where the stencil buffer is 1, leaving it unchanged
+/// rgl::stencil_func(CompareFunc::Equal, 1, 0xFF);
+/// rgl::stencil_mask(0x00);
+/// //...draw the masked scene
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glStencilFunc.xhtml
+pub fn stencil_func(func: enums::CompareFunc, reference: GLint, mask: GLuint) {
+    gl_call!(stencil_func, gl::StencilFunc(func as GLenum, reference, mask));
+}
+
+/// Set the function and reference value of the stencil test for the given faces
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glStencilFuncSeparate.xhtml
+pub fn stencil_func_separate(face: enums::Face, func: enums::CompareFunc, reference: GLint, mask: GLuint) {
+    gl_call!(stencil_func_separate, gl::StencilFuncSeparate(face as GLenum, func as GLenum, reference, mask));
+}
+
+/// Set the actions taken when the stencil test fails, the depth test fails, or both pass
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glStencilOp.xhtml
+pub fn stencil_op(sfail: enums::StencilOp, dpfail: enums::StencilOp, dppass: enums::StencilOp) {
+    gl_call!(stencil_op, gl::StencilOp(sfail as GLenum, dpfail as GLenum, dppass as GLenum));
+}
+
+/// Set the stencil actions for the given faces
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glStencilOpSeparate.xhtml
+pub fn stencil_op_separate(
+    face: enums::Face,
+    sfail: enums::StencilOp,
+    dpfail: enums::StencilOp,
+    dppass: enums::StencilOp,
+) {
+    gl_call!(stencil_op_separate, gl::StencilOpSeparate(face as GLenum, sfail as GLenum, dpfail as GLenum, dppass as GLenum));
+}
+
+/// Set which bits of the stencil buffer can be written
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glStencilMask.xhtml
+pub fn stencil_mask(mask: GLuint) {
+    gl_call!(stencil_mask, gl::StencilMask(mask));
+}
+
+/// Set which bits of the stencil buffer can be written for the given faces
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glStencilMaskSeparate.xhtml
+pub fn stencil_mask_separate(face: enums::Face, mask: GLuint) {
+    gl_call!(stencil_mask_separate, gl::StencilMaskSeparate(face as GLenum, mask));
+}
+
+/// Specify which faces are culled while face culling is enabled
+///
+/// # Examples
+/// ```rust,no_run
+/// rgl::enable(rgl::Capability::CullFace);
+/// rgl::cull_face(rgl::Face::Back);
+/// rgl::front_face(rgl::Winding::Ccw);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCullFace.xhtml
+pub fn cull_face(face: enums::Face) {
+    gl_call!(cull_face, gl::CullFace(face as GLenum));
+}
+
+/// Specify the winding order of front-facing polygons
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFrontFace.xhtml
+pub fn front_face(winding: enums::Winding) {
+    gl_call!(front_face, gl::FrontFace(winding as GLenum));
+}
+
+/// Select which vertex of each primitive provides the value of `flat` outputs
+///
+/// Outputs declared `flat` are not interpolated, every fragment of the primitive gets the value of
+/// its provoking vertex, so flat shaded meshes need their face normal on that vertex
+///
+/// # Examples
+/// ```rust,no_run
+/// //Low poly terrain with one colour per triangle, `flat out vec3 colour;` in the vertex shader,
+/// //where the index buffer lists each triangle's coloured vertex first
+/// rgl::provoking_vertex(rgl::ProvokingVertex::FirstVertexConvention);
+/// //...draw the terrain
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProvokingVertex.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn provoking_vertex(convention: enums::ProvokingVertex) {
+    gl_call!(provoking_vertex, gl::ProvokingVertex(convention as GLenum));
+}
+
+/// Whether `provoking_vertex` also applies to quads, implementations may always use their own rule
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glProvokingVertex.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn quads_follow_provoking_vertex_convention() -> bool {
+    info::get_boolean(enums::Parameter::QuadsFollowProvokingVertexConvention)
+}
+
+/// Set the scale and units used to offset polygon depth values, see `Capability::PolygonOffsetFill`
+///
+/// # Examples
+/// ```rust,no_run
+/// # let shadow_map = rgl::gen_framebuffer();
+/// //Shadow pass: push the casters' depth away from the light to stop shadow acne, clamping the
+/// //offset so steep polygons don't detach their shadows (peter panning), and clamp depth so casters
+/// //between the light and the near plane still cast shadows
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Draw, shadow_map);
+/// rgl::enable(rgl::Capability::DepthClamp);
+/// rgl::enable(rgl::Capability::PolygonOffsetFill);
+/// rgl::polygon_offset_clamp(2.0, 4.0, 0.01);
+/// //...draw the shadow casters
+///
+/// rgl::disable(rgl::Capability::PolygonOffsetFill);
+/// rgl::disable(rgl::Capability::DepthClamp);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPolygonOffset.xhtml
+pub fn polygon_offset(factor: GLfloat, units: GLfloat) {
+    gl_call!(polygon_offset, gl::PolygonOffset(factor, units));
+}
+
+type PolygonOffsetClamp = unsafe extern "system" fn(GLfloat, GLfloat, GLfloat);
+
+/// Address of `glPolygonOffsetClamp`, which the GL 4.5 bindings do not have, 0 until loaded
+static POLYGON_OFFSET_CLAMP: AtomicUsize = AtomicUsize::new(0);
+
+/// Loads `polygon_offset_clamp` from GL 4.6, or from `EXT_polygon_offset_clamp` on older contexts
+///
+/// `loader` is the same function given to `gl::load_with`. Returns whether either was found
+///
+/// # Examples
+/// ```rust,ignore
+/// gl::load_with(|symbol| context.get_proc_address(symbol) as *const _);
+/// rgl::load_polygon_offset_clamp(|symbol| context.get_proc_address(symbol) as *const _);
+/// ```
+pub fn load_polygon_offset_clamp<F>(mut loader: F) -> bool
+where
+    F: FnMut(&'static str) -> *const c_void,
+{
+    let mut address = loader("glPolygonOffsetClamp");
+    if address.is_null() {
+        address = loader("glPolygonOffsetClampEXT");
+    }
+    POLYGON_OFFSET_CLAMP.store(address as usize, Ordering::SeqCst);
+    !address.is_null()
+}
+
+/// Whether `polygon_offset_clamp` was loaded by `load_polygon_offset_clamp`
+pub fn supports_polygon_offset_clamp() -> bool {
+    POLYGON_OFFSET_CLAMP.load(Ordering::SeqCst) != 0
+}
+
+/// Like `polygon_offset`, with the offset limited to at most `clamp` (or at least, if negative)
+///
+/// Falls back to `polygon_offset`, ignoring `clamp`, unless `load_polygon_offset_clamp` found the
+/// function
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPolygonOffsetClamp.xhtml
+pub fn polygon_offset_clamp(factor: GLfloat, units: GLfloat, clamp: GLfloat) {
+    let address = POLYGON_OFFSET_CLAMP.load(Ordering::SeqCst);
+    if address == 0 {
+        polygon_offset(factor, units);
+        return;
+    }
+
+    let function: PolygonOffsetClamp = unsafe { mem::transmute(address) };
+    gl_call!(polygon_offset_clamp, function(factor, units, clamp));
+}
+
+/// Select how the given faces of polygons are rasterised
+///
+/// Core profile contexts only accept `Face::FrontAndBack`, prefer `polygon_mode_all` there
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPolygonMode.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn polygon_mode(face: enums::Face, mode: enums::PolygonMode) {
+    gl_call!(polygon_mode, gl::PolygonMode(face as GLenum, mode as GLenum));
+}
+
+/// Select how both faces of polygons are rasterised, the only form allowed by core profile
+///
+/// # Examples
+/// ```rust,no_run
+/// //Toggle wireframe rendering
+/// rgl::polygon_mode_all(rgl::PolygonMode::Line);
+/// rgl::line_width(2.0);
+/// ```
+#[cfg(not(feature = "gles2"))]
+pub fn polygon_mode_all(mode: enums::PolygonMode) {
+    polygon_mode(enums::Face::FrontAndBack, mode);
+}
+
+/// Set the width of rasterised lines
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glLineWidth.xhtml
+pub fn line_width(width: GLfloat) {
+    gl_call!(line_width, gl::LineWidth(width));
+}
+
+/// Set the diameter of rasterised points, unless `Capability::ProgramPointSize` is enabled
+///
+/// Sizes are clamped to the range read with `get_float2(Parameter::PointSizeRange)`
+///
+/// # Examples
+/// ```rust,no_run
+/// let [min, max] = rgl::get_float2(rgl::Parameter::PointSizeRange);
+/// rgl::point_size(16.0_f32.clamp(min, max));
+/// rgl::draw_arrays(rgl::Primitive::Points, 0, 1000);
+///
+/// //Or size each particle in the vertex shader by writing gl_PointSize
+/// rgl::enable(rgl::Capability::ProgramPointSize);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPointSize.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn point_size(size: GLfloat) {
+    gl_call!(point_size, gl::PointSize(size));
+}
+
+/// Set the size multisampled points fade out below, rather than shrinking further
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPointParameter.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn point_fade_threshold_size(size: GLfloat) {
+    gl_call!(point_fade_threshold_size, gl::PointParameterf(gl::POINT_FADE_THRESHOLD_SIZE, size));
+}
+
+/// Set the corner of point sprites that `gl_PointCoord` is `(0, 0)` at
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPointParameter.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn point_sprite_coord_origin(origin: enums::SpriteCoordOrigin) {
+    gl_call!(point_sprite_coord_origin, gl::PointParameteri(gl::POINT_SPRITE_COORD_ORIGIN, origin as GLint));
+}
+
+/// Suggest how the implementation trades speed for quality, drivers are free to ignore it
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glHint.xhtml
+pub fn hint(target: enums::HintTarget, mode: enums::HintMode) {
+    gl_call!(hint, gl::Hint(target as GLenum, mode as GLenum));
+}
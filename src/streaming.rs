@@ -0,0 +1,268 @@
+//! Streaming per-frame data through a persistently mapped ring buffer
+//!
+//! A `StreamingBuffer` splits one immutable, persistently mapped buffer into regions, and hands out
+//! a region per frame for the CPU to write while the GPU may still be reading the regions of the
+//! previous frames. Each region is guarded by a fence covering the frame's draws, so the CPU only
+//! waits when it gets a whole ring ahead of the GPU. Requires GL 4.5 or `ARB_buffer_storage` and
+//! `ARB_direct_state_access`.
+//!
+//! # Examples
+//! ```rust
+//! extern crate gl;
+//! extern crate rgl;
+//!
+//! use gl::types::*;
+//! use std::os::raw::c_void;
+//! use std::ptr;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! const REGION: usize = 256;
+//! const REGIONS: usize = 3;
+//!
+//! static MEMORY: AtomicUsize = AtomicUsize::new(0);
+//! static FENCES: AtomicUsize = AtomicUsize::new(0);
+//! static WAITS: AtomicUsize = AtomicUsize::new(0);
+//! static FLUSHES: AtomicUsize = AtomicUsize::new(0);
+//!
+//! extern "system" fn create_buffers(_n: GLsizei, buffers: *mut GLuint) {
+//!     unsafe { *buffers = 1 };
+//! }
+//!
+//! extern "system" fn named_buffer_storage(_buffer: GLuint, _size: GLsizeiptr, _data: *const c_void, _flags: GLbitfield) {}
+//!
+//! extern "system" fn map_named_buffer_range(_buffer: GLuint, _offset: GLintptr, length: GLsizeiptr, _access: GLbitfield) -> *mut c_void {
+//!     let memory = Box::leak(vec![0u8; length as usize].into_boxed_slice()).as_mut_ptr();
+//!     MEMORY.store(memory as usize, Ordering::SeqCst);
+//!     memory as *mut c_void
+//! }
+//!
+//! extern "system" fn flush_mapped_named_buffer_range(_buffer: GLuint, offset: GLintptr, length: GLsizeiptr) {
+//!     assert_eq!(offset as usize % REGION, 0);
+//!     assert_eq!(length as usize, REGION);
+//!     FLUSHES.fetch_add(1, Ordering::SeqCst);
+//! }
+//!
+//! //The fence of frame n is the pointer n + 1, each frame is fenced when the next one begins
+//! extern "system" fn fence_sync(_condition: GLenum, _flags: GLbitfield) -> GLsync {
+//!     (FENCES.fetch_add(1, Ordering::SeqCst) + 1) as GLsync
+//! }
+//!
+//! //The GPU reads a frame's region when the CPU waits on its fence, so it must still hold exactly
+//! //what the CPU wrote that frame
+//! extern "system" fn client_wait_sync(sync: GLsync, _flags: GLbitfield, _timeout: GLuint64) -> GLenum {
+//!     let frame = sync as usize - 1;
+//!     let memory = MEMORY.load(Ordering::SeqCst) as *const u8;
+//!     let region = unsafe { std::slice::from_raw_parts(memory.add(frame % REGIONS * REGION), REGION) };
+//!     assert!(region.iter().all(|&byte| byte == frame as u8), "frame {} was torn", frame);
+//!
+//!     WAITS.fetch_add(1, Ordering::SeqCst);
+//!     gl::CONDITION_SATISFIED
+//! }
+//!
+//! extern "system" fn delete_sync(_sync: GLsync) {}
+//!
+//! extern "system" fn unmap_named_buffer(_buffer: GLuint) -> GLboolean {
+//!     gl::TRUE
+//! }
+//!
+//! extern "system" fn delete_buffers(_n: GLsizei, _buffers: *const GLuint) {}
+//!
+//! extern "system" fn get_error() -> GLenum {
+//!     gl::NO_ERROR
+//! }
+//!
+//! # fn main() {
+//! //Stream a counter pattern through stand ins for the driver's functions
+//! gl::load_with(|symbol| match symbol {
+//!     "glCreateBuffers" => create_buffers as *const c_void,
+//!     "glNamedBufferStorage" => named_buffer_storage as *const c_void,
+//!     "glMapNamedBufferRange" => map_named_buffer_range as *const c_void,
+//!     "glFlushMappedNamedBufferRange" => flush_mapped_named_buffer_range as *const c_void,
+//!     "glFenceSync" => fence_sync as *const c_void,
+//!     "glClientWaitSync" => client_wait_sync as *const c_void,
+//!     "glDeleteSync" => delete_sync as *const c_void,
+//!     "glUnmapNamedBuffer" => unmap_named_buffer as *const c_void,
+//!     "glDeleteBuffers" => delete_buffers as *const c_void,
+//!     "glGetError" => get_error as *const c_void,
+//!     _ => ptr::null(),
+//! });
+//!
+//! let mut stream = rgl::StreamingBuffer::new(REGION * REGIONS, REGIONS, false).unwrap();
+//! for frame in 0..100 {
+//!     stream.begin_frame().fill(frame as u8);
+//!     assert_eq!(stream.end_frame(), frame % REGIONS * REGION);
+//! }
+//!
+//! //Only the first lap of the ring is written without waiting
+//! assert_eq!(WAITS.load(Ordering::SeqCst), 100 - REGIONS);
+//! assert_eq!(FLUSHES.load(Ordering::SeqCst), 100);
+//! stream.delete();
+//! # }
+//! ```
+
+use super::buffers::{self, Buffer, MapError, MappedBuffer};
+use super::dsa;
+use super::enums;
+use super::sync::{self, Fence};
+
+use std::mem::ManuallyDrop;
+
+/// A persistently mapped buffer split into `regions` regions, written one per frame
+///
+/// Call `begin_frame` to get the next region, write the frame's data to it, then `end_frame` for
+/// the byte offset to source it from, in `bind_buffer_range` or as a vertex buffer offset. The
+/// fence guarding a region is inserted by the next `begin_frame`, so it covers the draws issued
+/// in between, and every draw reading a region has to be issued before then. Offsets are multiples
+/// of the region size, so pick a total size that keeps them aligned for the use, see
+/// `uniform_buffer_offset_alignment`
+///
+/// Dropping it unmaps and deletes the buffer and deletes the fences, so it must be dropped while
+/// its context is current
+///
+/// # Examples
+/// ```rust,no_run
+/// # fn stream() -> Result<(), rgl::MapError> {
+/// //Per-frame uniforms, triple buffered
+/// let mut uniforms = rgl::StreamingBuffer::new(3 * 64 * 1024, 3, true)?;
+///
+/// loop {
+///     let region = uniforms.begin_frame();
+///     region[..4].copy_from_slice(&1.0f32.to_ne_bytes());
+///     let offset = uniforms.end_frame();
+///
+///     rgl::bind_buffer_range(rgl::IndexedTarget::UniformBuffer, 0, uniforms.buffer(), offset, uniforms.region_size());
+///     //...draw the frame
+/// }
+/// # }
+/// ```
+pub struct StreamingBuffer {
+    buffer: Buffer,
+    /// Dropped by hand, so the buffer is unmapped before it is deleted
+    mapped: ManuallyDrop<MappedBuffer>,
+    region_size: usize,
+    coherent: bool,
+    fences: Vec<Option<Fence>>,
+    current: usize,
+    /// The region of the last ended frame, which is fenced by the next `begin_frame`
+    unfenced: Option<usize>,
+    in_frame: bool,
+}
+
+impl StreamingBuffer {
+    /// Creates a buffer of `size` bytes, split into `regions` regions of equal size, and maps it
+    ///
+    /// With `coherent`, writes are visible to the GPU as they happen. Otherwise each region is
+    /// flushed in `end_frame`, which is faster on some drivers
+    ///
+    /// # Panics
+    /// If `regions` is 0, or `size` is not a multiple of it
+    pub fn new(size: usize, regions: usize, coherent: bool) -> Result<StreamingBuffer, MapError> {
+        assert!(regions > 0, "StreamingBuffer needs at least one region");
+        assert!(
+            size.is_multiple_of(regions),
+            "StreamingBuffer size {} is not a multiple of {} regions",
+            size,
+            regions
+        );
+
+        let mut flags = enums::StorageFlags::MAP_WRITE | enums::StorageFlags::MAP_PERSISTENT;
+        let mut access = enums::MapAccess::WRITE | enums::MapAccess::PERSISTENT;
+        if coherent {
+            flags |= enums::StorageFlags::MAP_COHERENT;
+            access |= enums::MapAccess::COHERENT;
+        } else {
+            access |= enums::MapAccess::FLUSH_EXPLICIT;
+        }
+
+        let buffer = buffers::create_buffer();
+        dsa::named_buffer_storage_reserve(buffer, size, flags);
+        let mapped = match dsa::map_named_buffer_range(buffer, 0, size, access) {
+            Ok(mapped) => mapped,
+            Err(error) => {
+                buffers::delete_buffer(buffer);
+                return Err(error);
+            }
+        };
+
+        Ok(StreamingBuffer {
+            buffer,
+            mapped: ManuallyDrop::new(mapped),
+            region_size: size / regions,
+            coherent,
+            fences: (0..regions).map(|_| None).collect(),
+            current: 0,
+            unfenced: None,
+            in_frame: false,
+        })
+    }
+
+    /// The buffer object, to bind for drawing
+    pub fn buffer(&self) -> Buffer {
+        self.buffer
+    }
+
+    /// Bytes in each region
+    pub fn region_size(&self) -> usize {
+        self.region_size
+    }
+
+    /// Returns the next region to write, first waiting for the GPU to finish reading it if it was
+    /// used a whole ring ago
+    ///
+    /// Fences the region of the previous frame, after everything issued since its `end_frame`
+    ///
+    /// # Panics
+    /// If the previous frame was not ended with `end_frame`
+    pub fn begin_frame(&mut self) -> &mut [u8] {
+        assert!(!self.in_frame, "begin_frame called twice without end_frame");
+        self.in_frame = true;
+
+        if let Some(region) = self.unfenced.take() {
+            self.fences[region] = Some(sync::fence_sync());
+        }
+        if let Some(fence) = self.fences[self.current].take() {
+            while sync::client_wait_sync(&fence, true, 1_000_000_000) == enums::WaitStatus::TimeoutExpired {}
+            sync::delete_sync(fence);
+        }
+
+        let start = self.current * self.region_size;
+        &mut self.mapped[start..start + self.region_size]
+    }
+
+    /// Ends the frame started with `begin_frame`, returning the byte offset of its region
+    ///
+    /// Without `coherent`, this is also where the region is flushed. The region is not fenced yet,
+    /// that is deferred to the next `begin_frame` so the fence covers the draws issued in between.
+    /// Every draw reading the region has to be issued before then
+    ///
+    /// # Panics
+    /// If no frame was started with `begin_frame`
+    pub fn end_frame(&mut self) -> usize {
+        assert!(self.in_frame, "end_frame called without begin_frame");
+        self.in_frame = false;
+
+        let offset = self.current * self.region_size;
+        if !self.coherent {
+            self.mapped.flush_range(offset, self.region_size);
+        }
+
+        self.unfenced = Some(self.current);
+        self.current = (self.current + 1) % self.fences.len();
+        offset
+    }
+
+    /// Unmaps and deletes the buffer, and deletes the fences, the same as dropping it
+    pub fn delete(self) {
+        drop(self);
+    }
+}
+
+impl Drop for StreamingBuffer {
+    fn drop(&mut self) {
+        for fence in self.fences.drain(..).flatten() {
+            sync::delete_sync(fence);
+        }
+        unsafe { ManuallyDrop::drop(&mut self.mapped) };
+        buffers::delete_buffer(self.buffer);
+    }
+}
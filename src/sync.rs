@@ -0,0 +1,116 @@
+use super::enums;
+
+use gl;
+use gl::types::*;
+
+/// A fence in the GL command stream, signaled once every command before it has completed
+///
+/// Not `Copy`, as `delete_sync` consumes it. Named `Fence` rather than after `GLsync`, so that a
+/// glob import of the crate does not shadow `std::marker::Sync`
+///
+/// # Examples
+/// ```rust
+/// use rgl::*;
+///
+/// fn share<T: Sync>(_value: &T) {}
+/// share(&Buffer(1));
+/// ```
+#[derive(Debug)]
+pub struct Fence(pub GLsync);
+
+/// Inserts a fence into the command stream
+///
+/// # Examples
+#[cfg_attr(not(feature = "gles2"), doc = "```rust,no_run")]
+#[cfg_attr(feature = "gles2", doc = "```rust,ignore")]
+/// # fn stream() -> Result<(), rgl::MapError> {
+/// //Triple buffered streaming: the CPU writes one third of a persistently mapped buffer while the
+/// //GPU may still be reading the other two, with a fence guarding each third
+/// const SECTION: usize = 64 * 1024;
+///
+/// let flags = rgl::StorageFlags::MAP_WRITE | rgl::StorageFlags::MAP_PERSISTENT | rgl::StorageFlags::MAP_COHERENT;
+/// rgl::buffer_storage_reserve(rgl::Target::ArrayBuffer, SECTION * 3, flags);
+/// let access = rgl::MapAccess::WRITE | rgl::MapAccess::PERSISTENT | rgl::MapAccess::COHERENT;
+/// let mut mapped = rgl::map_buffer_range(rgl::Target::ArrayBuffer, 0, SECTION * 3, access)?;
+///
+/// let mut fences: [Option<rgl::Fence>; 3] = [None, None, None];
+/// let mut section = 0;
+/// loop {
+///     //Wait until the GPU has finished with this third, three frames ago
+///     if let Some(fence) = fences[section].take() {
+///         while rgl::client_wait_sync(&fence, true, 1_000_000) == rgl::WaitStatus::TimeoutExpired {}
+///         rgl::delete_sync(fence);
+///     }
+///
+///     mapped[section * SECTION..(section + 1) * SECTION].fill(0);
+///     //...draw from this third
+///
+///     fences[section] = Some(rgl::fence_sync());
+///     section = (section + 1) % 3;
+/// }
+/// # }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFenceSync.xhtml
+pub fn fence_sync() -> Fence {
+    Fence(gl_call!(fence_sync, gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0)))
+}
+
+/// Blocks the CPU until `sync` is signaled or `timeout_ns` nanoseconds have passed
+///
+/// With `flush`, the command stream is flushed first so that the fence is guaranteed to be signaled eventually
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClientWaitSync.xhtml
+pub fn client_wait_sync(sync: &Fence, flush: bool, timeout_ns: u64) -> enums::WaitStatus {
+    let flags = if flush { gl::SYNC_FLUSH_COMMANDS_BIT } else { 0 };
+
+    match gl_call!(client_wait_sync, gl::ClientWaitSync(sync.0, flags, timeout_ns)) {
+        gl::ALREADY_SIGNALED => enums::WaitStatus::AlreadySignaled,
+        gl::CONDITION_SATISFIED => enums::WaitStatus::ConditionSatisfied,
+        gl::TIMEOUT_EXPIRED => enums::WaitStatus::TimeoutExpired,
+        _ => enums::WaitStatus::Failed,
+    }
+}
+
+/// Makes the GPU wait for `sync` before executing later commands, without blocking the CPU
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glWaitSync.xhtml
+pub fn wait_sync(sync: &Fence) {
+    gl_call!(wait_sync, gl::WaitSync(sync.0, 0, gl::TIMEOUT_IGNORED));
+}
+
+/// Deletes a fence, it is not a problem to delete one that has not been signaled yet
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteSync.xhtml
+pub fn delete_sync(sync: Fence) {
+    gl_call!(delete_sync, gl::DeleteSync(sync.0));
+}
+
+/// Sends every command issued so far to the GPU, without waiting for them to complete
+///
+/// Needed before another context waits on work from this one, like a fence created here
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFlush.xhtml
+pub fn flush() {
+    gl_call!(flush, gl::Flush());
+}
+
+/// Blocks until every command issued so far has completed
+///
+/// Stalls the CPU and GPU against each other, so it is mostly useful for benchmarks
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::time::Instant;
+///
+/// rgl::finish();
+/// let start = Instant::now();
+/// //...the draws being measured
+/// rgl::finish();
+/// println!("took {:?}", start.elapsed());
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glFinish.xhtml
+pub fn finish() {
+    gl_call!(finish, gl::Finish());
+}
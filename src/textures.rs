@@ -1,79 +1,1866 @@
+use super::buffers::{self, Plain};
 use super::enums;
+use super::framebuffers::Renderbuffer;
+use super::info;
 
 use gl;
 use gl::types::*;
+#[cfg(feature = "image")]
+use image::DynamicImage;
+#[cfg(all(feature = "image", not(feature = "gles2")))]
+use image::{imageops, RgbaImage};
+use std::cell::Cell;
 use std::os::raw::c_void;
+use std::ptr;
+use std::sync::atomic::{AtomicU8, Ordering};
 
-#[derive(Clone, Copy)]
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
 pub struct Texture(pub GLuint);
 
-pub fn gen_textures(count: GLsizei, texture: *mut Texture) {
-    unsafe {
-        gl::GenTextures(count, &mut (*texture).0);
-    }
+impl Texture {
+    /// The name 0, which binds no texture object, leaving the default texture bound
+    pub const NONE: Texture = Texture(0);
+}
+
+/// Generates texture objects, one for every element of the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenTextures.xhtml
+pub fn gen_textures(textures: &mut [Texture]) {
+    gl_call!(gen_textures, gl::GenTextures(textures.len() as GLsizei, textures.as_mut_ptr() as *mut GLuint));
 }
 
+/// Generates a single texture
+///
+/// # Examples
+/// ```rust,no_run
+/// let texture = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, texture);
+/// ```
 pub fn gen_texture() -> Texture {
-    let mut tex = Texture(0);
-    gen_textures(1, &mut tex);
-    tex
+    let mut tex = [Texture(0)];
+    gen_textures(&mut tex);
+    tex[0]
+}
+
+/// Generates `count` texture objects with a single call, see `gen_textures`
+///
+/// A `count` of 0 returns an empty Vec without calling GL
+pub fn gen_textures_vec(count: usize) -> Vec<Texture> {
+    let mut textures = vec![Texture(0); count];
+    if count > 0 {
+        gen_textures(&mut textures);
+    }
+    textures
+}
+
+/// Creates texture objects of the given target, one for every element of the slice
+///
+/// Unlike `gen_textures`, the objects exist straight away, so they can be used with the direct
+/// state access functions without binding them first. Requires GL 4.5 or `ARB_direct_state_access`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCreateTextures.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn create_textures(target: enums::TextureTarget, textures: &mut [Texture]) {
+    gl_call!(create_textures, gl::CreateTextures(target as GLenum, textures.len() as GLsizei, textures.as_mut_ptr() as *mut GLuint));
+}
+
+/// Creates a single texture, see `create_textures`
+#[cfg(not(feature = "gles2"))]
+pub fn create_texture(target: enums::TextureTarget) -> Texture {
+    let mut tex = [Texture(0)];
+    create_textures(target, &mut tex);
+    tex[0]
+}
+
+/// Selects the active texture unit, `unit` is the index of the unit rather than a `GL_TEXTUREi` token
+///
+/// In debug builds this asserts that `unit` is below `GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glActiveTexture.xhtml
+pub fn active_texture(unit: GLuint) {
+    debug_assert!(
+        (unit as GLint) < max_combined_texture_image_units(),
+        "texture unit {} is out of range",
+        unit
+    );
+    gl_call!(active_texture, gl::ActiveTexture(gl::TEXTURE0 + unit));
+}
+
+/// Binds a texture to a texture unit, combining `active_texture` and `bind_texture`
+///
+/// This leaves `unit` as the active texture unit
+///
+/// # Examples
+/// ```rust,no_run
+/// let program = rgl::create_program();
+/// let texture = rgl::gen_texture();
+/// //...
+/// rgl::bind_texture_unit(3, rgl::TextureTarget::Texture2d, texture);
+///
+/// let sampler = rgl::get_uniform_location(program, "u_texture").unwrap();
+/// rgl::use_program(program);
+/// rgl::uniform1i(sampler, 3);
+/// ```
+pub fn bind_texture_unit<H: Into<Texture>>(unit: GLuint, target: enums::TextureTarget, texture: H) {
+    active_texture(unit);
+    bind_texture(target, texture);
+}
+
+fn max_combined_texture_image_units() -> GLint {
+    let mut units = 0;
+    gl_call!(max_combined_texture_image_units, gl::GetIntegerv(gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS, &mut units));
+    units
+}
+
+/// Binds a texture to a texture target of the active texture unit
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindTexture.xhtml
+pub fn bind_texture<H: Into<Texture>>(target: enums::TextureTarget, texture: H) {
+    let texture: Texture = texture.into();
+    gl_call!(bind_texture, gl::BindTexture(target as GLenum, texture.0));
+}
+
+/// Unbinds the texture bound to `target` of the active texture unit
+///
+/// This binds the target's default texture, which has no images, so sampling it is incomplete and
+/// returns black
+pub fn unbind_texture(target: enums::TextureTarget) {
+    gl_call!(unbind_texture, gl::BindTexture(target as GLenum, 0));
+}
+
+/// Binds consecutive texture units starting at `first_unit` in one call, `None` unbinds every
+/// target of that unit
+///
+/// Each texture is bound to the target it was created with, and this leaves the active texture unit
+/// alone. Requires GL 4.4 or `ARB_multi_bind`, check with `supports_multi_bind`
+///
+/// # Examples
+/// ```rust,no_run
+/// # let (albedo, normal, roughness) = (rgl::gen_texture(), rgl::gen_texture(), rgl::gen_texture());
+/// let textures = [Some(albedo), Some(normal), Some(roughness)];
+/// if rgl::supports_multi_bind() {
+///     rgl::bind_textures(0, &textures);
+/// } else {
+///     //Older contexts need every unit bound on its own, and the targets spelled out
+///     for (unit, texture) in textures.iter().enumerate() {
+///         let texture = texture.unwrap_or(rgl::Texture(0));
+///         rgl::bind_texture_unit(unit as u32, rgl::TextureTarget::Texture2d, texture);
+///     }
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindTextures.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn bind_textures(first_unit: GLuint, textures: &[Option<Texture>]) {
+    let names: Vec<GLuint> = textures.iter().map(|texture| texture.map_or(0, |texture| texture.0)).collect();
+    gl_call!(bind_textures, gl::BindTextures(first_unit, names.len() as GLsizei, names.as_ptr()));
+}
+
+/// Whether `bind_textures`, `bind_samplers` and the other multi-bind functions are available
+#[cfg(not(feature = "gles2"))]
+pub fn supports_multi_bind() -> bool {
+    gl::BindTextures::is_loaded()
+}
+
+/// Binds mip `level` of a texture to image unit `unit`, for shaders to load from and store to
+/// directly with `imageLoad` and `imageStore`
+///
+/// With `layered`, every layer of an array, cube map or 3D texture is bound, otherwise only `layer`.
+/// Writes are not visible to later reads until a `memory_barrier` of the matching kind. Requires
+/// GL 4.2 or `ARB_shader_image_load_store`
+///
+/// # Examples
+/// ```rust,no_run
+/// //Blur an image in a compute shader (layout(rgba16f) uniform image2D u_output, with 16x16 work
+/// //groups), then sample the result in a fragment shader
+/// let (width, height): (u32, u32) = (1280, 720);
+/// let source = rgl::gen_texture();
+/// let blurred = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, blurred);
+/// rgl::tex_storage_2d(rgl::TextureTarget::Texture2d, 1, rgl::SizedInternalFormat::Rgba16F, width as i32, height as i32);
+///
+/// let blur = rgl::create_program();
+/// let present = rgl::create_program();
+/// //...
+/// rgl::use_program(blur);
+/// rgl::bind_texture_unit(0, rgl::TextureTarget::Texture2d, source);
+/// rgl::bind_image_texture(0, blurred, 0, false, 0, rgl::ImageAccess::WriteOnly, rgl::ImageFormat::Rgba16F);
+/// rgl::dispatch_compute(width.div_ceil(16), height.div_ceil(16), 1);
+///
+/// //The fragment shader samples what the compute shader stored
+/// rgl::memory_barrier(rgl::BarrierMask::TEXTURE_FETCH);
+///
+/// rgl::use_program(present);
+/// rgl::bind_texture_unit(0, rgl::TextureTarget::Texture2d, blurred);
+/// rgl::draw_arrays(rgl::Primitive::Triangles, 0, 3);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindImageTexture.xhtml
+pub fn bind_image_texture<H: Into<Texture>>(
+    unit: GLuint,
+    texture: H,
+    level: GLint,
+    layered: bool,
+    layer: GLint,
+    access: enums::ImageAccess,
+    format: enums::ImageFormat,
+) {
+    let texture: Texture = texture.into();
+    gl_call!(bind_image_texture, gl::BindImageTexture(
+        unit,
+        texture.0,
+        level,
+        layered as GLboolean,
+        layer,
+        access as GLenum,
+        format as GLenum
+    ));
+}
+
+/// Binds level 0 of textures to consecutive image units starting at `first_unit`, `None` unbinds that
+/// unit
+///
+/// Every layer is bound with `ImageAccess::ReadWrite` in the texture's internal format. Requires
+/// GL 4.4 or `ARB_multi_bind`, check with `supports_multi_bind`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindImageTextures.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn bind_image_textures(first_unit: GLuint, textures: &[Option<Texture>]) {
+    let names: Vec<GLuint> = textures.iter().map(|texture| texture.map_or(0, |texture| texture.0)).collect();
+    gl_call!(bind_image_textures, gl::BindImageTextures(first_unit, names.len() as GLsizei, names.as_ptr()));
+}
+
+/// Deletes every texture object in the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteTextures.xhtml
+pub fn delete_textures(textures: &[Texture]) {
+    gl_call!(delete_textures, gl::DeleteTextures(textures.len() as GLsizei, textures.as_ptr() as *const GLuint));
 }
 
-pub fn active_texture(texture: GLuint) {
-    unsafe {
-        gl::ActiveTexture(gl::TEXTURE0 + texture);
+pub fn delete_texture(texture: Texture) {
+    delete_textures(&[texture]);
+}
+
+/// Whether `texture` names a texture object, see `is_buffer`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glIsTexture.xhtml
+pub fn is_texture(texture: Texture) -> bool {
+    gl_call!(is_texture, gl::IsTexture(texture.0)) == gl::TRUE
+}
+
+/// Specifies a two-dimensional texture image
+///
+/// Passing `None` for `data` allocates the storage for the image without uploading anything
+///
+/// Rows of `data` are expected to start on 4 byte boundaries, so tightly packed images whose rows
+/// are not a multiple of 4 bytes long (such as odd width `Rgb` `UnsignedByte` images) need to be
+/// uploaded inside `with_unpack_alignment(1, ...)`
+///
+/// # Panics
+/// If `data` is smaller than the image, laid out by the current unpack state, see `PixelLayout::set_unpack`
+///
+/// # Examples
+/// ```rust,no_run
+/// let checkerboard: [u8; 16] = [
+///     255, 255, 255, 255,   0,   0,   0, 255,
+///       0,   0,   0, 255, 255, 255, 255, 255,
+/// ];
+///
+/// let texture = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, texture);
+/// rgl::tex_image_2d(
+///     rgl::TextureTarget::Texture2d,
+///     0,
+///     rgl::InternalFormat::Rgba8,
+///     2,
+///     2,
+///     rgl::PixelFormat::Rgba,
+///     rgl::PixelType::UnsignedByte,
+///     Some(&checkerboard),
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexImage2D.xhtml
+pub fn tex_image_2d(
+    target: enums::TextureTarget,
+    level: GLint,
+    internal_format: enums::InternalFormat,
+    width: GLsizei,
+    height: GLsizei,
+    format: enums::PixelFormat,
+    pixel_type: enums::PixelType,
+    data: Option<&[u8]>,
+) {
+    if let Some(data) = data {
+        check_pixel_data(data, current_unpack().data_size(width, height, format, pixel_type));
     }
+    gl_call!(tex_image_2d, gl::TexImage2D(
+        target as GLenum,
+        level,
+        internal_format as GLint,
+        width,
+        height,
+        0,
+        format as GLenum,
+        pixel_type as GLenum,
+        pixels_ptr(data),
+    ));
+}
+
+/// Replaces a rectangle of a level of the two-dimensional texture bound to `target`
+///
+/// # Panics
+/// If `data` is smaller than the rectangle, laid out by the current unpack state, see `PixelLayout::set_unpack`
+///
+/// # Examples
+/// ```rust,no_run
+/// //Rasterize a glyph into its 32x32 cell of a single channel font atlas
+/// let glyph = [0u8; 32 * 32];
+/// let (cell_x, cell_y) = (5, 2);
+///
+/// rgl::tex_sub_image_2d(
+///     rgl::TextureTarget::Texture2d,
+///     0,
+///     cell_x * 32,
+///     cell_y * 32,
+///     32,
+///     32,
+///     rgl::PixelFormat::Red,
+///     rgl::PixelType::UnsignedByte,
+///     &glyph,
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexSubImage2D.xhtml
+pub fn tex_sub_image_2d<T: Plain>(
+    target: enums::TextureTarget,
+    level: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+    data: &[T],
+) {
+    let data = buffers::as_bytes(data);
+    check_pixel_data(data, current_unpack().data_size(width, height, format, type_));
+    gl_call!(tex_sub_image_2d, gl::TexSubImage2D(
+        target as GLenum,
+        level,
+        x,
+        y,
+        width,
+        height,
+        format as GLenum,
+        type_ as GLenum,
+        data.as_ptr() as *const c_void,
+    ));
+}
+
+/// Replaces a rectangle of a level of the two-dimensional texture bound to `target` with pixels of
+/// the current read framebuffer, starting at (`x`, `y`)
+///
+/// The copy is converted to the texture's internal format, which fails with `InvalidOperation`
+/// when the two are of different kinds, such as a depth buffer into a colour texture
+///
+/// # Examples
+/// ```rust,no_run
+/// # let (scene, width, height) = (rgl::gen_texture(), 1280, 720);
+/// //Snapshot the frame so far for a refraction pass to sample
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Read, rgl::Framebuffer::NONE);
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, scene);
+/// rgl::copy_tex_sub_image_2d(rgl::TextureTarget::Texture2d, 0, 0, 0, 0, 0, width, height);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCopyTexSubImage2D.xhtml
+pub fn copy_tex_sub_image_2d(
+    target: enums::TextureTarget,
+    level: GLint,
+    x_offset: GLint,
+    y_offset: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    gl_call!(copy_tex_sub_image_2d, gl::CopyTexSubImage2D(target as GLenum, level, x_offset, y_offset, x, y, width, height));
+}
+
+/// An object `copy_image_sub_data` can copy to or from
+#[derive(Clone, Copy, Debug)]
+pub enum ImageObject {
+    /// A texture, with the target it was created with
+    Texture(Texture, enums::TextureTarget),
+    /// A renderbuffer, which only has level 0 and no depth
+    Renderbuffer(Renderbuffer),
 }
 
-pub fn bind_texture(target: enums::TexTarget, texture: Texture) {
-    unsafe {
-        gl::BindTexture(target as GLenum, texture.0);
+impl ImageObject {
+    fn name_and_target(self) -> (GLuint, GLenum) {
+        match self {
+            ImageObject::Texture(texture, target) => (texture.0, target as GLenum),
+            ImageObject::Renderbuffer(renderbuffer) => (renderbuffer.0, gl::RENDERBUFFER),
+        }
     }
 }
 
-pub fn delete_textures(count: GLsizei, texture: *mut Texture) {
-    unsafe {
-        gl::DeleteTextures(count, &mut (*texture).0);
+/// Copies a box of texels from level `src_level` of `src` to level `dst_level` of `dst`, on the GPU
+/// and without binding either or going through a framebuffer
+///
+/// The texels are copied as raw bits, so the two formats must be compatible: the same internal
+/// format, or two uncompressed formats of the same size per texel, such as `Rgba8` with `R32f`. A
+/// compressed format is compatible with the uncompressed format whose texel is the size of one of
+/// its blocks, such as `Rgba32ui` for BC3, with the box given in texels of each. Anything else,
+/// including copies between multisampled and single sampled images or with depth or stencil
+/// formats that differ, is an `InvalidOperation`. The z coordinates are layers for array textures,
+/// and faces for cube maps. Requires GL 4.3 or `ARB_copy_image`
+///
+/// # Examples
+/// ```rust,no_run
+/// # let (atlas, sprite) = (rgl::gen_texture(), rgl::gen_texture());
+/// //Blit a 64x64 sprite into its cell of an atlas
+/// let target = rgl::TextureTarget::Texture2d;
+/// rgl::copy_image_sub_data(
+///     rgl::ImageObject::Texture(sprite, target),
+///     0,
+///     0,
+///     0,
+///     0,
+///     rgl::ImageObject::Texture(atlas, target),
+///     0,
+///     128,
+///     64,
+///     0,
+///     64,
+///     64,
+///     1,
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCopyImageSubData.xhtml
+pub fn copy_image_sub_data(
+    src: ImageObject,
+    src_level: GLint,
+    src_x: GLint,
+    src_y: GLint,
+    src_z: GLint,
+    dst: ImageObject,
+    dst_level: GLint,
+    dst_x: GLint,
+    dst_y: GLint,
+    dst_z: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+) {
+    let (src_name, src_target) = src.name_and_target();
+    let (dst_name, dst_target) = dst.name_and_target();
+    gl_call!(copy_image_sub_data, gl::CopyImageSubData(
+        src_name,
+        src_target,
+        src_level,
+        src_x,
+        src_y,
+        src_z,
+        dst_name,
+        dst_target,
+        dst_level,
+        dst_x,
+        dst_y,
+        dst_z,
+        width,
+        height,
+        depth,
+    ));
+}
+
+#[cfg(not(feature = "gles2"))]
+fn clear_value_ptr(data: Option<&[u8]>, format: enums::PixelFormat, type_: enums::PixelType) -> *const c_void {
+    match data {
+        Some(data) => {
+            let texel = bytes_per_pixel(format, type_)
+                .unwrap_or_else(|| panic!("{:?} pixels can not be of type {:?}", format, type_));
+            assert!(data.len() == texel, "clear value is {} bytes but a texel is {}", data.len(), texel);
+            data.as_ptr() as *const c_void
+        }
+        None => ptr::null(),
     }
 }
 
-pub fn delete_texture(texture: *mut Texture) {
-    delete_textures(1, texture);
+/// Fills every texel of level `level` of `texture` with a single value, without binding it
+///
+/// `data` is one texel laid out as described by `format` and `type_`, which is converted to the
+/// texture's internal format. `None` fills the level with zeros. Requires GL 4.4 or
+/// `ARB_clear_texture`, check with `supports_clear_texture`. Without it, attach the level to a
+/// framebuffer and `clear`, or upload a buffer of the value with `tex_sub_image_2d`
+///
+/// # Panics
+/// If `data` is not exactly one texel of `format` and `type_`
+///
+/// # Examples
+/// ```rust,no_run
+/// # let (histogram, texture) = (rgl::gen_texture(), rgl::gen_texture());
+/// //Reset the compute-written histogram before the next frame accumulates into it
+/// rgl::clear_tex_image(histogram, 0, rgl::PixelFormat::RedInteger, rgl::PixelType::UnsignedInt, None);
+///
+/// //Fill another texture with magenta
+/// rgl::clear_tex_image(texture, 0, rgl::PixelFormat::Rgba, rgl::PixelType::UnsignedByte, Some(&[255, 0, 255, 255]));
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearTexImage.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn clear_tex_image(texture: Texture, level: GLint, format: enums::PixelFormat, type_: enums::PixelType, data: Option<&[u8]>) {
+    let data = clear_value_ptr(data, format, type_);
+    gl_call!(clear_tex_image, gl::ClearTexImage(texture.0, level, format as GLenum, type_ as GLenum, data));
 }
 
-pub fn tex_image_2d(
-    target: enums::TexTarget,
+/// Fills a box of level `level` of `texture` with a single value, see `clear_tex_image`
+///
+/// For two-dimensional textures `z` is 0 and `depth` 1, and the z coordinates are layers for array
+/// textures and faces for cube maps
+///
+/// # Panics
+/// If `data` is not exactly one texel of `format` and `type_`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glClearTexSubImage.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn clear_tex_sub_image(
+    texture: Texture,
+    level: GLint,
+    x: GLint,
+    y: GLint,
+    z: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+    data: Option<&[u8]>,
+) {
+    let data = clear_value_ptr(data, format, type_);
+    gl_call!(clear_tex_sub_image, gl::ClearTexSubImage(
+        texture.0,
+        level,
+        x,
+        y,
+        z,
+        width,
+        height,
+        depth,
+        format as GLenum,
+        type_ as GLenum,
+        data,
+    ));
+}
+
+/// Whether `clear_tex_image` and `clear_tex_sub_image` are available
+#[cfg(not(feature = "gles2"))]
+pub fn supports_clear_texture() -> bool {
+    gl::ClearTexImage::is_loaded()
+}
+
+/// Replaces a box of a level of the three-dimensional or array texture bound to `target`
+///
+/// # Panics
+/// If `data` is smaller than the box, laid out by the current unpack state, see `PixelLayout::set_unpack`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexSubImage3D.xhtml
+pub fn tex_sub_image_3d<T: Plain>(
+    target: enums::TextureTarget,
+    level: GLint,
+    x: GLint,
+    y: GLint,
+    z: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+    data: &[T],
+) {
+    let data = buffers::as_bytes(data);
+    check_pixel_data(data, current_unpack().data_size_3d(width, height, depth, format, type_));
+    gl_call!(tex_sub_image_3d, gl::TexSubImage3D(
+        target as GLenum,
+        level,
+        x,
+        y,
+        z,
+        width,
+        height,
+        depth,
+        format as GLenum,
+        type_ as GLenum,
+        data.as_ptr() as *const c_void,
+    ));
+}
+
+pub(crate) fn check_pixel_data(data: &[u8], required: Option<usize>) {
+    let required = required.expect("pixel data would not fit in memory");
+    assert!(
+        data.len() >= required,
+        "pixel data is {} bytes but {} are required",
+        data.len(),
+        required
+    );
+}
+
+pub(crate) fn unpack_alignment() -> GLint {
+    let mut alignment = 4;
+    gl_call!(unpack_alignment, gl::GetIntegerv(gl::UNPACK_ALIGNMENT, &mut alignment));
+    alignment
+}
+
+pub(crate) fn pack_alignment() -> GLint {
+    let mut alignment = 4;
+    gl_call!(pack_alignment, gl::GetIntegerv(gl::PACK_ALIGNMENT, &mut alignment));
+    alignment
+}
+
+/// Allocates immutable storage for `levels` mip levels of the two-dimensional texture bound to `target`
+///
+/// The storage can not be reallocated afterwards, instead the levels are filled with `tex_sub_image_2d`.
+/// Requires GL 4.2 or `ARB_texture_storage`
+///
+/// # Panics
+/// If `levels` is 0
+///
+/// # Examples
+/// ```rust,no_run
+/// let (width, height) = (1024, 512);
+///
+/// let texture = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, texture);
+/// rgl::tex_storage_2d(
+///     rgl::TextureTarget::Texture2d,
+///     rgl::max_mip_levels(width, height),
+///     rgl::SizedInternalFormat::Srgb8Alpha8,
+///     width,
+///     height,
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexStorage2D.xhtml
+pub fn tex_storage_2d(
+    target: enums::TextureTarget,
+    levels: GLsizei,
+    internal_format: enums::SizedInternalFormat,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    assert!(levels > 0, "tex_storage_2d: levels must be at least 1");
+    gl_call!(tex_storage_2d, gl::TexStorage2D(target as GLenum, levels, internal_format as GLenum, width, height));
+}
+
+/// Allocates immutable storage for `levels` mip levels of the three-dimensional or array texture bound to `target`
+///
+/// Requires GL 4.2 or `ARB_texture_storage`
+///
+/// # Panics
+/// If `levels` is 0
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexStorage3D.xhtml
+pub fn tex_storage_3d(
+    target: enums::TextureTarget,
+    levels: GLsizei,
+    internal_format: enums::SizedInternalFormat,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+) {
+    assert!(levels > 0, "tex_storage_3d: levels must be at least 1");
+    gl_call!(tex_storage_3d, gl::TexStorage3D(target as GLenum, levels, internal_format as GLenum, width, height, depth));
+}
+
+/// Makes `new_texture` a view of a range of the levels and layers of `original`, sharing its storage
+///
+/// `original` must have immutable storage allocated with `tex_storage_2d` or `tex_storage_3d`,
+/// and `new_texture` must be a fresh name from `gen_texture` that has never been bound.
+/// `internal_format` must be compatible with the original's, for example `Srgb8Alpha8` with `Rgba8`.
+/// Requires GL 4.3 or `ARB_texture_view`, see `supports_texture_view`
+///
+/// # Examples
+/// ```rust,no_run
+/// let original = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, original);
+/// rgl::tex_storage_2d(rgl::TextureTarget::Texture2d, rgl::max_mip_levels(512, 512), rgl::SizedInternalFormat::Rgba8, 512, 512);
+/// //...upload the levels
+///
+/// //Level 2 on its own, as a 128x128 sRGB texture with a single level
+/// let view = rgl::gen_texture();
+/// rgl::texture_view(
+///     view,
+///     rgl::TextureTarget::Texture2d,
+///     original,
+///     rgl::SizedInternalFormat::Srgb8Alpha8,
+///     2,
+///     1,
+///     0,
+///     1,
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTextureView.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn texture_view(
+    new_texture: Texture,
+    target: enums::TextureTarget,
+    original: Texture,
+    internal_format: enums::SizedInternalFormat,
+    min_level: GLuint,
+    num_levels: GLuint,
+    min_layer: GLuint,
+    num_layers: GLuint,
+) {
+    gl_call!(texture_view, gl::TextureView(
+        new_texture.0,
+        target as GLenum,
+        original.0,
+        internal_format as GLenum,
+        min_level,
+        num_levels,
+        min_layer,
+        num_layers,
+    ));
+}
+
+/// Whether `texture_view` is available
+#[cfg(not(feature = "gles2"))]
+pub fn supports_texture_view() -> bool {
+    gl::TextureView::is_loaded()
+}
+
+/// Allocates the storage of the multisampled texture bound to `Texture2dMultisample`
+///
+/// With `fixed_sample_locations`, every texel uses the same sample pattern, which is required to
+/// attach the texture alongside multisampled renderbuffers
+///
+/// # Examples
+/// ```rust,no_run
+/// let samples = 4.min(rgl::max_samples());
+///
+/// let color = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::Texture2dMultisample, color);
+/// rgl::tex_image_2d_multisample(samples, rgl::SizedInternalFormat::Rgba8, 1280, 720, true);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexImage2DMultisample.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn tex_image_2d_multisample(
+    samples: GLsizei,
+    internal_format: enums::SizedInternalFormat,
+    width: GLsizei,
+    height: GLsizei,
+    fixed_sample_locations: bool,
+) {
+    gl_call!(tex_image_2d_multisample, gl::TexImage2DMultisample(
+        gl::TEXTURE_2D_MULTISAMPLE,
+        samples,
+        internal_format as GLenum,
+        width,
+        height,
+        fixed_sample_locations as GLboolean,
+    ));
+}
+
+/// Number of levels in a full mip chain of a `width` by `height` texture, floor(log2(max(width, height))) + 1
+///
+/// # Examples
+/// ```rust
+/// assert_eq!(rgl::max_mip_levels(1, 1), 1);
+/// assert_eq!(rgl::max_mip_levels(256, 256), 9);
+/// assert_eq!(rgl::max_mip_levels(1024, 512), 11);
+/// assert_eq!(rgl::max_mip_levels(300, 17), 9);
+/// assert_eq!(rgl::max_mip_levels(0, 0), 1);
+/// ```
+pub fn max_mip_levels(width: GLsizei, height: GLsizei) -> GLsizei {
+    let size = width.max(height).max(1) as u32;
+    (32 - size.leading_zeros()) as GLsizei
+}
+
+/// Specifies a two-dimensional texture image from block compressed data
+///
+/// # Examples
+/// ```rust,no_run
+/// //A 256x256 BC7 level is 16 bytes per 4x4 block
+/// let blocks = vec![0u8; 64 * 64 * 16];
+///
+/// rgl::compressed_tex_image_2d(
+///     rgl::TextureTarget::Texture2d,
+///     0,
+///     rgl::CompressedFormat::RgbaBptcUnorm,
+///     256,
+///     256,
+///     &blocks,
+/// );
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCompressedTexImage2D.xhtml
+pub fn compressed_tex_image_2d(
+    target: enums::TextureTarget,
+    level: GLint,
+    internal_format: enums::CompressedFormat,
+    width: GLsizei,
+    height: GLsizei,
+    data: &[u8],
+) {
+    gl_call!(compressed_tex_image_2d, gl::CompressedTexImage2D(
+        target as GLenum,
+        level,
+        internal_format as GLenum,
+        width,
+        height,
+        0,
+        data.len() as GLsizei,
+        data.as_ptr() as *const c_void,
+    ));
+}
+
+/// Replaces a rectangle of a level of a compressed texture, `x`, `y`, `width` and `height` must be
+/// aligned to the format's blocks
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glCompressedTexSubImage2D.xhtml
+pub fn compressed_tex_sub_image_2d(
+    target: enums::TextureTarget,
+    level: GLint,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: enums::CompressedFormat,
+    data: &[u8],
+) {
+    gl_call!(compressed_tex_sub_image_2d, gl::CompressedTexSubImage2D(
+        target as GLenum,
+        level,
+        x,
+        y,
+        width,
+        height,
+        format as GLenum,
+        data.len() as GLsizei,
+        data.as_ptr() as *const c_void,
+    ));
+}
+
+/// Returns the compressed formats the implementation supports, leaving out any this crate does not know about
+///
+/// # Examples
+/// ```rust,no_run
+/// let bc7 = rgl::supported_compressed_formats().contains(&rgl::CompressedFormat::RgbaBptcUnorm);
+/// ```
+pub fn supported_compressed_formats() -> Vec<enums::CompressedFormat> {
+    let mut count = 0;
+    gl_call!(supported_compressed_formats, gl::GetIntegerv(gl::NUM_COMPRESSED_TEXTURE_FORMATS, &mut count));
+
+    let mut formats = vec![0; count.max(0) as usize];
+    if !formats.is_empty() {
+        gl_call!(supported_compressed_formats, gl::GetIntegerv(gl::COMPRESSED_TEXTURE_FORMATS, formats.as_mut_ptr()));
+    }
+
+    formats
+        .into_iter()
+        .filter_map(|format| enums::CompressedFormat::from_raw(format as u32))
+        .collect()
+}
+
+/// Specifies the image of one face of the cube map bound to `TextureCubeMap`
+///
+/// # Panics
+/// If `data` is smaller than the image, like `tex_image_2d`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexImage2D.xhtml
+pub fn tex_image_2d_cube_face(
+    face: enums::CubeMapFace,
+    level: GLint,
+    internal_format: enums::InternalFormat,
+    width: GLsizei,
+    height: GLsizei,
+    format: enums::PixelFormat,
+    pixel_type: enums::PixelType,
+    data: Option<&[u8]>,
+) {
+    if let Some(data) = data {
+        check_pixel_data(data, current_unpack().data_size(width, height, format, pixel_type));
+    }
+    gl_call!(tex_image_2d_cube_face, gl::TexImage2D(
+        face as GLenum,
+        level,
+        internal_format as GLint,
+        width,
+        height,
+        0,
+        format as GLenum,
+        pixel_type as GLenum,
+        pixels_ptr(data),
+    ));
+}
+
+/// Uploads the base level of all six faces of the cube map bound to `TextureCubeMap`
+///
+/// `faces` are in the canonical +X, -X, +Y, -Y, +Z, -Z order, each `size` by `size` pixels
+///
+/// # Examples
+/// ```rust,no_run
+/// let faces: Vec<Vec<u8>> = vec![vec![0; 512 * 512 * 4]; 6];
+/// //...load right, left, top, bottom, front and back
+///
+/// let skybox = rgl::gen_texture();
+/// rgl::bind_texture(rgl::TextureTarget::TextureCubeMap, skybox);
+/// rgl::upload_cubemap(
+///     [&faces[0], &faces[1], &faces[2], &faces[3], &faces[4], &faces[5]],
+///     rgl::InternalFormat::Rgba8,
+///     512,
+///     rgl::PixelFormat::Rgba,
+///     rgl::PixelType::UnsignedByte,
+/// );
+///
+/// let target = rgl::TextureTarget::TextureCubeMap;
+/// rgl::tex_min_filter(target, rgl::MinFilter::Linear);
+/// rgl::tex_mag_filter(target, rgl::MagFilter::Linear);
+/// rgl::tex_wrap_s(target, rgl::WrapMode::ClampToEdge);
+/// rgl::tex_wrap_t(target, rgl::WrapMode::ClampToEdge);
+/// rgl::tex_wrap_r(target, rgl::WrapMode::ClampToEdge);
+/// rgl::enable(rgl::Capability::TextureCubeMapSeamless);
+///
+/// //Every frame, draw the sky last so only uncovered pixels are shaded
+/// rgl::depth_func(rgl::CompareFunc::Lequal);
+/// //...use the skybox program and draw a unit cube
+/// rgl::draw_arrays(rgl::Primitive::Triangles, 0, 36);
+/// rgl::depth_func(rgl::CompareFunc::Less);
+/// ```
+pub fn upload_cubemap(
+    faces: [&[u8]; 6],
+    internal_format: enums::InternalFormat,
+    size: GLsizei,
+    format: enums::PixelFormat,
+    pixel_type: enums::PixelType,
+) {
+    for (face, data) in enums::CubeMapFace::ALL.iter().zip(faces.iter()) {
+        tex_image_2d_cube_face(*face, 0, internal_format, size, size, format, pixel_type, Some(data));
+    }
+}
+
+/// Specifies a three-dimensional or array texture image
+///
+/// For array targets `depth` is the number of layers. Passing `None` for `data` allocates the
+/// storage for the image without uploading anything
+///
+/// # Panics
+/// If `data` is smaller than the image, laid out by the current unpack state, see `PixelLayout::set_unpack`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexImage3D.xhtml
+pub fn tex_image_3d(
+    target: enums::TextureTarget,
     level: GLint,
-    internal_format: enums::TexFormat,
+    internal_format: enums::InternalFormat,
     width: GLsizei,
     height: GLsizei,
-    border: GLint,
-    format: enums::TexFormat,
-    pixels: &Vec<u8>,
+    depth: GLsizei,
+    format: enums::PixelFormat,
+    pixel_type: enums::PixelType,
+    data: Option<&[u8]>,
 ) {
-    unsafe {
-        gl::TexImage2D(
-            target as GLenum,
+    if let Some(data) = data {
+        check_pixel_data(data, current_unpack().data_size_3d(width, height, depth, format, pixel_type));
+    }
+    gl_call!(tex_image_3d, gl::TexImage3D(
+        target as GLenum,
+        level,
+        internal_format as GLint,
+        width,
+        height,
+        depth,
+        0,
+        format as GLenum,
+        pixel_type as GLenum,
+        pixels_ptr(data),
+    ));
+}
+
+/// Sets how pixel data is read from or written to client memory
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPixelStore.xhtml
+pub fn pixel_store(param_name: enums::PixelStoreParam, param: GLint) {
+    gl_call!(pixel_store, gl::PixelStorei(param_name as GLenum, param));
+
+    KNOWN_UNPACK.with(|known| {
+        if let Some(mut layout) = known.get() {
+            match param_name {
+                enums::PixelStoreParam::UnpackAlignment => layout.alignment = param,
+                enums::PixelStoreParam::UnpackRowLength => layout.row_length = param,
+                enums::PixelStoreParam::UnpackSkipPixels => layout.skip_pixels = param,
+                enums::PixelStoreParam::UnpackSkipRows => layout.skip_rows = param,
+                enums::PixelStoreParam::UnpackImageHeight => layout.image_height = param,
+                enums::PixelStoreParam::UnpackSkipImages => layout.skip_images = param,
+                _ => (),
+            }
+            known.set(Some(layout));
+        }
+    });
+}
+
+/// Runs `f` with `GL_UNPACK_ALIGNMENT` set to `alignment`, restoring the previous value afterwards
+///
+/// # Examples
+/// ```rust,no_run
+/// // 3x3 tightly packed RGB, 9 bytes per row
+/// let pixels = [255u8; 27];
+///
+/// rgl::with_unpack_alignment(1, || {
+///     rgl::tex_image_2d(
+///         rgl::TextureTarget::Texture2d,
+///         0,
+///         rgl::InternalFormat::Rgb8,
+///         3,
+///         3,
+///         rgl::PixelFormat::Rgb,
+///         rgl::PixelType::UnsignedByte,
+///         Some(&pixels),
+///     );
+/// });
+/// ```
+pub fn with_unpack_alignment<R, F: FnOnce() -> R>(alignment: GLint, f: F) -> R {
+    let previous = KNOWN_UNPACK.with(Cell::get).map_or_else(unpack_alignment, |layout| layout.alignment);
+    pixel_store(enums::PixelStoreParam::UnpackAlignment, alignment);
+    let result = f();
+    pixel_store(enums::PixelStoreParam::UnpackAlignment, previous);
+    result
+}
+
+/// Runs `f` with `GL_PACK_ALIGNMENT` set to `alignment`, restoring the previous value afterwards
+///
+/// The readback counterpart of `with_unpack_alignment`, for `read_pixels` and `get_tex_image`
+pub fn with_pack_alignment<R, F: FnOnce() -> R>(alignment: GLint, f: F) -> R {
+    let previous = pack_alignment();
+    pixel_store(enums::PixelStoreParam::PackAlignment, alignment);
+    let result = f();
+    pixel_store(enums::PixelStoreParam::PackAlignment, previous);
+    result
+}
+
+/// Returns a property of mip level `level` of the texture bound to `target`
+///
+/// For cube maps, `target` has to be one of the faces, and so is not available through this
+///
+/// # Examples
+/// ```rust,no_run
+/// let target = rgl::TextureTarget::Texture2d;
+/// let width = rgl::get_tex_level_parameter(target, 2, rgl::TextureLevelParameter::Width);
+/// let height = rgl::get_tex_level_parameter(target, 2, rgl::TextureLevelParameter::Height);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetTexLevelParameter.xhtml
+pub fn get_tex_level_parameter(target: enums::TextureTarget, level: GLint, param_name: enums::TextureLevelParameter) -> GLint {
+    let mut value = 0;
+    gl_call!(get_tex_level_parameter, gl::GetTexLevelParameteriv(target as GLenum, level, param_name as GLenum, &mut value));
+    value
+}
+
+/// Reads mip level `level` of the texture bound to `target` into a newly allocated `Vec`
+///
+/// The `Vec` is sized from the level's dimensions, the format, type and the current pack state,
+/// see `PixelLayout::pack`. Three-dimensional and array levels are read as one image after another.
+/// Any buffer bound to `Target::PixelPackBuffer` is unbound for the read, and bound again after
+///
+/// # Examples
+/// ```rust,no_run
+/// //Dump a generated texture for debugging
+/// let target = rgl::TextureTarget::Texture2d;
+/// let pixels = rgl::with_pack_alignment(1, || {
+///     rgl::get_tex_image(target, 0, rgl::PixelFormat::Rgba, rgl::PixelType::UnsignedByte)
+/// });
+/// std::fs::write("texture.rgba", &pixels).unwrap();
+/// ```
+///
+/// # Panics
+/// If the level would not fit in memory
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGetTexImage.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn get_tex_image(target: enums::TextureTarget, level: GLint, format: enums::PixelFormat, type_: enums::PixelType) -> Vec<u8> {
+    let width = get_tex_level_parameter(target, level, enums::TextureLevelParameter::Width);
+    let height = get_tex_level_parameter(target, level, enums::TextureLevelParameter::Height);
+    let depth = get_tex_level_parameter(target, level, enums::TextureLevelParameter::Depth);
+
+    let layout = PixelLayout::pack();
+    let size = match target {
+        enums::TextureTarget::Texture3d | enums::TextureTarget::Texture2dArray | enums::TextureTarget::TextureCubeMapArray => {
+            layout.data_size_3d(width, height, depth, format, type_)
+        }
+        _ => layout.data_size(width, height, format, type_),
+    };
+    let mut pixels = vec![0; size.expect("get_tex_image would not fit in memory")];
+    without_pack_buffer(|| {
+        gl_call!(get_tex_image, gl::GetTexImage(target as GLenum, level, format as GLenum, type_ as GLenum, pixels.as_mut_ptr() as *mut c_void));
+    });
+    pixels
+}
+
+/// Generates the full mipmap chain of the texture bound to `target` from its base level
+///
+/// # Examples
+/// ```rust,no_run
+/// let target = rgl::TextureTarget::Texture2d;
+/// //...upload the base level with tex_image_2d
+/// rgl::generate_mipmap(target);
+/// rgl::tex_min_filter(target, rgl::MinFilter::LinearMipmapLinear);
+/// rgl::tex_mag_filter(target, rgl::MagFilter::Linear);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenerateMipmap.xhtml
+pub fn generate_mipmap(target: enums::TextureTarget) {
+    gl_call!(generate_mipmap, gl::GenerateMipmap(target as GLenum));
+}
+
+/// Sets an integer parameter of the texture bound to `target`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexParameter.xhtml
+pub fn tex_parameteri(target: enums::TextureTarget, param_name: enums::TextureParameter, param: GLint) {
+    gl_call!(tex_parameteri, gl::TexParameteri(target as GLenum, param_name as GLenum, param));
+}
+
+/// Sets a float parameter of the texture bound to `target`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexParameter.xhtml
+pub fn tex_parameterf(target: enums::TextureTarget, param_name: enums::TextureParameter, param: GLfloat) {
+    gl_call!(tex_parameterf, gl::TexParameterf(target as GLenum, param_name as GLenum, param));
+}
+
+/// Sets a float vector parameter, GL reads as many values as `param_name` needs
+fn tex_parameterfv(target: enums::TextureTarget, param_name: enums::TextureParameter, params: &[GLfloat; 4]) {
+    gl_call!(tex_parameterfv, gl::TexParameterfv(target as GLenum, param_name as GLenum, params.as_ptr()));
+}
+
+/// Sets the wrap mode of the s (x) texture coordinate of the texture bound to `target`
+pub fn tex_wrap_s(target: enums::TextureTarget, mode: enums::WrapMode) {
+    tex_parameteri(target, enums::TextureParameter::WrapS, mode as GLint);
+}
+
+/// Sets the wrap mode of the t (y) texture coordinate of the texture bound to `target`
+pub fn tex_wrap_t(target: enums::TextureTarget, mode: enums::WrapMode) {
+    tex_parameteri(target, enums::TextureParameter::WrapT, mode as GLint);
+}
+
+/// Sets the wrap mode of the r (z) texture coordinate of the texture bound to `target`
+pub fn tex_wrap_r(target: enums::TextureTarget, mode: enums::WrapMode) {
+    tex_parameteri(target, enums::TextureParameter::WrapR, mode as GLint);
+}
+
+/// Sets the colour sampled outside of the texture when wrapping with `WrapMode::ClampToBorder`
+///
+/// # Examples
+/// ```rust,no_run
+/// let target = rgl::TextureTarget::Texture2d;
+/// rgl::tex_wrap_s(target, rgl::WrapMode::ClampToBorder);
+/// rgl::tex_wrap_t(target, rgl::WrapMode::ClampToBorder);
+/// rgl::tex_border_color(target, [1.0, 0.0, 1.0, 1.0]);
+/// ```
+pub fn tex_border_color(target: enums::TextureTarget, color: [GLfloat; 4]) {
+    tex_parameterfv(target, enums::TextureParameter::BorderColor, &color);
+}
+
+/// Sets the minifying filter of the texture bound to `target`
+pub fn tex_min_filter(target: enums::TextureTarget, filter: enums::MinFilter) {
+    tex_parameteri(target, enums::TextureParameter::MinFilter, filter as GLint);
+}
+
+/// Sets the magnification filter of the texture bound to `target`
+pub fn tex_mag_filter(target: enums::TextureTarget, filter: enums::MagFilter) {
+    tex_parameteri(target, enums::TextureParameter::MagFilter, filter as GLint);
+}
+
+/// Sets the lowest level of detail the texture bound to `target` selects, the highest resolution
+pub fn tex_min_lod(target: enums::TextureTarget, lod: GLfloat) {
+    tex_parameterf(target, enums::TextureParameter::MinLod, lod);
+}
+
+/// Sets the highest level of detail the texture bound to `target` selects, the lowest resolution
+pub fn tex_max_lod(target: enums::TextureTarget, lod: GLfloat) {
+    tex_parameterf(target, enums::TextureParameter::MaxLod, lod);
+}
+
+/// Sets the bias added to the level of detail of the texture bound to `target`, positive values
+/// select blurrier mip levels
+#[cfg(not(feature = "gles2"))]
+pub fn tex_lod_bias(target: enums::TextureTarget, bias: GLfloat) {
+    tex_parameterf(target, enums::TextureParameter::LodBias, bias);
+}
+
+/// Sets the first mip level of the texture bound to `target` that is sampled
+///
+/// Unlike the LOD range this is texture state only, samplers have no base and max level
+pub fn tex_base_level(target: enums::TextureTarget, level: GLint) {
+    tex_parameteri(target, enums::TextureParameter::BaseLevel, level);
+}
+
+/// Sets the last mip level of the texture bound to `target` that is sampled, see `tex_base_level`
+pub fn tex_max_level(target: enums::TextureTarget, level: GLint) {
+    tex_parameteri(target, enums::TextureParameter::MaxLevel, level);
+}
+
+/// Sets the range of mip levels of the texture bound to `target` that are sampled
+///
+/// # Examples
+/// ```rust,no_run
+/// //Stream a texture in from its smallest mip up, only sampling the levels that have arrived
+/// let target = rgl::TextureTarget::Texture2d;
+/// let levels = rgl::max_mip_levels(2048, 2048);
+/// rgl::tex_storage_2d(target, levels, rgl::SizedInternalFormat::Rgba8, 2048, 2048);
+///
+/// for level in (0..levels).rev() {
+///     //...upload `level` with tex_sub_image_2d once it has loaded
+///     rgl::tex_level_range(target, level, levels - 1);
+/// }
+/// ```
+///
+/// # Panics
+/// If `base` is greater than `max`, or negative
+pub fn tex_level_range(target: enums::TextureTarget, base: GLint, max: GLint) {
+    assert!(0 <= base && base <= max, "invalid mip level range {}..={}", base, max);
+    tex_base_level(target, base);
+    tex_max_level(target, max);
+}
+
+/// Sets whether sampling the depth texture bound to `target` returns its depth or the result of a
+/// comparison against it, which `sampler2DShadow` and the other shadow samplers need
+///
+/// # Examples
+#[cfg_attr(not(feature = "gles2"), doc = "```rust,no_run")]
+#[cfg_attr(feature = "gles2", doc = "```rust,ignore")]
+/// # fn shadow_map() -> Result<(), rgl::FramebufferStatus> {
+/// let target = rgl::TextureTarget::Texture2d;
+/// let depth = rgl::gen_texture();
+/// rgl::bind_texture(target, depth);
+/// rgl::tex_storage_2d(target, 1, rgl::SizedInternalFormat::DepthComponent24, 2048, 2048);
+/// rgl::tex_min_filter(target, rgl::MinFilter::Linear);
+/// rgl::tex_mag_filter(target, rgl::MagFilter::Linear);
+///
+/// //Compare in the sampler, with linear filtering this gives 2x2 percentage closer filtering for
+/// //free in `textureProj(shadow_map, light_space_position)`
+/// rgl::tex_compare_mode(target, rgl::CompareMode::CompareRefToTexture);
+/// rgl::tex_compare_func(target, rgl::CompareFunc::Lequal);
+///
+/// let fbo = rgl::gen_framebuffer();
+/// rgl::bind_framebuffer(rgl::FramebufferTarget::Both, fbo);
+/// rgl::framebuffer_texture_2d(rgl::FramebufferTarget::Both, rgl::Attachment::Depth, target, depth, 0);
+/// rgl::draw_buffer(rgl::DrawBufferTarget::None);
+/// rgl::read_buffer(rgl::ReadBufferTarget::None);
+/// rgl::check_framebuffer_status(rgl::FramebufferTarget::Both)?;
+///
+/// //...render the casters from the light, then bind `depth` and draw the scene
+/// # Ok(())
+/// # }
+/// ```
+pub fn tex_compare_mode(target: enums::TextureTarget, mode: enums::CompareMode) {
+    tex_parameteri(target, enums::TextureParameter::CompareMode, mode as GLint);
+}
+
+/// Sets the comparison used with `CompareMode::CompareRefToTexture` by the texture bound to `target`
+pub fn tex_compare_func(target: enums::TextureTarget, func: enums::CompareFunc) {
+    tex_parameteri(target, enums::TextureParameter::CompareFunc, func as GLint);
+}
+
+/// Sets where each channel of the texture bound to `target` reads from when sampled
+///
+/// Swizzling is texture state only, samplers have no swizzle and leave it alone. Requires GL 3.3 or
+/// `ARB_texture_swizzle`
+///
+/// # Examples
+/// ```rust,no_run
+/// //A single channel R8 font atlas sampled as white text with the coverage in alpha
+/// let target = rgl::TextureTarget::Texture2d;
+/// rgl::tex_swizzle(target, rgl::Swizzle::One, rgl::Swizzle::One, rgl::Swizzle::One, rgl::Swizzle::Red);
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTexParameter.xhtml
+pub fn tex_swizzle(target: enums::TextureTarget, r: enums::Swizzle, g: enums::Swizzle, b: enums::Swizzle, a: enums::Swizzle) {
+    //ES only has the parameters of the single channels
+    if cfg!(feature = "gles2") {
+        tex_swizzle_r(target, r);
+        tex_swizzle_g(target, g);
+        tex_swizzle_b(target, b);
+        tex_swizzle_a(target, a);
+        return;
+    }
+
+    let swizzle = [r as GLint, g as GLint, b as GLint, a as GLint];
+    gl_call!(tex_swizzle, gl::TexParameteriv(
+        target as GLenum,
+        enums::TextureParameter::SwizzleRgba as GLenum,
+        swizzle.as_ptr()
+    ));
+}
+
+/// Sets where the red channel of the texture bound to `target` reads from, see `tex_swizzle`
+pub fn tex_swizzle_r(target: enums::TextureTarget, swizzle: enums::Swizzle) {
+    tex_parameteri(target, enums::TextureParameter::SwizzleR, swizzle as GLint);
+}
+
+/// Sets where the green channel of the texture bound to `target` reads from, see `tex_swizzle`
+pub fn tex_swizzle_g(target: enums::TextureTarget, swizzle: enums::Swizzle) {
+    tex_parameteri(target, enums::TextureParameter::SwizzleG, swizzle as GLint);
+}
+
+/// Sets where the blue channel of the texture bound to `target` reads from, see `tex_swizzle`
+pub fn tex_swizzle_b(target: enums::TextureTarget, swizzle: enums::Swizzle) {
+    tex_parameteri(target, enums::TextureParameter::SwizzleB, swizzle as GLint);
+}
+
+/// Sets where the alpha channel of the texture bound to `target` reads from, see `tex_swizzle`
+pub fn tex_swizzle_a(target: enums::TextureTarget, swizzle: enums::Swizzle) {
+    tex_parameteri(target, enums::TextureParameter::SwizzleA, swizzle as GLint);
+}
+
+/// `GL_MAX_TEXTURE_MAX_ANISOTROPY`, shared by GL 4.6 and `EXT_texture_filter_anisotropic`
+pub(crate) const MAX_TEXTURE_MAX_ANISOTROPY: GLenum = 0x84FF;
+
+/// Whether anisotropic filtering is supported, 0 until `supports_anisotropy` first checks, then 1
+/// for no and 2 for yes
+static ANISOTROPY: AtomicU8 = AtomicU8::new(0);
+
+/// Whether anisotropic filtering is available, with GL 4.6, `ARB_texture_filter_anisotropic` or
+/// `EXT_texture_filter_anisotropic`
+///
+/// This may query every extension name, so it is only checked on the first call
+pub fn supports_anisotropy() -> bool {
+    match ANISOTROPY.load(Ordering::Relaxed) {
+        0 => {
+            let supported = (!cfg!(feature = "gles2") && info::get_version() >= (4, 6))
+                || info::has_extension("GL_EXT_texture_filter_anisotropic")
+                || info::has_extension("GL_ARB_texture_filter_anisotropic");
+            ANISOTROPY.store(if supported { 2 } else { 1 }, Ordering::Relaxed);
+            supported
+        }
+        known => known == 2,
+    }
+}
+
+/// Returns the highest anisotropy `tex_max_anisotropy` accepts, 1.0 if anisotropic filtering is
+/// not supported
+pub fn max_supported_anisotropy() -> GLfloat {
+    if !supports_anisotropy() {
+        return 1.0;
+    }
+
+    let mut anisotropy = 1.0;
+    gl_call!(max_supported_anisotropy, gl::GetFloatv(MAX_TEXTURE_MAX_ANISOTROPY, &mut anisotropy));
+    anisotropy
+}
+
+/// Sets the anisotropy of the texture bound to `target`, the number of samples its filter may take
+/// along the direction a surface slopes away from the camera
+///
+/// 1.0 is plain isotropic filtering, and values above `max_supported_anisotropy` are clamped by GL.
+/// Does nothing if anisotropic filtering is not supported, see `supports_anisotropy`
+///
+/// # Examples
+/// ```rust,no_run
+/// let target = rgl::TextureTarget::Texture2d;
+/// let texture = rgl::gen_texture();
+/// rgl::bind_texture(target, texture);
+/// rgl::tex_min_filter(target, rgl::MinFilter::LinearMipmapLinear);
+/// rgl::tex_max_anisotropy(target, rgl::max_supported_anisotropy().min(16.0));
+/// ```
+///
+/// # Panics
+/// If `anisotropy` is less than 1.0
+pub fn tex_max_anisotropy(target: enums::TextureTarget, anisotropy: GLfloat) {
+    assert!(anisotropy >= 1.0, "anisotropy must be at least 1.0, got {}", anisotropy);
+    if supports_anisotropy() {
+        tex_parameterf(target, enums::TextureParameter::MaxAnisotropy, anisotropy);
+    }
+}
+
+/// Specifies a two-dimensional texture image from an `image` crate image
+///
+/// 8 bit RGBA, RGB, luma and luma alpha images are uploaded as they are, to `Rgba8`, `Rgb8`, `R8`
+/// and `Rg8` respectively, so luma ends up in the red channel. Every other kind of image is
+/// converted to 8 bit RGBA first.
+///
+/// Images store their rows top to bottom while GL expects them bottom to top, with `flip_v` the
+/// image is flipped so that texture coordinate (0, 0) is its bottom left corner. Only available with
+/// the `image` feature
+///
+/// # Examples
+/// ```rust,ignore
+/// let image = image::open("assets/brick.png").unwrap();
+///
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, rgl::gen_texture());
+/// rgl::tex_image_2d_from_image(rgl::TextureTarget::Texture2d, 0, &image, true);
+/// ```
+#[cfg(feature = "image")]
+pub fn tex_image_2d_from_image(target: enums::TextureTarget, level: GLint, image: &DynamicImage, flip_v: bool) {
+    let flipped;
+    let image = if flip_v {
+        flipped = image.flipv();
+        &flipped
+    } else {
+        image
+    };
+
+    let converted;
+    let (image, internal_format, format) = match *image {
+        DynamicImage::ImageRgba8(_) => (image, enums::InternalFormat::Rgba8, enums::PixelFormat::Rgba),
+        DynamicImage::ImageRgb8(_) => (image, enums::InternalFormat::Rgb8, enums::PixelFormat::Rgb),
+        DynamicImage::ImageLuma8(_) => (image, enums::InternalFormat::R8, enums::PixelFormat::Red),
+        DynamicImage::ImageLumaA8(_) => (image, enums::InternalFormat::Rg8, enums::PixelFormat::Rg),
+        _ => {
+            converted = DynamicImage::ImageRgba8(image.to_rgba8());
+            (&converted, enums::InternalFormat::Rgba8, enums::PixelFormat::Rgba)
+        }
+    };
+
+    //Rows of the image are tightly packed, which GL only reads correctly with an alignment of 1
+    with_unpack_alignment(1, || {
+        tex_image_2d(
+            target,
             level,
-            internal_format as i32,
-            width,
-            height,
-            border,
-            format as GLenum,
-            gl::UNSIGNED_BYTE,
-            pixels.as_ptr() as *const c_void,
+            internal_format,
+            image.width() as GLsizei,
+            image.height() as GLsizei,
+            format,
+            enums::PixelType::UnsignedByte,
+            Some(image.as_bytes()),
         );
+    });
+}
+
+/// Creates a mipmapped two-dimensional texture from an `image` crate image
+///
+/// The image is flipped vertically as with `tex_image_2d_from_image`, and the texture is filtered
+/// with `LinearMipmapLinear` and `Linear`. This leaves the texture bound to `Texture2d`. Only
+/// available with the `image` feature
+///
+/// # Examples
+/// ```rust,ignore
+/// let texture = rgl::texture_from_image(&image::open("assets/brick.png").unwrap());
+/// ```
+#[cfg(feature = "image")]
+pub fn texture_from_image(image: &DynamicImage) -> Texture {
+    let target = enums::TextureTarget::Texture2d;
+    let texture = gen_texture();
+
+    bind_texture(target, texture);
+    tex_image_2d_from_image(target, 0, image, true);
+    tex_min_filter(target, enums::MinFilter::LinearMipmapLinear);
+    tex_mag_filter(target, enums::MagFilter::Linear);
+    generate_mipmap(target);
+    texture
+}
+
+/// Reads mip level `level` of the two-dimensional texture bound to `target` into an `image` crate
+/// image, as 8 bit RGBA
+///
+/// With `flip_v` the rows are flipped to undo the flip of `tex_image_2d_from_image`, so
+/// `texture_from_image` round trips. Only available with the `image` feature
+///
+/// # Examples
+/// ```rust,ignore
+/// rgl::bind_texture(rgl::TextureTarget::Texture2d, texture);
+/// rgl::texture_to_image(rgl::TextureTarget::Texture2d, 0, true).save("texture.png").unwrap();
+/// ```
+#[cfg(all(feature = "image", not(feature = "gles2")))]
+pub fn texture_to_image(target: enums::TextureTarget, level: GLint, flip_v: bool) -> RgbaImage {
+    let width = get_tex_level_parameter(target, level, enums::TextureLevelParameter::Width);
+    let height = get_tex_level_parameter(target, level, enums::TextureLevelParameter::Height);
+    let pixels = with_pack_alignment(1, || get_tex_image(target, level, enums::PixelFormat::Rgba, enums::PixelType::UnsignedByte));
+
+    let mut image = RgbaImage::from_raw(width as u32, height as u32, pixels).expect("texture level is not two-dimensional");
+    if flip_v {
+        imageops::flip_vertical_in_place(&mut image);
+    }
+    image
+}
+
+/// Number of bytes a single pixel of `format` and `type_` occupies in client memory, `None` if GL
+/// does not accept the pair
+///
+/// Packed types are only valid with formats of as many components as they pack, `DepthStencil`
+/// only with the packed depth stencil types, and integer formats only with integer types
+///
+/// # Examples
+/// ```rust
+/// use rgl::{PixelFormat, PixelType};
+///
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::Rgba, PixelType::UnsignedByte), Some(4));
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::Rgb, PixelType::Float), Some(12));
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::RgInteger, PixelType::UnsignedShort), Some(4));
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::Rgb, PixelType::UnsignedShort5_6_5), Some(2));
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::Bgra, PixelType::UnsignedInt2_10_10_10Rev), Some(4));
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::DepthStencil, PixelType::UnsignedInt24_8), Some(4));
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::DepthStencil, PixelType::Float32UnsignedInt24_8Rev), Some(8));
+///
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::Rgba, PixelType::UnsignedShort5_6_5), None);
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::DepthStencil, PixelType::UnsignedInt), None);
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::RedInteger, PixelType::Float), None);
+/// assert_eq!(rgl::bytes_per_pixel(PixelFormat::Red, PixelType::UnsignedInt24_8), None);
+/// ```
+pub fn bytes_per_pixel(format: enums::PixelFormat, type_: enums::PixelType) -> Option<usize> {
+    use enums::PixelFormat as F;
+    use enums::PixelType as T;
+
+    let components = match format {
+        F::Red | F::RedInteger | F::DepthComponent | F::StencilIndex => 1,
+        F::Rg | F::RgInteger => 2,
+        F::Rgb | F::Bgr | F::RgbInteger | F::BgrInteger => 3,
+        F::Rgba | F::Bgra | F::RgbaInteger | F::BgraInteger => 4,
+        F::DepthStencil => {
+            return match type_ {
+                T::UnsignedInt24_8 => Some(4),
+                T::Float32UnsignedInt24_8Rev => Some(8),
+                _ => None,
+            };
+        }
+    };
+    let integer = matches!(
+        format,
+        F::RedInteger | F::RgInteger | F::RgbInteger | F::BgrInteger | F::RgbaInteger | F::BgraInteger
+    );
+
+    match type_ {
+        T::UnsignedByte | T::Byte => Some(components),
+        T::UnsignedShort | T::Short => Some(components * 2),
+        T::UnsignedInt | T::Int => Some(components * 4),
+        T::HalfFloat if !integer => Some(components * 2),
+        T::Float if !integer => Some(components * 4),
+        T::UnsignedShort5_6_5 | T::UnsignedShort5_6_5Rev if components == 3 => Some(2),
+        T::UnsignedShort4_4_4_4
+        | T::UnsignedShort4_4_4_4Rev
+        | T::UnsignedShort5_5_5_1
+        | T::UnsignedShort1_5_5_5Rev
+            if components == 4 =>
+        {
+            Some(2)
+        }
+        T::UnsignedInt10_10_10_2 | T::UnsignedInt2_10_10_10Rev if components == 4 => Some(4),
+        T::UnsignedInt10F11F11FRev | T::UnsignedInt5_9_9_9Rev if format == F::Rgb => Some(4),
+        _ => None,
+    }
+}
+
+/// Number of bytes of client memory an image of the given size, layout and alignment occupies
+///
+/// Rows are padded to `alignment` (the `GL_PACK_ALIGNMENT` or `GL_UNPACK_ALIGNMENT` value), except
+/// the last row which GL never reads or writes past
+///
+/// The rest of the pixel store state is left at the defaults, see `PixelLayout` for all of it
+///
+/// # Panics
+/// If `bytes_per_pixel` does not accept `format` and `type_`, or the size does not fit in a `usize`
+///
+/// # Examples
+/// ```rust
+/// use rgl::{PixelFormat, PixelType};
+///
+/// assert_eq!(rgl::pixel_data_size(4, 4, PixelFormat::Rgba, PixelType::UnsignedByte, 4), 64);
+/// assert_eq!(rgl::pixel_data_size(2, 2, PixelFormat::Rgba, PixelType::Float, 4), 64);
+/// // 3 byte rows padded to 4 bytes, except the last
+/// assert_eq!(rgl::pixel_data_size(1, 3, PixelFormat::Rgb, PixelType::UnsignedByte, 4), 11);
+/// assert_eq!(rgl::pixel_data_size(1, 3, PixelFormat::Rgb, PixelType::UnsignedByte, 1), 9);
+/// assert_eq!(rgl::pixel_data_size(5, 2, PixelFormat::Red, PixelType::UnsignedByte, 8), 13);
+/// assert_eq!(rgl::pixel_data_size(0, 0, PixelFormat::Red, PixelType::UnsignedByte, 4), 0);
+/// // Packed pixels, 2 bytes each
+/// assert_eq!(rgl::pixel_data_size(3, 2, PixelFormat::Rgb, PixelType::UnsignedShort5_6_5, 4), 14);
+/// ```
+pub fn pixel_data_size(
+    width: GLsizei,
+    height: GLsizei,
+    format: enums::PixelFormat,
+    type_: enums::PixelType,
+    alignment: GLint,
+) -> usize {
+    PixelLayout { alignment, ..PixelLayout::DEFAULT }
+        .data_size(width, height, format, type_)
+        .expect("pixel data would not fit in memory")
+}
+
+/// The pixel store state that lays out pixel data in client memory, see `pixel_store`
+///
+/// A `row_length` or `image_height` of 0 means rows or images are as long as the data's width or
+/// height. Only three-dimensional data uses `image_height` and `skip_images`
+///
+/// # Examples
+/// ```rust
+/// use rgl::{PixelFormat, PixelLayout, PixelType};
+///
+/// //Reading a 16x16 tile out of a 256 pixel wide image, starting at pixel (32, 8)
+/// let layout = PixelLayout {
+///     row_length: 256,
+///     skip_pixels: 32,
+///     skip_rows: 8,
+///     ..PixelLayout::DEFAULT
+/// };
+/// let size = layout.data_size(16, 16, PixelFormat::Rgba, PixelType::UnsignedByte);
+/// assert_eq!(size, Some((8 + 15) * 256 * 4 + (32 + 16) * 4));
+///
+/// //Images of 4 rows, of which 2 are used
+/// let layout = PixelLayout { image_height: 4, ..PixelLayout::DEFAULT };
+/// let size = layout.data_size_3d(1, 2, 3, PixelFormat::Red, PixelType::UnsignedByte);
+/// assert_eq!(size, Some(2 * 4 * 4 + 4 + 1));
+///
+/// //Sizes that do not fit in memory
+/// let size = PixelLayout::DEFAULT.data_size_3d(i32::MAX, i32::MAX, i32::MAX, PixelFormat::Rgba, PixelType::Float);
+/// assert_eq!(size, None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PixelLayout {
+    pub alignment: GLint,
+    pub row_length: GLint,
+    pub skip_pixels: GLint,
+    pub skip_rows: GLint,
+    pub image_height: GLint,
+    pub skip_images: GLint,
+}
+
+impl PixelLayout {
+    /// The initial state of GL, rows one after another aligned to 4 bytes
+    pub const DEFAULT: PixelLayout = PixelLayout {
+        alignment: 4,
+        row_length: 0,
+        skip_pixels: 0,
+        skip_rows: 0,
+        image_height: 0,
+        skip_images: 0,
+    };
+
+    /// The current `GL_PACK_*` state, which pixels read back from GL are written with
+    pub fn pack() -> PixelLayout {
+        let alignment = pack_alignment();
+        if !pixel_store_layout_supported() {
+            return PixelLayout { alignment, ..PixelLayout::DEFAULT };
+        }
+
+        PixelLayout {
+            alignment,
+            row_length: get_pixel_store(gl::PACK_ROW_LENGTH),
+            skip_pixels: get_pixel_store(gl::PACK_SKIP_PIXELS),
+            skip_rows: get_pixel_store(gl::PACK_SKIP_ROWS),
+            #[cfg(not(feature = "gles2"))]
+            image_height: get_pixel_store(gl::PACK_IMAGE_HEIGHT),
+            #[cfg(not(feature = "gles2"))]
+            skip_images: get_pixel_store(gl::PACK_SKIP_IMAGES),
+            #[cfg(feature = "gles2")]
+            image_height: 0,
+            #[cfg(feature = "gles2")]
+            skip_images: 0,
+        }
+    }
+
+    /// The current `GL_UNPACK_*` state, which pixels uploaded to GL are read with
+    ///
+    /// This is queried from GL, unlike the layout uploads are checked against after `set_unpack`
+    pub fn unpack() -> PixelLayout {
+        let alignment = unpack_alignment();
+        if !pixel_store_layout_supported() {
+            return PixelLayout { alignment, ..PixelLayout::DEFAULT };
+        }
+
+        PixelLayout {
+            alignment,
+            row_length: get_pixel_store(gl::UNPACK_ROW_LENGTH),
+            skip_pixels: get_pixel_store(gl::UNPACK_SKIP_PIXELS),
+            skip_rows: get_pixel_store(gl::UNPACK_SKIP_ROWS),
+            image_height: get_pixel_store(gl::UNPACK_IMAGE_HEIGHT),
+            skip_images: get_pixel_store(gl::UNPACK_SKIP_IMAGES),
+        }
+    }
+
+    /// Sets the `GL_UNPACK_*` state to this layout, and remembers it for the thread
+    ///
+    /// The uploads such as `tex_sub_image_2d` check the length of their data against the unpack
+    /// state, which otherwise takes several queries per upload, and queries can stall threaded
+    /// drivers. After this they use the remembered layout, which `pixel_store` keeps up to date.
+    /// Call it again after making another context current on the thread, or changing the unpack
+    /// state through `gl` directly
+    ///
+    /// # Examples
+    #[cfg_attr(not(feature = "gles2"), doc = "```rust")]
+    #[cfg_attr(feature = "gles2", doc = "```rust,ignore")]
+    /// extern crate gl;
+    /// extern crate rgl;
+    ///
+    /// use gl::types::*;
+    /// use std::os::raw::c_void;
+    /// use std::ptr;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static QUERIES: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// extern "system" fn pixel_store(_param_name: GLenum, _param: GLint) {}
+    ///
+    /// extern "system" fn get_integerv(_param_name: GLenum, _data: *mut GLint) {
+    ///     QUERIES.fetch_add(1, Ordering::SeqCst);
+    /// }
+    ///
+    /// extern "system" fn tex_sub_image_2d(
+    ///     _target: GLenum,
+    ///     _level: GLint,
+    ///     _x: GLint,
+    ///     _y: GLint,
+    ///     _width: GLsizei,
+    ///     _height: GLsizei,
+    ///     _format: GLenum,
+    ///     _type: GLenum,
+    ///     _pixels: *const c_void,
+    /// ) {
+    /// }
+    ///
+    /// extern "system" fn get_error() -> GLenum {
+    ///     gl::NO_ERROR
+    /// }
+    ///
+    /// # fn main() {
+    /// gl::load_with(|symbol| match symbol {
+    ///     "glPixelStorei" => pixel_store as *const c_void,
+    ///     "glGetIntegerv" => get_integerv as *const c_void,
+    ///     "glTexSubImage2D" => tex_sub_image_2d as *const c_void,
+    ///     "glGetError" => get_error as *const c_void,
+    ///     _ => ptr::null(),
+    /// });
+    ///
+    /// //A font cache uploading glyphs of one byte per pixel every frame, set up once
+    /// let layout = rgl::PixelLayout { alignment: 1, ..rgl::PixelLayout::DEFAULT };
+    /// layout.set_unpack();
+    ///
+    /// let glyph = [255u8; 7 * 9];
+    /// for _ in 0..100 {
+    ///     rgl::tex_sub_image_2d(rgl::TextureTarget::Texture2d, 0, 0, 0, 7, 9, rgl::PixelFormat::Red, rgl::PixelType::UnsignedByte, &glyph);
+    /// }
+    /// assert_eq!(QUERIES.load(Ordering::SeqCst), 0);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// If anything but the alignment is set on a GLES 2 context, which only has the alignment
+    pub fn set_unpack(&self) {
+        pixel_store(enums::PixelStoreParam::UnpackAlignment, self.alignment);
+        if pixel_store_layout_supported() {
+            pixel_store(enums::PixelStoreParam::UnpackRowLength, self.row_length);
+            pixel_store(enums::PixelStoreParam::UnpackSkipPixels, self.skip_pixels);
+            pixel_store(enums::PixelStoreParam::UnpackSkipRows, self.skip_rows);
+            pixel_store(enums::PixelStoreParam::UnpackImageHeight, self.image_height);
+            pixel_store(enums::PixelStoreParam::UnpackSkipImages, self.skip_images);
+        } else {
+            assert!(
+                *self == PixelLayout { alignment: self.alignment, ..PixelLayout::DEFAULT },
+                "GLES 2 only has the unpack alignment"
+            );
+        }
+        KNOWN_UNPACK.with(|known| known.set(Some(*self)));
+    }
+
+    /// Number of bytes of client memory two-dimensional pixel data of this layout spans, from the
+    /// start of the data to the end of its last pixel, or `None` if that does not fit in a `usize`
+    ///
+    /// # Panics
+    /// If `bytes_per_pixel` does not accept `format` and `type_`
+    pub fn data_size(&self, width: GLsizei, height: GLsizei, format: enums::PixelFormat, type_: enums::PixelType) -> Option<usize> {
+        self.size(width, height, 1, false, format, type_)
+    }
+
+    /// Like `data_size`, for three-dimensional and array data of `depth` images
+    ///
+    /// # Panics
+    /// If `bytes_per_pixel` does not accept `format` and `type_`
+    pub fn data_size_3d(
+        &self,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: enums::PixelFormat,
+        type_: enums::PixelType,
+    ) -> Option<usize> {
+        self.size(width, height, depth, true, format, type_)
+    }
+
+    fn size(
+        &self,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        images: bool,
+        format: enums::PixelFormat,
+        type_: enums::PixelType,
+    ) -> Option<usize> {
+        let pixel = bytes_per_pixel(format, type_)
+            .unwrap_or_else(|| panic!("{:?} pixels can not be of type {:?}", format, type_));
+        if width <= 0 || height <= 0 || depth <= 0 {
+            return Some(0);
+        }
+        let (width, height, depth) = (width as usize, height as usize, depth as usize);
+        //GL refuses negative values in pixel_store, treat them as 0 all the same
+        let value = |value: GLint| value.max(0) as usize;
+
+        let alignment = value(self.alignment).max(1);
+        let row_pixels = if self.row_length > 0 { value(self.row_length) } else { width };
+        let stride = row_pixels.checked_mul(pixel)?.div_ceil(alignment).checked_mul(alignment)?;
+
+        let mut skip = value(self.skip_rows)
+            .checked_mul(stride)?
+            .checked_add(value(self.skip_pixels).checked_mul(pixel)?)?;
+        let mut end = (height - 1).checked_mul(stride)?.checked_add(width.checked_mul(pixel)?)?;
+
+        if images {
+            let image_rows = if self.image_height > 0 { value(self.image_height) } else { height };
+            let image_stride = image_rows.checked_mul(stride)?;
+            skip = skip.checked_add(value(self.skip_images).checked_mul(image_stride)?)?;
+            end = end.checked_add((depth - 1).checked_mul(image_stride)?)?;
+        }
+        skip.checked_add(end)
     }
 }
 
-pub fn generate_mipmap(target: enums::TexTarget) {
-    unsafe {
-        gl::GenerateMipmap(target as GLenum);
+thread_local! {
+    /// The unpack state set by `PixelLayout::set_unpack`, `None` until it is called
+    static KNOWN_UNPACK: Cell<Option<PixelLayout>> = const { Cell::new(None) };
+}
+
+/// The unpack state the uploads check their data against, queried unless it is known
+pub(crate) fn current_unpack() -> PixelLayout {
+    KNOWN_UNPACK.with(Cell::get).unwrap_or_else(PixelLayout::unpack)
+}
+
+//ES 2 only has the alignment, the other pixel store state arrived in ES 3
+#[cfg(not(feature = "gles2"))]
+fn pixel_store_layout_supported() -> bool {
+    true
+}
+
+#[cfg(feature = "gles2")]
+fn pixel_store_layout_supported() -> bool {
+    info::get_version().0 >= 3
+}
+
+fn get_pixel_store(param_name: GLenum) -> GLint {
+    let mut value = 0;
+    gl_call!(get_pixel_store, gl::GetIntegerv(param_name, &mut value));
+    value
+}
+
+/// Runs `f` with no buffer bound to `Target::PixelPackBuffer`, so pixels read back go to client
+/// memory rather than into the buffer, restoring the binding afterwards
+pub(crate) fn without_pack_buffer<R, F: FnOnce() -> R>(f: F) -> R {
+    if !pixel_store_layout_supported() {
+        return f();
+    }
+
+    let bound = get_pixel_store(gl::PIXEL_PACK_BUFFER_BINDING) as GLuint;
+    if bound == 0 {
+        return f();
     }
+    buffers::bind_buffer(enums::Target::PixelPackBuffer, buffers::Buffer::NONE);
+    let result = f();
+    buffers::bind_buffer(enums::Target::PixelPackBuffer, buffers::Buffer(bound));
+    result
 }
 
-pub fn tex_parameteri(target: enums::TexTarget, param_name: enums::TexParamName, param: enums::TexParam) {
-    unsafe {
-        gl::TexParameteri(target as GLenum, param_name as GLenum, param as GLenum as i32);
+/// Pointer to optional pixel data, null meaning no data is uploaded
+fn pixels_ptr(data: Option<&[u8]>) -> *const c_void {
+    match data {
+        Some(data) => data.as_ptr() as *const c_void,
+        None => ptr::null(),
     }
 }
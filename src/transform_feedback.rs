@@ -0,0 +1,152 @@
+use super::enums;
+use super::shaders::Program;
+
+use gl;
+use gl::types::*;
+use std::ffi::CString;
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct TransformFeedback(pub GLuint);
+
+impl TransformFeedback {
+    /// The name 0, which binds no transform feedback object, leaving the default one bound
+    pub const NONE: TransformFeedback = TransformFeedback(0);
+}
+
+/// Sets which varyings of a program are captured by transform feedback, takes effect at the next link
+///
+/// # Panics
+/// If any of the names contain a NUL
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glTransformFeedbackVaryings.xhtml
+pub fn transform_feedback_varyings(program: Program, names: &[&str], mode: enums::BufferMode) {
+    let names: Vec<CString> = names
+        .iter()
+        .map(|name| CString::new(*name).expect("transform_feedback_varyings: name contains a NUL"))
+        .collect();
+    let pointers: Vec<*const GLchar> = names.iter().map(|name| name.as_ptr()).collect();
+
+    gl_call!(transform_feedback_varyings, gl::TransformFeedbackVaryings(
+        program.0,
+        pointers.len() as GLsizei,
+        pointers.as_ptr(),
+        mode as GLenum
+    ));
+}
+
+/// Starts capturing the output of the vertex stages into the buffers bound to `TransformFeedbackBuffer`
+///
+/// `primitive` must match the kind of primitive drawn: `Points`, `Lines` or `Triangles`
+///
+/// # Examples
+#[cfg_attr(not(feature = "gles2"), doc = "```rust,no_run")]
+#[cfg_attr(feature = "gles2", doc = "```rust,ignore")]
+/// //Particles are simulated in the vertex shader, reading last frame's state from one buffer and
+/// //capturing this frame's into the other, then the two are swapped
+/// let program = rgl::create_program();
+/// //...attach the simulation shader
+/// rgl::transform_feedback_varyings(program, &["out_position", "out_velocity"], rgl::BufferMode::Interleaved);
+/// rgl::link_program(program);
+///
+/// let buffers = [rgl::gen_buffer(), rgl::gen_buffer()];
+/// let feedbacks = [rgl::gen_transform_feedback(), rgl::gen_transform_feedback()];
+/// for i in 0..2 {
+///     rgl::bind_transform_feedback(feedbacks[i]);
+///     rgl::bind_buffer_base(rgl::IndexedTarget::TransformFeedbackBuffer, 0, buffers[i]);
+/// }
+///
+/// let mut current = 0;
+/// let mut first_frame = true;
+/// loop {
+///     let (src, dst) = (current, 1 - current);
+///
+///     rgl::use_program(program);
+///     rgl::enable(rgl::Capability::RasterizerDiscard);
+///     rgl::bind_buffer(rgl::Target::ArrayBuffer, buffers[src]);
+///     //...vertex_attrib_pointer for the particle state
+///     rgl::bind_transform_feedback(feedbacks[dst]);
+///
+///     rgl::begin_transform_feedback(rgl::Primitive::Points);
+///     if first_frame {
+///         rgl::draw_arrays(rgl::Primitive::Points, 0, 1000);
+///         first_frame = false;
+///     } else {
+///         rgl::draw_transform_feedback(rgl::Primitive::Points, feedbacks[src]);
+///     }
+///     rgl::end_transform_feedback();
+///     rgl::disable(rgl::Capability::RasterizerDiscard);
+///
+///     current = dst;
+/// }
+/// ```
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBeginTransformFeedback.xhtml
+pub fn begin_transform_feedback(primitive: enums::Primitive) {
+    gl_call!(begin_transform_feedback, gl::BeginTransformFeedback(primitive as GLenum));
+}
+
+pub fn end_transform_feedback() {
+    gl_call!(end_transform_feedback, gl::EndTransformFeedback());
+}
+
+/// Pauses capturing, so that draws in between are not recorded
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glPauseTransformFeedback.xhtml
+pub fn pause_transform_feedback() {
+    gl_call!(pause_transform_feedback, gl::PauseTransformFeedback());
+}
+
+/// Resumes capturing after `pause_transform_feedback`
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glResumeTransformFeedback.xhtml
+pub fn resume_transform_feedback() {
+    gl_call!(resume_transform_feedback, gl::ResumeTransformFeedback());
+}
+
+/// Generates transform feedback objects, one for every element of the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glGenTransformFeedbacks.xhtml
+pub fn gen_transform_feedbacks(feedbacks: &mut [TransformFeedback]) {
+    gl_call!(gen_transform_feedbacks, gl::GenTransformFeedbacks(feedbacks.len() as GLsizei, feedbacks.as_mut_ptr() as *mut GLuint));
+}
+
+/// Generates a single transform feedback object
+pub fn gen_transform_feedback() -> TransformFeedback {
+    let mut feedback = [TransformFeedback(0)];
+    gen_transform_feedbacks(&mut feedback);
+    feedback[0]
+}
+
+/// Binds a transform feedback object, which records the `TransformFeedbackBuffer` bindings
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glBindTransformFeedback.xhtml
+pub fn bind_transform_feedback<H: Into<TransformFeedback>>(feedback: H) {
+    let feedback: TransformFeedback = feedback.into();
+    gl_call!(bind_transform_feedback, gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, feedback.0));
+}
+
+/// Binds the default transform feedback object, which always exists and records the
+/// `TransformFeedbackBuffer` bindings made while it is bound
+pub fn unbind_transform_feedback() {
+    gl_call!(unbind_transform_feedback, gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, 0));
+}
+
+/// Deletes every transform feedback object in the slice
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDeleteTransformFeedbacks.xhtml
+pub fn delete_transform_feedbacks(feedbacks: &[TransformFeedback]) {
+    gl_call!(delete_transform_feedbacks, gl::DeleteTransformFeedbacks(feedbacks.len() as GLsizei, feedbacks.as_ptr() as *const GLuint));
+}
+
+pub fn delete_transform_feedback(feedback: TransformFeedback) {
+    delete_transform_feedbacks(&[feedback]);
+}
+
+/// Draws as many vertices as were last captured by `feedback`, without reading the count back
+///
+/// More: https://www.khronos.org/registry/OpenGL-Refpages/gl4/html/glDrawTransformFeedback.xhtml
+#[cfg(not(feature = "gles2"))]
+pub fn draw_transform_feedback(mode: enums::Primitive, feedback: TransformFeedback) {
+    gl_call!(draw_transform_feedback, gl::DrawTransformFeedback(mode as GLenum, feedback.0));
+}
@@ -0,0 +1,438 @@
+use gl::types::*;
+use std::mem;
+
+use super::buffers;
+use super::enums;
+
+/// How the shader reads the components of an attribute
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeKind {
+    /// Read by `float`/`vec` inputs, integer components are converted and optionally normalised
+    Float { type_: enums::Type, normalised: bool },
+    /// Read unconverted by `int`/`ivec`/`uint`/`uvec` inputs
+    Integer(enums::IntType),
+}
+
+impl AttributeKind {
+    /// Turns an integer attribute into one read as floats normalised to `[0, 1]` or `[-1, 1]`
+    ///
+    /// Float attributes are returned unchanged
+    pub const fn normalised(self) -> AttributeKind {
+        match self {
+            AttributeKind::Integer(int_type) => AttributeKind::Float {
+                type_: int_to_type(int_type),
+                normalised: true,
+            },
+            float => float,
+        }
+    }
+}
+
+//`From` is not usable in constants
+const fn int_to_type(int_type: enums::IntType) -> enums::Type {
+    match int_type {
+        enums::IntType::Byte => enums::Type::Byte,
+        enums::IntType::UnsignedByte => enums::Type::UnsignedByte,
+        enums::IntType::Short => enums::Type::Short,
+        enums::IntType::UnsignedShort => enums::Type::UnsignedShort,
+        enums::IntType::Int => enums::Type::Int,
+        enums::IntType::UnsignedInt => enums::Type::UnsignedInt,
+    }
+}
+
+/// One attribute of a vertex struct, see `VertexLayout`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AttributeDesc {
+    /// The attribute location in the shader
+    pub index: GLuint,
+    /// Number of components, 1 to 4
+    pub size: GLint,
+    pub kind: AttributeKind,
+    /// Byte offset of the field within the vertex
+    pub offset: usize,
+}
+
+/// Field types that can be used as attributes in `vertex_layout!`
+pub trait VertexAttribute {
+    const SIZE: GLint;
+    const KIND: AttributeKind;
+}
+
+macro_rules! vertex_attribute_impls {
+    ($($type_:ty => $kind:expr;)*) => {
+        $(
+            impl VertexAttribute for $type_ {
+                const SIZE: GLint = 1;
+                const KIND: AttributeKind = $kind;
+            }
+
+            impl VertexAttribute for [$type_; 1] {
+                const SIZE: GLint = 1;
+                const KIND: AttributeKind = $kind;
+            }
+
+            impl VertexAttribute for [$type_; 2] {
+                const SIZE: GLint = 2;
+                const KIND: AttributeKind = $kind;
+            }
+
+            impl VertexAttribute for [$type_; 3] {
+                const SIZE: GLint = 3;
+                const KIND: AttributeKind = $kind;
+            }
+
+            impl VertexAttribute for [$type_; 4] {
+                const SIZE: GLint = 4;
+                const KIND: AttributeKind = $kind;
+            }
+        )*
+    };
+}
+
+vertex_attribute_impls! {
+    f32 => AttributeKind::Float { type_: enums::Type::Float, normalised: false };
+    i8 => AttributeKind::Integer(enums::IntType::Byte);
+    u8 => AttributeKind::Integer(enums::IntType::UnsignedByte);
+    i16 => AttributeKind::Integer(enums::IntType::Short);
+    u16 => AttributeKind::Integer(enums::IntType::UnsignedShort);
+    i32 => AttributeKind::Integer(enums::IntType::Int);
+    u32 => AttributeKind::Integer(enums::IntType::UnsignedInt);
+}
+
+/// A vertex struct whose attributes can be set up with `apply_vertex_layout`
+///
+/// Usually implemented with `vertex_layout!`, which computes the offsets
+pub trait VertexLayout: Sized {
+    const ATTRIBUTES: &'static [AttributeDesc];
+}
+
+/// Enables and configures every attribute of `V` for the bound VAO and array buffer
+///
+/// The stride is `size_of::<V>()`. Float attributes use `vertex_attrib_pointer` and integer ones
+/// `vertex_attrib_i_pointer`
+///
+/// # Examples
+/// ```rust,no_run
+/// #[macro_use]
+/// extern crate rgl;
+///
+/// vertex_layout! {
+///     #[derive(Clone, Copy)]
+///     pub struct Vertex {
+///         pub position: [f32; 3] => 0,
+///         pub uv: [f32; 2] => 1,
+///         pub color: [u8; 4] => 2 normalised,
+///     }
+/// }
+///
+/// # fn main() {
+/// let vertices = [Vertex { position: [0.0; 3], uv: [0.0; 2], color: [255; 4] }; 3];
+///
+/// let vao = rgl::gen_vertex_array();
+/// rgl::bind_vertex_array(vao);
+/// let vbo = rgl::gen_buffer();
+/// rgl::bind_buffer(rgl::Target::ArrayBuffer, vbo);
+/// rgl::buffer_data(rgl::Target::ArrayBuffer, &vertices, rgl::Usage::StaticDraw);
+/// rgl::apply_vertex_layout::<Vertex>();
+/// # }
+/// ```
+pub fn apply_vertex_layout<V: VertexLayout>() {
+    let stride = mem::size_of::<V>() as GLsizei;
+    for attribute in V::ATTRIBUTES {
+        buffers::enable_vertex_attrib_array(attribute.index);
+        match attribute.kind {
+            AttributeKind::Float { type_, normalised } => {
+                buffers::vertex_attrib_pointer(attribute.index, attribute.size, type_, normalised, stride, attribute.offset);
+            }
+            AttributeKind::Integer(type_) => {
+                buffers::vertex_attrib_i_pointer(attribute.index, attribute.size, type_, stride, attribute.offset);
+            }
+        }
+    }
+}
+
+/// Sets up a VAO from its vertex buffers, attribute layout and index buffer in the right order
+///
+/// Attributes read from the vertex buffer added before them. Unless overridden with `offset` and
+/// `stride`, each attribute directly follows the previous one of the same buffer and the stride is
+/// the size of all of them, so interleaved vertices need nothing more than the attributes.
+/// `build` leaves the new VAO bound, along with the last vertex buffer on `ArrayBuffer`
+///
+/// # Examples
+/// ```rust,no_run
+/// //Interleaved positions and uvs, with a per-instance offset from a second buffer
+/// let (vertices, offsets, indices) = (rgl::gen_buffer(), rgl::gen_buffer(), rgl::gen_buffer());
+/// //...upload the data
+///
+/// let vao = rgl::VaoBuilder::new()
+///     .vertex_buffer(vertices)
+///     .attribute(0, 3, rgl::Type::Float, false)
+///     .attribute(1, 2, rgl::Type::Float, false)
+///     .vertex_buffer(offsets)
+///     .attribute(2, 2, rgl::Type::Float, false)
+///     .divisor(1)
+///     .index_buffer(indices)
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct VaoBuilder {
+    buffers: Vec<BufferLayout>,
+    index_buffer: Option<buffers::Buffer>,
+}
+
+struct BufferLayout {
+    buffer: buffers::Buffer,
+    stride: Option<GLsizei>,
+    attributes: Vec<BuilderAttribute>,
+}
+
+struct BuilderAttribute {
+    index: GLuint,
+    size: GLint,
+    kind: AttributeKind,
+    offset: Option<usize>,
+    divisor: GLuint,
+}
+
+impl VaoBuilder {
+    pub fn new() -> VaoBuilder {
+        VaoBuilder::default()
+    }
+
+    /// Makes the following attributes read from `buffer`
+    pub fn vertex_buffer(mut self, buffer: buffers::Buffer) -> VaoBuilder {
+        self.buffers.push(BufferLayout {
+            buffer,
+            stride: None,
+            attributes: Vec::new(),
+        });
+        self
+    }
+
+    /// Adds an attribute read by `float`/`vec` shader inputs, see `vertex_attrib_pointer`
+    ///
+    /// # Panics
+    /// If no vertex buffer was added before it
+    pub fn attribute(self, index: GLuint, size: GLint, type_: enums::Type, normalised: bool) -> VaoBuilder {
+        self.push_attribute(index, size, AttributeKind::Float { type_, normalised })
+    }
+
+    /// Adds an attribute read unconverted by `int`/`uint` shader inputs, see `vertex_attrib_i_pointer`
+    ///
+    /// # Panics
+    /// If no vertex buffer was added before it
+    pub fn int_attribute(self, index: GLuint, size: GLint, type_: enums::IntType) -> VaoBuilder {
+        self.push_attribute(index, size, AttributeKind::Integer(type_))
+    }
+
+    /// Places the last attribute at byte `offset` within the vertex, rather than after the previous one
+    ///
+    /// # Panics
+    /// If no attribute was added before it
+    pub fn offset(mut self, offset: usize) -> VaoBuilder {
+        self.last_attribute("offset").offset = Some(offset);
+        self
+    }
+
+    /// Makes the last attribute advance once every `divisor` instances, see `vertex_attrib_divisor`
+    ///
+    /// # Panics
+    /// If no attribute was added before it
+    pub fn divisor(mut self, divisor: GLuint) -> VaoBuilder {
+        self.last_attribute("divisor").divisor = divisor;
+        self
+    }
+
+    /// Sets the stride of the current vertex buffer in bytes, rather than the size of its attributes
+    ///
+    /// # Panics
+    /// If no vertex buffer was added before it
+    pub fn stride(mut self, stride: GLsizei) -> VaoBuilder {
+        self.current_buffer("stride").stride = Some(stride);
+        self
+    }
+
+    /// Sets the element array buffer, which is bound while the VAO is, so it belongs to it
+    pub fn index_buffer(mut self, buffer: buffers::Buffer) -> VaoBuilder {
+        self.index_buffer = Some(buffer);
+        self
+    }
+
+    /// Generates the VAO and records everything into it, leaving it bound
+    pub fn build(self) -> buffers::VAO {
+        let vao = buffers::gen_vertex_array();
+        buffers::bind_vertex_array(vao);
+
+        for layout in &self.buffers {
+            buffers::bind_buffer(enums::Target::ArrayBuffer, layout.buffer);
+
+            //Attributes without an offset follow the previous one, and the stride defaults to the
+            //end of the furthest one
+            let (mut next, mut end) = (0, 0);
+            let mut offsets = Vec::with_capacity(layout.attributes.len());
+            for attribute in &layout.attributes {
+                let offset = attribute.offset.unwrap_or(next);
+                next = offset + attribute_bytes(attribute.size, attribute.kind);
+                end = end.max(next);
+                offsets.push(offset);
+            }
+            let stride = layout.stride.unwrap_or(end as GLsizei);
+
+            for (attribute, offset) in layout.attributes.iter().zip(offsets) {
+                buffers::enable_vertex_attrib_array(attribute.index);
+                match attribute.kind {
+                    AttributeKind::Float { type_, normalised } => {
+                        buffers::vertex_attrib_pointer(attribute.index, attribute.size, type_, normalised, stride, offset);
+                    }
+                    AttributeKind::Integer(type_) => {
+                        buffers::vertex_attrib_i_pointer(attribute.index, attribute.size, type_, stride, offset);
+                    }
+                }
+                if attribute.divisor != 0 {
+                    buffers::vertex_attrib_divisor(attribute.index, attribute.divisor);
+                }
+            }
+        }
+
+        if let Some(buffer) = self.index_buffer {
+            buffers::bind_buffer(enums::Target::ElementArrayBuffer, buffer);
+        }
+
+        vao
+    }
+
+    fn push_attribute(mut self, index: GLuint, size: GLint, kind: AttributeKind) -> VaoBuilder {
+        self.current_buffer("attribute").attributes.push(BuilderAttribute {
+            index,
+            size,
+            kind,
+            offset: None,
+            divisor: 0,
+        });
+        self
+    }
+
+    fn current_buffer(&mut self, method: &str) -> &mut BufferLayout {
+        match self.buffers.last_mut() {
+            Some(layout) => layout,
+            None => panic!("VaoBuilder::{} needs a vertex_buffer first", method),
+        }
+    }
+
+    fn last_attribute(&mut self, method: &str) -> &mut BuilderAttribute {
+        match self.buffers.last_mut().and_then(|layout| layout.attributes.last_mut()) {
+            Some(attribute) => attribute,
+            None => panic!("VaoBuilder::{} needs an attribute first", method),
+        }
+    }
+}
+
+/// Size in bytes of an attribute of `size` components
+fn attribute_bytes(size: GLint, kind: AttributeKind) -> usize {
+    let type_ = match kind {
+        AttributeKind::Float { type_, .. } => type_,
+        AttributeKind::Integer(int_type) => int_to_type(int_type),
+    };
+    let component = match type_ {
+        enums::Type::Byte | enums::Type::UnsignedByte => 1,
+        enums::Type::Short | enums::Type::UnsignedShort | enums::Type::HalfFloat => 2,
+        enums::Type::Int | enums::Type::UnsignedInt | enums::Type::Float | enums::Type::Fixed => 4,
+        enums::Type::Double => 8,
+        //All four components are packed into one 32 bit value
+        enums::Type::Int2101010Rev | enums::Type::UnsignedInt2101010Rev => return 4,
+    };
+    component * size.max(0) as usize
+}
+
+/// Declares a `#[repr(C)]` vertex struct and implements `VertexLayout` for it
+///
+/// Each field is followed by `=> location`, and integer fields can add `normalised` to be read as
+/// floats. Field types must implement `VertexAttribute`
+///
+/// With the `bytemuck` feature the struct also implements `bytemuck::Pod` so it can be uploaded,
+/// which requires it to derive `Clone, Copy` and fails to compile if the fields leave padding
+///
+/// # Examples
+/// ```rust
+/// #[macro_use]
+/// extern crate rgl;
+///
+/// use rgl::{AttributeKind, VertexLayout};
+///
+/// vertex_layout! {
+///     #[derive(Clone, Copy)]
+///     struct Vertex {
+///         position: [f32; 3] => 0,
+///         color: [u8; 4] => 1 normalised,
+///         bone: u32 => 2,
+///     }
+/// }
+///
+/// # fn main() {
+/// let attributes = Vertex::ATTRIBUTES;
+/// assert_eq!(attributes.len(), 3);
+/// assert_eq!((attributes[0].index, attributes[0].size, attributes[0].offset), (0, 3, 0));
+/// assert_eq!((attributes[1].index, attributes[1].size, attributes[1].offset), (1, 4, 12));
+/// assert_eq!(attributes[1].kind, AttributeKind::Float { type_: rgl::Type::UnsignedByte, normalised: true });
+/// assert_eq!(attributes[2].offset, 16);
+/// assert_eq!(attributes[2].kind, AttributeKind::Integer(rgl::IntType::UnsignedInt));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! vertex_layout {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident : $type_:ty => $index:tt $($normalised:ident)?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $type_,
+            )*
+        }
+
+        impl $crate::VertexLayout for $name {
+            const ATTRIBUTES: &'static [$crate::AttributeDesc] = &[
+                $(
+                    $crate::AttributeDesc {
+                        index: $index,
+                        size: <$type_ as $crate::VertexAttribute>::SIZE,
+                        kind: <$type_ as $crate::VertexAttribute>::KIND$(.$normalised())?,
+                        offset: ::std::mem::offset_of!($name, $field),
+                    },
+                )*
+            ];
+        }
+
+        $crate::__vertex_layout_pod!($name, $($type_),*);
+    };
+}
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vertex_layout_pod {
+    ($name:ident, $($type_:ty),*) => {
+        const _: () = assert!(
+            ::std::mem::size_of::<$name>() == 0 $(+ ::std::mem::size_of::<$type_>())*,
+            concat!(stringify!($name), " has padding between its fields so it cannot be Pod")
+        );
+
+        unsafe impl $crate::__private::Zeroable for $name {}
+        unsafe impl $crate::__private::Pod for $name {}
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __vertex_layout_pod {
+    ($name:ident, $($type_:ty),*) => {};
+}